@@ -0,0 +1,215 @@
+use serde::{Deserialize, Serialize};
+use crate::misc::query_field::QueryField;
+
+/// Body of the `_aliases` endpoint, actions are applied atomically
+/// https://opensearch.org/docs/latest/api-reference/index-apis/alias/
+///
+/// Examples
+/// ```
+/// use serde_json::json;
+/// use os_query_builder_rs::alias::actions::{AddAlias, AliasActions, RemoveAlias};
+/// use os_query_builder_rs::term::term::Term;
+///
+/// let actions = AliasActions::new()
+///             .action(RemoveAlias::new("products-v1", "products"))
+///             .action(AddAlias::new("products-v2", "products-acme").filter(Term::new("tenant", "acme")).routing("acme"));
+///
+/// assert_eq!(serde_json::to_value(actions).unwrap(), json!({
+///     "actions": [
+///         { "remove": { "index": "products-v1", "alias": "products" } },
+///         { "add": {
+///             "index": "products-v2",
+///             "alias": "products-acme",
+///             "filter": { "term": { "tenant": { "value": "acme" } } },
+///             "routing": "acme"
+///         } }
+///     ]
+/// }));
+/// ```
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct AliasActions {
+    actions: Vec<AliasAction>,
+}
+
+impl AliasActions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn action<T: Into<AliasAction>>(mut self, action: T) -> Self {
+        self.actions.push(action.into());
+        self
+    }
+
+    pub fn actions<T, F>(mut self, actions: F) -> Self
+        where T: Into<AliasAction>,
+              F: IntoIterator<Item = T>
+    {
+        self.actions.extend(actions.into_iter().map(|x| x.into()));
+        self
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &AliasAction> {
+        self.actions.iter()
+    }
+}
+
+/// https://opensearch.org/docs/latest/api-reference/index-apis/alias/#request-body
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AliasAction {
+    Add(AddAlias),
+    Remove(RemoveAlias),
+    /// Deletes the index, e.g. to swap an alias onto a new index in place of the old one
+    RemoveIndex(RemoveIndex),
+}
+
+impl From<AddAlias> for AliasAction {
+    fn from(action: AddAlias) -> Self {
+        AliasAction::Add(action)
+    }
+}
+
+impl From<RemoveAlias> for AliasAction {
+    fn from(action: RemoveAlias) -> Self {
+        AliasAction::Remove(action)
+    }
+}
+
+impl From<RemoveIndex> for AliasAction {
+    fn from(action: RemoveIndex) -> Self {
+        AliasAction::RemoveIndex(action)
+    }
+}
+
+/// Adds the alias to the index, or updates it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddAlias {
+    index: String,
+
+    alias: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filter: Option<Box<QueryField>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    routing: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    index_routing: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    search_routing: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_write_index: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_hidden: Option<bool>,
+}
+
+impl AddAlias {
+    /// The index can be a pattern such as `logs-*`
+    pub fn new<T: Into<String>, F: Into<String>>(index: T, alias: F) -> Self {
+        Self {
+            index: index.into(),
+            alias: alias.into(),
+            filter: None,
+            routing: None,
+            index_routing: None,
+            search_routing: None,
+            is_write_index: None,
+            is_hidden: None,
+        }
+    }
+
+    /// Documents visible through the alias, e.g. the ones of a tenant
+    pub fn filter<T: Into<QueryField>>(self, filter: T) -> Self {
+        Self {
+            filter: Some(Box::new(filter.into())),
+            ..self
+        }
+    }
+
+    /// Routing of both indexing and search requests
+    pub fn routing<T: Into<String>>(self, routing: T) -> Self {
+        Self {
+            routing: Some(routing.into()),
+            ..self
+        }
+    }
+
+    /// Routing of indexing requests
+    pub fn index_routing<T: Into<String>>(self, index_routing: T) -> Self {
+        Self {
+            index_routing: Some(index_routing.into()),
+            ..self
+        }
+    }
+
+    /// Routing of search requests
+    pub fn search_routing<T: Into<String>>(self, search_routing: T) -> Self {
+        Self {
+            search_routing: Some(search_routing.into()),
+            ..self
+        }
+    }
+
+    /// Index receiving writes when the alias points to several indices
+    pub fn is_write_index(self, is_write_index: bool) -> Self {
+        Self {
+            is_write_index: Some(is_write_index),
+            ..self
+        }
+    }
+
+    pub fn is_hidden(self, is_hidden: bool) -> Self {
+        Self {
+            is_hidden: Some(is_hidden),
+            ..self
+        }
+    }
+}
+
+/// Removes the alias from the index
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoveAlias {
+    index: String,
+
+    alias: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    must_exist: Option<bool>,
+}
+
+impl RemoveAlias {
+    pub fn new<T: Into<String>, F: Into<String>>(index: T, alias: F) -> Self {
+        Self {
+            index: index.into(),
+            alias: alias.into(),
+            must_exist: None,
+        }
+    }
+
+    /// Fails when the alias does not exist
+    pub fn must_exist(self, must_exist: bool) -> Self {
+        Self {
+            must_exist: Some(must_exist),
+            ..self
+        }
+    }
+}
+
+/// Deletes a concrete index, aliases are not accepted
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoveIndex {
+    index: String,
+}
+
+impl RemoveIndex {
+    pub fn new<T: Into<String>>(index: T) -> Self {
+        Self {
+            index: index.into(),
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use serde::{Deserialize, Serialize};
+use crate::misc::query_field::QueryField;
+
+/// Alias of an index template or of the create-index body
+/// https://opensearch.org/docs/latest/im-plugin/index-alias/
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Alias {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filter: Option<Box<QueryField>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    routing: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    index_routing: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    search_routing: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_write_index: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_hidden: Option<bool>,
+}
+
+impl Alias {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Documents visible through the alias, e.g. the ones of a tenant
+    pub fn filter<T: Into<QueryField>>(self, filter: T) -> Self {
+        Self {
+            filter: Some(Box::new(filter.into())),
+            ..self
+        }
+    }
+
+    /// Routing of both indexing and search requests
+    pub fn routing<T: Into<String>>(self, routing: T) -> Self {
+        Self {
+            routing: Some(routing.into()),
+            ..self
+        }
+    }
+
+    /// Routing of indexing requests
+    pub fn index_routing<T: Into<String>>(self, index_routing: T) -> Self {
+        Self {
+            index_routing: Some(index_routing.into()),
+            ..self
+        }
+    }
+
+    /// Routing of search requests
+    pub fn search_routing<T: Into<String>>(self, search_routing: T) -> Self {
+        Self {
+            search_routing: Some(search_routing.into()),
+            ..self
+        }
+    }
+
+    /// Index receiving writes when the alias points to several indices
+    pub fn is_write_index(self, is_write_index: bool) -> Self {
+        Self {
+            is_write_index: Some(is_write_index),
+            ..self
+        }
+    }
+
+    pub fn is_hidden(self, is_hidden: bool) -> Self {
+        Self {
+            is_hidden: Some(is_hidden),
+            ..self
+        }
+    }
+}
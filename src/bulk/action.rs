@@ -0,0 +1,278 @@
+use serde::{Serialize, Serializer};
+use serde::ser::SerializeMap;
+use serde_json::Value;
+use crate::bulk::metadata::{BulkMetadata, VersionType};
+use crate::misc::script::Script;
+
+/// https://opensearch.org/docs/latest/api-reference/document-apis/bulk/#request-body
+#[derive(Debug, Clone)]
+pub enum BulkAction {
+    /// Creates or replaces the document
+    Index(IndexAction),
+    /// Creates the document, fails when it exists
+    Create(CreateAction),
+    /// Partially updates the document
+    Update(UpdateAction),
+    Delete(DeleteAction),
+}
+
+impl BulkAction {
+    pub fn name(&self) -> &'static str {
+        match self {
+            BulkAction::Index(_) => "index",
+            BulkAction::Create(_) => "create",
+            BulkAction::Update(_) => "update",
+            BulkAction::Delete(_) => "delete",
+        }
+    }
+
+    pub fn metadata(&self) -> &BulkMetadata {
+        match self {
+            BulkAction::Index(action) => &action.metadata,
+            BulkAction::Create(action) => &action.metadata,
+            BulkAction::Update(action) => &action.metadata,
+            BulkAction::Delete(action) => &action.metadata,
+        }
+    }
+
+    /// Lines of the action, each ending with a newline
+    pub fn to_ndjson(&self) -> String {
+        let mut lines = to_json(&MetadataLine(self.name(), self.metadata()));
+        lines.push('\n');
+
+        let source = match self {
+            BulkAction::Index(action) => Some(action.document.to_string()),
+            BulkAction::Create(action) => Some(action.document.to_string()),
+            BulkAction::Update(action) => Some(to_json(&action.body)),
+            BulkAction::Delete(_) => None,
+        };
+        if let Some(source) = source {
+            lines.push_str(&source);
+            lines.push('\n');
+        }
+        lines
+    }
+}
+
+/// `{"<action>": {metadata}}`
+struct MetadataLine<'a>(&'static str, &'a BulkMetadata);
+
+impl Serialize for MetadataLine<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(1))?;
+        state.serialize_entry(self.0, self.1)?;
+        state.end()
+    }
+}
+
+/// Lines are built from strings, numbers and `Value`s, which always serialize
+fn to_json<T: Serialize>(value: &T) -> String {
+    serde_json::to_string(value).expect("bulk line serializes")
+}
+
+#[derive(Debug, Clone)]
+pub struct IndexAction {
+    metadata: BulkMetadata,
+    document: Value,
+}
+
+impl IndexAction {
+    pub fn new<T: Into<Value>>(document: T) -> Self {
+        Self {
+            metadata: BulkMetadata::new(),
+            document: document.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateAction {
+    metadata: BulkMetadata,
+    document: Value,
+}
+
+impl CreateAction {
+    pub fn new<T: Into<Value>>(document: T) -> Self {
+        Self {
+            metadata: BulkMetadata::new(),
+            document: document.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UpdateAction {
+    metadata: BulkMetadata,
+    body: UpdateBody,
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+struct UpdateBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    doc: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    upsert: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    doc_as_upsert: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    script: Option<Script>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scripted_upsert: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    detect_noop: Option<bool>,
+}
+
+impl UpdateAction {
+    pub fn new<T: Into<String>>(id: T) -> Self {
+        Self {
+            metadata: BulkMetadata::new().id(id),
+            body: UpdateBody::default(),
+        }
+    }
+
+    /// Partial document merged into the existing one
+    pub fn doc<T: Into<Value>>(self, doc: T) -> Self {
+        let body = UpdateBody {
+            doc: Some(doc.into()),
+            ..self.body
+        };
+        Self { body, ..self }
+    }
+
+    /// Document indexed when the document does not exist
+    pub fn upsert<T: Into<Value>>(self, upsert: T) -> Self {
+        let body = UpdateBody {
+            upsert: Some(upsert.into()),
+            ..self.body
+        };
+        Self { body, ..self }
+    }
+
+    /// Indexes `doc` when the document does not exist
+    pub fn doc_as_upsert(self, doc_as_upsert: bool) -> Self {
+        let body = UpdateBody {
+            doc_as_upsert: Some(doc_as_upsert),
+            ..self.body
+        };
+        Self { body, ..self }
+    }
+
+    pub fn script<T: Into<Script>>(self, script: T) -> Self {
+        let body = UpdateBody {
+            script: Some(script.into()),
+            ..self.body
+        };
+        Self { body, ..self }
+    }
+
+    /// Runs the script when the document does not exist
+    pub fn scripted_upsert(self, scripted_upsert: bool) -> Self {
+        let body = UpdateBody {
+            scripted_upsert: Some(scripted_upsert),
+            ..self.body
+        };
+        Self { body, ..self }
+    }
+
+    pub fn detect_noop(self, detect_noop: bool) -> Self {
+        let body = UpdateBody {
+            detect_noop: Some(detect_noop),
+            ..self.body
+        };
+        Self { body, ..self }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DeleteAction {
+    metadata: BulkMetadata,
+}
+
+impl DeleteAction {
+    pub fn new<T: Into<String>>(id: T) -> Self {
+        Self {
+            metadata: BulkMetadata::new().id(id),
+        }
+    }
+}
+
+/// Shortcuts for the metadata of every action
+macro_rules! metadata_methods {
+    ($(($action:ident, $variant:ident)),*) => {
+        $(
+            #[allow(clippy::needless_update)]
+            impl $action {
+                /// Replaces the whole metadata, including the id
+                pub fn metadata(self, metadata: BulkMetadata) -> Self {
+                    Self { metadata, ..self }
+                }
+
+                pub fn index<T: Into<String>>(self, index: T) -> Self {
+                    let metadata = self.metadata.index(index);
+                    Self { metadata, ..self }
+                }
+
+                pub fn id<T: Into<String>>(self, id: T) -> Self {
+                    let metadata = self.metadata.id(id);
+                    Self { metadata, ..self }
+                }
+
+                pub fn routing<T: Into<String>>(self, routing: T) -> Self {
+                    let metadata = self.metadata.routing(routing);
+                    Self { metadata, ..self }
+                }
+
+                pub fn if_seq_no<T: Into<u64>>(self, if_seq_no: T) -> Self {
+                    let metadata = self.metadata.if_seq_no(if_seq_no);
+                    Self { metadata, ..self }
+                }
+
+                pub fn if_primary_term<T: Into<u64>>(self, if_primary_term: T) -> Self {
+                    let metadata = self.metadata.if_primary_term(if_primary_term);
+                    Self { metadata, ..self }
+                }
+
+                pub fn version<T: Into<u64>>(self, version: T) -> Self {
+                    let metadata = self.metadata.version(version);
+                    Self { metadata, ..self }
+                }
+
+                pub fn version_type<T: Into<VersionType>>(self, version_type: T) -> Self {
+                    let metadata = self.metadata.version_type(version_type);
+                    Self { metadata, ..self }
+                }
+
+                pub fn pipeline<T: Into<String>>(self, pipeline: T) -> Self {
+                    let metadata = self.metadata.pipeline(pipeline);
+                    Self { metadata, ..self }
+                }
+
+                pub fn retry_on_conflict<T: Into<u32>>(self, retry_on_conflict: T) -> Self {
+                    let metadata = self.metadata.retry_on_conflict(retry_on_conflict);
+                    Self { metadata, ..self }
+                }
+            }
+
+            impl From<$action> for BulkAction {
+                fn from(action: $action) -> Self {
+                    BulkAction::$variant(action)
+                }
+            }
+        )*
+    }
+}
+
+metadata_methods! {
+    (IndexAction, Index),
+    (CreateAction, Create),
+    (UpdateAction, Update),
+    (DeleteAction, Delete)
+}
@@ -0,0 +1,114 @@
+use serde::{Deserialize, Serialize};
+
+/// Metadata line of a bulk action
+/// https://opensearch.org/docs/latest/api-reference/document-apis/bulk/#request-body
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct BulkMetadata {
+    #[serde(rename = "_index", skip_serializing_if = "Option::is_none")]
+    index: Option<String>,
+
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    routing: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    if_seq_no: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    if_primary_term: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version_type: Option<VersionType>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pipeline: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    retry_on_conflict: Option<u32>,
+}
+
+impl BulkMetadata {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Index of the document, can be omitted when the bulk request targets an index
+    pub fn index<T: Into<String>>(self, index: T) -> Self {
+        Self {
+            index: Some(index.into()),
+            ..self
+        }
+    }
+
+    pub fn id<T: Into<String>>(self, id: T) -> Self {
+        Self {
+            id: Some(id.into()),
+            ..self
+        }
+    }
+
+    pub fn routing<T: Into<String>>(self, routing: T) -> Self {
+        Self {
+            routing: Some(routing.into()),
+            ..self
+        }
+    }
+
+    pub fn if_seq_no<T: Into<u64>>(self, if_seq_no: T) -> Self {
+        Self {
+            if_seq_no: Some(if_seq_no.into()),
+            ..self
+        }
+    }
+
+    pub fn if_primary_term<T: Into<u64>>(self, if_primary_term: T) -> Self {
+        Self {
+            if_primary_term: Some(if_primary_term.into()),
+            ..self
+        }
+    }
+
+    pub fn version<T: Into<u64>>(self, version: T) -> Self {
+        Self {
+            version: Some(version.into()),
+            ..self
+        }
+    }
+
+    pub fn version_type<T: Into<VersionType>>(self, version_type: T) -> Self {
+        Self {
+            version_type: Some(version_type.into()),
+            ..self
+        }
+    }
+
+    /// Ingest pipeline for `index` and `create` actions
+    pub fn pipeline<T: Into<String>>(self, pipeline: T) -> Self {
+        Self {
+            pipeline: Some(pipeline.into()),
+            ..self
+        }
+    }
+
+    /// Retries of `update` actions on version conflicts
+    pub fn retry_on_conflict<T: Into<u32>>(self, retry_on_conflict: T) -> Self {
+        Self {
+            retry_on_conflict: Some(retry_on_conflict.into()),
+            ..self
+        }
+    }
+}
+
+/// https://opensearch.org/docs/latest/api-reference/document-apis/index-document/#url-parameters
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VersionType {
+    Internal,
+    External,
+    ExternalGte,
+}
@@ -0,0 +1,3 @@
+pub mod metadata;
+pub mod action;
+pub mod request;
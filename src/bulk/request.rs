@@ -0,0 +1,108 @@
+use crate::bulk::action::BulkAction;
+
+/// Body of the `_bulk` endpoint
+/// https://opensearch.org/docs/latest/api-reference/document-apis/bulk/
+///
+/// Examples
+/// ```
+/// use serde_json::json;
+/// use os_query_builder_rs::bulk::action::{DeleteAction, IndexAction, UpdateAction};
+/// use os_query_builder_rs::bulk::request::BulkRequest;
+///
+/// let bulk = BulkRequest::new()
+///             .action(IndexAction::new(json!({ "brand": "FIAT" })).index("products").id("1"))
+///             .action(UpdateAction::new("2").index("products").doc(json!({ "price": 10 })).doc_as_upsert(true))
+///             .action(DeleteAction::new("3").index("products"));
+///
+/// assert_eq!(bulk.to_ndjson(), concat!(
+///     r#"{"index":{"_index":"products","_id":"1"}}"#, "\n",
+///     r#"{"brand":"FIAT"}"#, "\n",
+///     r#"{"update":{"_index":"products","_id":"2"}}"#, "\n",
+///     r#"{"doc":{"price":10},"doc_as_upsert":true}"#, "\n",
+///     r#"{"delete":{"_index":"products","_id":"3"}}"#, "\n",
+/// ));
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct BulkRequest {
+    actions: Vec<BulkAction>,
+}
+
+impl BulkRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn action<T: Into<BulkAction>>(mut self, action: T) -> Self {
+        self.push(action);
+        self
+    }
+
+    pub fn actions<T, F>(mut self, actions: F) -> Self
+        where T: Into<BulkAction>,
+              F: IntoIterator<Item = T>
+    {
+        self.actions.extend(actions.into_iter().map(|x| x.into()));
+        self
+    }
+
+    pub fn push<T: Into<BulkAction>>(&mut self, action: T) -> &mut Self {
+        self.actions.push(action.into());
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.actions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &BulkAction> {
+        self.actions.iter()
+    }
+
+    /// Newline-delimited body, ending with a newline as the endpoint requires
+    pub fn to_ndjson(&self) -> String {
+        self.actions.iter().map(BulkAction::to_ndjson).collect()
+    }
+
+    /// Splits the body into chunks of at most `max_actions` actions and `max_bytes` bytes,
+    /// an action larger than `max_bytes` is sent in a chunk of its own
+    pub fn chunks(&self, max_actions: usize, max_bytes: usize) -> Vec<String> {
+        let mut chunks = Vec::new();
+        let mut chunk = String::new();
+        let mut actions = 0;
+
+        for action in &self.actions {
+            let lines = action.to_ndjson();
+            if actions > 0 && (actions >= max_actions || chunk.len() + lines.len() > max_bytes) {
+                chunks.push(std::mem::take(&mut chunk));
+                actions = 0;
+            }
+            chunk.push_str(&lines);
+            actions += 1;
+        }
+
+        if actions > 0 {
+            chunks.push(chunk);
+        }
+        chunks
+    }
+
+    /// See [`BulkRequest::chunks`]
+    pub fn chunks_by_actions(&self, max_actions: usize) -> Vec<String> {
+        self.chunks(max_actions, usize::MAX)
+    }
+
+    /// See [`BulkRequest::chunks`]
+    pub fn chunks_by_bytes(&self, max_bytes: usize) -> Vec<String> {
+        self.chunks(usize::MAX, max_bytes)
+    }
+}
+
+impl<T: Into<BulkAction>> FromIterator<T> for BulkRequest {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::new().actions(iter)
+    }
+}
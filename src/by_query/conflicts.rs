@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// What to do on version conflicts
+/// https://opensearch.org/docs/latest/api-reference/document-apis/delete-by-query/#request-body
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Conflicts {
+    /// Stops the operation, the default
+    Abort,
+    /// Counts the conflict and continues
+    Proceed,
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use crate::misc::query_field::QueryField;
+
+/// Body of the `_count` endpoint
+/// https://opensearch.org/docs/latest/api-reference/count/
+///
+/// Examples
+/// ```
+/// use os_query_builder_rs::by_query::count::CountRequest;
+/// use os_query_builder_rs::term::term::Term;
+///
+/// let count = CountRequest::new().query(Term::new("brand", "FIAT"));
+///
+/// assert_eq!(serde_json::to_string(&count).unwrap(), r#"{"query":{"term":{"brand":{"value":"FIAT"}}}}"#);
+/// ```
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CountRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query: Option<QueryField>,
+}
+
+impl CountRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Counts all documents when omitted
+    pub fn query<T: Into<QueryField>>(self, query: T) -> Self {
+        Self {
+            query: Some(query.into()),
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+use crate::by_query::{conflicts::Conflicts, slice::Slice};
+use crate::misc::query_field::QueryField;
+
+/// Body of the `_delete_by_query` endpoint
+/// https://opensearch.org/docs/latest/api-reference/document-apis/delete-by-query/
+///
+/// Examples
+/// ```
+/// use os_query_builder_rs::by_query::{conflicts::Conflicts, delete_by_query::DeleteByQuery};
+/// use os_query_builder_rs::term::range::Range;
+///
+/// let delete = DeleteByQuery::new()
+///             .query(Range::new().field("created").lt("now-1y"))
+///             .max_docs(1000u64)
+///             .conflicts(Conflicts::Proceed);
+///
+/// assert_eq!(
+///     serde_json::to_string(&delete).unwrap(),
+///     r#"{"query":{"range":{"created":{"lt":"now-1y"}}},"max_docs":1000,"conflicts":"proceed"}"#
+/// );
+/// ```
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct DeleteByQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query: Option<QueryField>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_docs: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    conflicts: Option<Conflicts>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    slice: Option<Slice>,
+}
+
+impl DeleteByQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn query<T: Into<QueryField>>(self, query: T) -> Self {
+        Self {
+            query: Some(query.into()),
+            ..self
+        }
+    }
+
+    /// Maximum number of documents to delete
+    pub fn max_docs<T: Into<u64>>(self, max_docs: T) -> Self {
+        Self {
+            max_docs: Some(max_docs.into()),
+            ..self
+        }
+    }
+
+    pub fn conflicts<T: Into<Conflicts>>(self, conflicts: T) -> Self {
+        Self {
+            conflicts: Some(conflicts.into()),
+            ..self
+        }
+    }
+
+    pub fn slice<T: Into<Slice>>(self, slice: T) -> Self {
+        Self {
+            slice: Some(slice.into()),
+            ..self
+        }
+    }
+}
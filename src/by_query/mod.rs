@@ -0,0 +1,7 @@
+pub mod count;
+pub mod delete_by_query;
+pub mod update_by_query;
+pub mod reindex;
+pub mod conflicts;
+pub mod slice;
+pub mod params;
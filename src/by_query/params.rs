@@ -0,0 +1,101 @@
+use std::fmt::{Display, Formatter};
+use serde::{Deserialize, Serialize};
+
+/// URL parameters of `_delete_by_query`, `_update_by_query` and `_reindex`,
+/// serializes for query-string encoders and displays as `name=value&...`
+/// https://opensearch.org/docs/latest/api-reference/document-apis/delete-by-query/#url-parameters
+///
+/// Examples
+/// ```
+/// use os_query_builder_rs::by_query::params::ByQueryParams;
+///
+/// let params = ByQueryParams::new()
+///             .wait_for_completion(false)
+///             .requests_per_second(500)
+///             .scroll_size(1000u64);
+///
+/// assert_eq!(format!("/products/_delete_by_query?{params}"),
+///            "/products/_delete_by_query?wait_for_completion=false&requests_per_second=500&scroll_size=1000");
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ByQueryParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    refresh: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    wait_for_completion: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    requests_per_second: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scroll_size: Option<u64>,
+}
+
+impl ByQueryParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Refreshes the affected shards when the operation completes
+    pub fn refresh(self, refresh: bool) -> Self {
+        Self {
+            refresh: Some(refresh),
+            ..self
+        }
+    }
+
+    /// `false` runs the operation as a task and returns its id
+    pub fn wait_for_completion(self, wait_for_completion: bool) -> Self {
+        Self {
+            wait_for_completion: Some(wait_for_completion),
+            ..self
+        }
+    }
+
+    /// Throttling of the operation, `-1` disables it
+    pub fn requests_per_second<T: Into<f64>>(self, requests_per_second: T) -> Self {
+        Self {
+            requests_per_second: Some(requests_per_second.into()),
+            ..self
+        }
+    }
+
+    /// Number of documents fetched by each scroll batch
+    pub fn scroll_size<T: Into<u64>>(self, scroll_size: T) -> Self {
+        Self {
+            scroll_size: Some(scroll_size.into()),
+            ..self
+        }
+    }
+
+    /// Parameters that are set, in declaration order
+    pub fn pairs(&self) -> Vec<(&'static str, String)> {
+        let mut pairs = Vec::new();
+        if let Some(refresh) = self.refresh {
+            pairs.push(("refresh", refresh.to_string()));
+        }
+        if let Some(wait_for_completion) = self.wait_for_completion {
+            pairs.push(("wait_for_completion", wait_for_completion.to_string()));
+        }
+        if let Some(requests_per_second) = self.requests_per_second {
+            pairs.push(("requests_per_second", requests_per_second.to_string()));
+        }
+        if let Some(scroll_size) = self.scroll_size {
+            pairs.push(("scroll_size", scroll_size.to_string()));
+        }
+        pairs
+    }
+}
+
+impl Display for ByQueryParams {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, (name, value)) in self.pairs().iter().enumerate() {
+            if i > 0 {
+                f.write_str("&")?;
+            }
+            write!(f, "{name}={value}")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,294 @@
+use serde::{Deserialize, Serialize};
+use crate::bulk::metadata::VersionType;
+use crate::by_query::{conflicts::Conflicts, slice::Slice};
+use crate::misc::query_field::QueryField;
+use crate::misc::script::Script;
+use crate::misc::source_filter::SourceFilter;
+
+/// Body of the `_reindex` endpoint
+/// https://opensearch.org/docs/latest/api-reference/document-apis/reindex/
+///
+/// Examples
+/// ```
+/// use os_query_builder_rs::by_query::reindex::{OpType, Reindex, ReindexDest, ReindexSource};
+/// use os_query_builder_rs::term::term::Term;
+///
+/// let reindex = Reindex::new(
+///             ReindexSource::new(["products"]).query(Term::new("brand", "FIAT")),
+///             ReindexDest::new("products-fiat").op_type(OpType::Create)
+///         )
+///         .max_docs(1000u64);
+///
+/// assert_eq!(
+///     serde_json::to_string(&reindex).unwrap(),
+///     r#"{"source":{"index":["products"],"query":{"term":{"brand":{"value":"FIAT"}}}},"dest":{"index":"products-fiat","op_type":"create"},"max_docs":1000}"#
+/// );
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Reindex {
+    source: ReindexSource,
+
+    dest: ReindexDest,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    script: Option<Script>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_docs: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    conflicts: Option<Conflicts>,
+}
+
+impl Reindex {
+    pub fn new<T, F>(source: T, dest: F) -> Self
+        where
+            T: Into<ReindexSource>,
+            F: Into<ReindexDest>
+    {
+        Self {
+            source: source.into(),
+            dest: dest.into(),
+            script: None,
+            max_docs: None,
+            conflicts: None,
+        }
+    }
+
+    /// Script run on each document before it is indexed into the destination
+    pub fn script<T: Into<Script>>(self, script: T) -> Self {
+        Self {
+            script: Some(script.into()),
+            ..self
+        }
+    }
+
+    /// Maximum number of documents to reindex
+    pub fn max_docs<T: Into<u64>>(self, max_docs: T) -> Self {
+        Self {
+            max_docs: Some(max_docs.into()),
+            ..self
+        }
+    }
+
+    pub fn conflicts<T: Into<Conflicts>>(self, conflicts: T) -> Self {
+        Self {
+            conflicts: Some(conflicts.into()),
+            ..self
+        }
+    }
+}
+
+/// Documents to copy
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ReindexSource {
+    index: Vec<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query: Option<QueryField>,
+
+    #[serde(rename = "_source", skip_serializing_if = "Option::is_none")]
+    source: Option<SourceFilter>,
+
+    /// Batch size of the scroll
+    #[serde(skip_serializing_if = "Option::is_none")]
+    size: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    slice: Option<Slice>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    remote: Option<RemoteSource>,
+}
+
+impl ReindexSource {
+    pub fn new<F, T>(index: F) -> Self
+        where
+            F: IntoIterator<Item=T>,
+            T: Into<String>
+    {
+        Self {
+            index: index.into_iter().map(|x| x.into()).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Copies only the matching documents
+    pub fn query<T: Into<QueryField>>(self, query: T) -> Self {
+        Self {
+            query: Some(query.into()),
+            ..self
+        }
+    }
+
+    /// Fields to copy, a list of fields or [`SourceRules`](crate::misc::source_filter::SourceRules)
+    pub fn source<T: Into<SourceFilter>>(self, source: T) -> Self {
+        Self {
+            source: Some(source.into()),
+            ..self
+        }
+    }
+
+    /// Number of documents fetched by each scroll batch
+    pub fn size<T: Into<u64>>(self, size: T) -> Self {
+        Self {
+            size: Some(size.into()),
+            ..self
+        }
+    }
+
+    pub fn slice<T: Into<Slice>>(self, slice: T) -> Self {
+        Self {
+            slice: Some(slice.into()),
+            ..self
+        }
+    }
+
+    /// Reads the source indices from another cluster
+    pub fn remote<T: Into<RemoteSource>>(self, remote: T) -> Self {
+        Self {
+            remote: Some(remote.into()),
+            ..self
+        }
+    }
+}
+
+impl From<&str> for ReindexSource {
+    fn from(index: &str) -> Self {
+        Self::new([index])
+    }
+}
+
+impl From<String> for ReindexSource {
+    fn from(index: String) -> Self {
+        Self::new([index])
+    }
+}
+
+/// Cluster to reindex from, its host must be allowed by `reindex.remote.allowlist`
+/// https://opensearch.org/docs/latest/im-plugin/reindex-data/#reindex-from-a-remote-cluster
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct RemoteSource {
+    host: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    username: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    password: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    socket_timeout: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    connect_timeout: Option<String>,
+}
+
+impl RemoteSource {
+    /// `scheme://host:port`, e.g. `https://old-cluster:9200`
+    pub fn new<T: Into<String>>(host: T) -> Self {
+        Self {
+            host: host.into(),
+            ..Self::default()
+        }
+    }
+
+    pub fn username<T: Into<String>>(self, username: T) -> Self {
+        Self {
+            username: Some(username.into()),
+            ..self
+        }
+    }
+
+    pub fn password<T: Into<String>>(self, password: T) -> Self {
+        Self {
+            password: Some(password.into()),
+            ..self
+        }
+    }
+
+    /// Timeout of socket reads, e.g. `1m`
+    pub fn socket_timeout<T: Into<String>>(self, socket_timeout: T) -> Self {
+        Self {
+            socket_timeout: Some(socket_timeout.into()),
+            ..self
+        }
+    }
+
+    /// Timeout of the connection, e.g. `10s`
+    pub fn connect_timeout<T: Into<String>>(self, connect_timeout: T) -> Self {
+        Self {
+            connect_timeout: Some(connect_timeout.into()),
+            ..self
+        }
+    }
+}
+
+/// Index the documents are copied into
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ReindexDest {
+    index: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    op_type: Option<OpType>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pipeline: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version_type: Option<VersionType>,
+}
+
+impl ReindexDest {
+    pub fn new<T: Into<String>>(index: T) -> Self {
+        Self {
+            index: index.into(),
+            ..Self::default()
+        }
+    }
+
+    pub fn op_type<T: Into<OpType>>(self, op_type: T) -> Self {
+        Self {
+            op_type: Some(op_type.into()),
+            ..self
+        }
+    }
+
+    /// Ingest pipeline run on each document
+    pub fn pipeline<T: Into<String>>(self, pipeline: T) -> Self {
+        Self {
+            pipeline: Some(pipeline.into()),
+            ..self
+        }
+    }
+
+    /// `external` keeps the source versions and only overwrites older documents
+    pub fn version_type<T: Into<VersionType>>(self, version_type: T) -> Self {
+        Self {
+            version_type: Some(version_type.into()),
+            ..self
+        }
+    }
+}
+
+impl From<&str> for ReindexDest {
+    fn from(index: &str) -> Self {
+        Self::new(index)
+    }
+}
+
+impl From<String> for ReindexDest {
+    fn from(index: String) -> Self {
+        Self::new(index)
+    }
+}
+
+/// https://opensearch.org/docs/latest/api-reference/document-apis/reindex/#request-body
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OpType {
+    /// Creates or replaces documents, the default
+    Index,
+    /// Creates missing documents only, existing ones count as conflicts
+    Create,
+}
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+/// Manual slice of a scroll, `id` goes from `0` to `max - 1`
+/// https://opensearch.org/docs/latest/api-reference/document-apis/reindex/#request-body
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Slice {
+    id: u32,
+    max: u32,
+}
+
+impl Slice {
+    pub fn new(id: u32, max: u32) -> Self {
+        Self { id, max }
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn max(&self) -> u32 {
+        self.max
+    }
+}
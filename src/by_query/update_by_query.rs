@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+use crate::by_query::{conflicts::Conflicts, slice::Slice};
+use crate::misc::query_field::QueryField;
+use crate::misc::script::Script;
+
+/// Body of the `_update_by_query` endpoint, without a script documents are reindexed as they are,
+/// e.g. to pick up mapping changes
+/// https://opensearch.org/docs/latest/api-reference/document-apis/update-by-query/
+///
+/// Examples
+/// ```
+/// use os_query_builder_rs::by_query::update_by_query::UpdateByQuery;
+/// use os_query_builder_rs::misc::script::Script;
+/// use os_query_builder_rs::term::term::Term;
+///
+/// let update = UpdateByQuery::new()
+///             .query(Term::new("brand", "FIAT"))
+///             .script(Script::new().source("ctx._source.archived = true").lang("painless"));
+///
+/// assert_eq!(
+///     serde_json::to_string(&update).unwrap(),
+///     r#"{"query":{"term":{"brand":{"value":"FIAT"}}},"script":{"source":"ctx._source.archived = true","lang":"painless"}}"#
+/// );
+/// ```
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct UpdateByQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query: Option<QueryField>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    script: Option<Script>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_docs: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    conflicts: Option<Conflicts>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    slice: Option<Slice>,
+}
+
+impl UpdateByQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn query<T: Into<QueryField>>(self, query: T) -> Self {
+        Self {
+            query: Some(query.into()),
+            ..self
+        }
+    }
+
+    pub fn script<T: Into<Script>>(self, script: T) -> Self {
+        Self {
+            script: Some(script.into()),
+            ..self
+        }
+    }
+
+    /// Maximum number of documents to update
+    pub fn max_docs<T: Into<u64>>(self, max_docs: T) -> Self {
+        Self {
+            max_docs: Some(max_docs.into()),
+            ..self
+        }
+    }
+
+    pub fn conflicts<T: Into<Conflicts>>(self, conflicts: T) -> Self {
+        Self {
+            conflicts: Some(conflicts.into()),
+            ..self
+        }
+    }
+
+    pub fn slice<T: Into<Slice>>(self, slice: T) -> Self {
+        Self {
+            slice: Some(slice.into()),
+            ..self
+        }
+    }
+}
@@ -1,23 +1,30 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use crate::misc::minimum_should_match::MinimumShouldMatch;
 use crate::misc::query_field::QueryField;
+use crate::misc::validate::{child, Validate, ValidationError};
+use crate::misc::visit::{Visitor, VisitorMut, Walk};
 
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct Bool {
     #[serde(skip_serializing_if = "Option::is_none")]
-    must: Option<Vec<QueryField>>,
+    pub(crate) must: Option<Vec<QueryField>>,
     
     #[serde(skip_serializing_if = "Option::is_none")]
-    must_not: Option<Vec<QueryField>>,
+    pub(crate) must_not: Option<Vec<QueryField>>,
     
     #[serde(skip_serializing_if = "Option::is_none")]
-    filter: Option<Vec<QueryField>>,
+    pub(crate) filter: Option<Vec<QueryField>>,
     
     #[serde(skip_serializing_if = "Option::is_none")]
-    should: Option<Vec<QueryField>>,
+    pub(crate) should: Option<Vec<QueryField>>,
     
     #[serde(skip_serializing_if = "Option::is_none")]
-    minimum_should_match: Option<usize>
+    pub(crate) minimum_should_match: Option<MinimumShouldMatch>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) boost: Option<f64>
     
 }
 
@@ -29,10 +36,12 @@ impl Bool {
             must_not: None,
             should: None,
             filter: None,
-            minimum_should_match: None
+            minimum_should_match: None,
+            boost: None
         }
     }
     
+    /// Replaces `must` clauses, use [`Bool::add_must`] or [`Bool::extend_must`] to append
     pub fn must<T,F>(self, must_value: F) -> Self
         where T: Into<QueryField>,
             F: IntoIterator<Item = T>
@@ -44,6 +53,7 @@ impl Bool {
         }
     }
     
+    /// Replaces `must_not` clauses, use [`Bool::add_must_not`] or [`Bool::extend_must_not`] to append
     pub fn must_not<T,F>(self, must_value: F) -> Self
         where T: Into<QueryField>,
             F: IntoIterator<Item = T>
@@ -55,6 +65,7 @@ impl Bool {
         }
     }
     
+    /// Replaces `should` clauses, use [`Bool::add_should`] or [`Bool::extend_should`] to append
     pub fn should<T,F>(self, must_value: F) -> Self
         where T: Into<QueryField>,
             F: IntoIterator<Item = T>
@@ -66,6 +77,7 @@ impl Bool {
         }
     }
     
+    /// Replaces `filter` clauses, use [`Bool::add_filter`] or [`Bool::extend_filter`] to append
     pub fn filter<T,F>(self, filter_values: F) -> Self
         where T: Into<QueryField>,
             F: IntoIterator<Item = T>
@@ -77,11 +89,127 @@ impl Bool {
         }
     }
     
-    pub fn minimum_should_match<T: Into<usize>>(self, minimum_should_match : T) -> Self {
+    pub fn minimum_should_match<T: Into<MinimumShouldMatch>>(self, minimum_should_match : T) -> Self {
         Self {
             minimum_should_match: Some(minimum_should_match.into()),
             ..self
         }
     }
+
+    pub fn boost<T: Into<f64>>(self, boost: T) -> Self {
+        Self {
+            boost: Some(boost.into()),
+            ..self
+        }
+    }
     
+}
+
+/// Appending and conditional builders for every clause of `bool`
+macro_rules! clause_methods {
+    ($(($clause:ident, $add:ident, $extend:ident, $push:ident, $append:ident, $when:ident, $opt:ident)),*) => {
+        impl Bool {
+            $(
+                #[doc = concat!("Appends one clause to `", stringify!($clause), "`")]
+                pub fn $add<T: Into<QueryField>>(mut self, value: T) -> Self {
+                    self.$push(value);
+                    self
+                }
+
+                #[doc = concat!("Appends clauses to `", stringify!($clause), "`")]
+                pub fn $extend<T, F>(mut self, values: F) -> Self
+                    where T: Into<QueryField>,
+                          F: IntoIterator<Item = T>
+                {
+                    self.$append(values);
+                    self
+                }
+
+                #[doc = concat!("Appends one clause to `", stringify!($clause), "` in place")]
+                pub fn $push<T: Into<QueryField>>(&mut self, value: T) -> &mut Self {
+                    self.$clause.get_or_insert_with(Vec::new).push(value.into());
+                    self
+                }
+
+                #[doc = concat!("Appends clauses to `", stringify!($clause), "` in place")]
+                pub fn $append<T, F>(&mut self, values: F) -> &mut Self
+                    where T: Into<QueryField>,
+                          F: IntoIterator<Item = T>
+                {
+                    self.$clause.get_or_insert_with(Vec::new).extend(values.into_iter().map(|x| x.into()));
+                    self
+                }
+
+                #[doc = concat!("Appends the clause to `", stringify!($clause), "` when `condition` is true")]
+                pub fn $when<T: Into<QueryField>>(self, condition: bool, value: T) -> Self {
+                    if condition {
+                        self.$add(value)
+                    } else {
+                        self
+                    }
+                }
+
+                #[doc = concat!("Appends the clause to `", stringify!($clause), "` when it is `Some`")]
+                pub fn $opt<T: Into<QueryField>>(self, value: Option<T>) -> Self {
+                    match value {
+                        Some(value) => self.$add(value),
+                        None => self,
+                    }
+                }
+            )*
+        }
+    }
+}
+
+clause_methods! {
+    (must, add_must, extend_must, push_must, append_must, must_if, must_opt),
+    (must_not, add_must_not, extend_must_not, push_must_not, append_must_not, must_not_if, must_not_opt),
+    (should, add_should, extend_should, push_should, append_should, should_if, should_opt),
+    (filter, add_filter, extend_filter, push_filter, append_filter, filter_if, filter_opt)
+}
+
+impl Validate for Bool {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        self.must.validate_at(&child(path, "must"), errors);
+        self.must_not.validate_at(&child(path, "must_not"), errors);
+        self.filter.validate_at(&child(path, "filter"), errors);
+        self.should.validate_at(&child(path, "should"), errors);
+        self.minimum_should_match.validate_at(&child(path, "minimum_should_match"), errors);
+    }
+}
+
+impl Walk for Bool {
+    fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        for query in self.must.iter().flatten() {
+            visitor.visit_query(query);
+        }
+        for query in self.must_not.iter().flatten() {
+            visitor.visit_query(query);
+        }
+        for query in self.filter.iter().flatten() {
+            visitor.visit_query(query);
+        }
+        for query in self.should.iter().flatten() {
+            visitor.visit_query(query);
+        }
+        if let Some(boost) = self.boost {
+            visitor.visit_boost(boost);
+        }
+    }
+
+    fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        for query in self.must.iter_mut().flatten() {
+            visitor.visit_query_mut(query);
+        }
+        for query in self.must_not.iter_mut().flatten() {
+            visitor.visit_query_mut(query);
+        }
+        for query in self.filter.iter_mut().flatten() {
+            visitor.visit_query_mut(query);
+        }
+        for query in self.should.iter_mut().flatten() {
+            visitor.visit_query_mut(query);
+        }
+        visitor.visit_boost_mut(&mut self.boost);
+    }
 }
\ No newline at end of file
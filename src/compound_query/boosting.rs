@@ -1,7 +1,10 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use crate::misc::query_field::QueryField;
+use crate::misc::validate::{child, Validate, ValidationError};
+use crate::misc::visit::{Visitor, VisitorMut, Walk};
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct Boosting {
     positive: Box<QueryField>,
     negative: Box<QueryField>,
@@ -28,4 +31,29 @@ impl Boosting {
             ..self
         }
     }
+}
+
+impl Validate for Boosting {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        self.positive.validate_at(&child(path, "positive"), errors);
+        self.negative.validate_at(&child(path, "negative"), errors);
+
+        if let Some(negative_boost) = self.negative_boost {
+            if !(0.0..=1.0).contains(&negative_boost) {
+                errors.push(ValidationError::new(child(path, "negative_boost"), "negative_boost must be between 0 and 1"));
+            }
+        }
+    }
+}
+
+impl Walk for Boosting {
+    fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        visitor.visit_query(&self.positive);
+        visitor.visit_query(&self.negative);
+    }
+
+    fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        visitor.visit_query_mut(&mut self.positive);
+        visitor.visit_query_mut(&mut self.negative);
+    }
 }
\ No newline at end of file
@@ -1,7 +1,10 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use crate::misc::query_field::QueryField;
+use crate::misc::validate::{child, Validate, ValidationError};
+use crate::misc::visit::{Visitor, VisitorMut, Walk};
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct ConstantScore {
     filter: Box<QueryField>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -22,4 +25,24 @@ impl ConstantScore {
             ..self
         }
     }
+}
+
+impl Validate for ConstantScore {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        self.filter.validate_at(&child(path, "filter"), errors);
+    }
+}
+
+impl Walk for ConstantScore {
+    fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        visitor.visit_query(&self.filter);
+        if let Some(boost) = self.boost {
+            visitor.visit_boost(boost);
+        }
+    }
+
+    fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        visitor.visit_query_mut(&mut self.filter);
+        visitor.visit_boost_mut(&mut self.boost);
+    }
 }
\ No newline at end of file
@@ -1,7 +1,10 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use crate::misc::query_field::QueryField;
+use crate::misc::validate::{child, Validate, ValidationError};
+use crate::misc::visit::{Visitor, VisitorMut, Walk};
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct DisMax {
     queries: Vec<QueryField>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -27,4 +30,28 @@ impl DisMax {
             ..self
         }
     }
+}
+
+impl Validate for DisMax {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        let path = child(path, "queries");
+        if self.queries.is_empty() {
+            errors.push(ValidationError::new(path.as_str(), "queries are empty"));
+        }
+        self.queries.validate_at(&path, errors);
+    }
+}
+
+impl Walk for DisMax {
+    fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        for query in &self.queries {
+            visitor.visit_query(query);
+        }
+    }
+
+    fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        for query in &mut self.queries {
+            visitor.visit_query_mut(query);
+        }
+    }
 }
\ No newline at end of file
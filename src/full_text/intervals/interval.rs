@@ -1,9 +1,14 @@
 use serde::{
+    Deserialize,
+    Deserializer,
     Serialize,
     Serializer,
     ser::SerializeMap
 };
 use crate::full_text::intervals::interval_rule::IntervalRule;
+use crate::misc::field_entry::{deserialize_field_entry, FieldValue};
+use crate::misc::validate::{check_field, child, Validate, ValidationError};
+use crate::misc::visit::{Visitor, VisitorMut, Walk};
 
 #[derive(Debug, Default, Clone)]
 pub struct Intervals {
@@ -22,6 +27,21 @@ impl Serialize for Intervals {
     }
 }
 
+impl<'de> Deserialize<'de> for Intervals {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        let (field, value) = deserialize_field_entry(deserializer)?;
+        Ok(Self {
+            field: Some(field),
+            value: Some(value)
+        })
+    }
+}
+
+impl FieldValue for IntervalRule {}
+
 
 impl Intervals {
 
@@ -42,4 +62,36 @@ impl Intervals {
             ..self
         }
     }
+}
+
+impl Validate for Intervals {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        check_field(self.field.as_deref(), path, errors);
+
+        let path = child(path, self.field.as_deref().unwrap_or_default());
+        match &self.value {
+            Some(rule) => rule.validate_at(&path, errors),
+            None => errors.push(ValidationError::new(path, "interval rule is missing")),
+        }
+    }
+}
+
+impl Walk for Intervals {
+    fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        if let Some(field) = &self.field {
+            visitor.visit_field(field);
+        }
+        if let Some(rule) = &self.value {
+            visitor.visit_interval_rule(rule);
+        }
+    }
+
+    fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        if let Some(field) = &mut self.field {
+            visitor.visit_field_mut(field);
+        }
+        if let Some(rule) = &mut self.value {
+            visitor.visit_interval_rule_mut(rule);
+        }
+    }
 }
\ No newline at end of file
@@ -1,4 +1,4 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use crate::full_text::intervals::rules::{
     r#match::MatchRule,
     prefix::PrefixRule,
@@ -7,8 +7,10 @@ use crate::full_text::intervals::rules::{
     all_of::AllOfRule,
     any_of::AnyOfRule
 };
+use crate::misc::validate::{child, Validate, ValidationError};
+use crate::misc::visit::{Visitor, VisitorMut, Walk};
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all="snake_case")]
 pub enum IntervalRule {
     Match(MatchRule),
@@ -42,3 +44,40 @@ from_types_enum! {
     (AllOfRule, AllOf),
     (AnyOfRule, AnyOf)
 }
+
+impl Validate for IntervalRule {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        match self {
+            IntervalRule::Match(rule) => rule.validate_at(&child(path, "match"), errors),
+            IntervalRule::Prefix(rule) => rule.validate_at(&child(path, "prefix"), errors),
+            IntervalRule::Wildcard(rule) => rule.validate_at(&child(path, "wildcard"), errors),
+            IntervalRule::Fuzzy(rule) => rule.validate_at(&child(path, "fuzzy"), errors),
+            IntervalRule::AllOf(rule) => rule.validate_at(&child(path, "all_of"), errors),
+            IntervalRule::AnyOf(rule) => rule.validate_at(&child(path, "any_of"), errors),
+        }
+    }
+}
+
+impl Walk for IntervalRule {
+    fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        match self {
+            IntervalRule::Match(rule) => rule.walk(visitor),
+            IntervalRule::Prefix(rule) => rule.walk(visitor),
+            IntervalRule::Wildcard(rule) => rule.walk(visitor),
+            IntervalRule::Fuzzy(rule) => rule.walk(visitor),
+            IntervalRule::AllOf(rule) => rule.walk(visitor),
+            IntervalRule::AnyOf(rule) => rule.walk(visitor),
+        }
+    }
+
+    fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        match self {
+            IntervalRule::Match(rule) => rule.walk_mut(visitor),
+            IntervalRule::Prefix(rule) => rule.walk_mut(visitor),
+            IntervalRule::Wildcard(rule) => rule.walk_mut(visitor),
+            IntervalRule::Fuzzy(rule) => rule.walk_mut(visitor),
+            IntervalRule::AllOf(rule) => rule.walk_mut(visitor),
+            IntervalRule::AnyOf(rule) => rule.walk_mut(visitor),
+        }
+    }
+}
\ No newline at end of file
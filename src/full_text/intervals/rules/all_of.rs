@@ -1,12 +1,15 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use crate::full_text::intervals::{
     interval_rule::IntervalRule,
     rules::filter::FilterRule
 };
+use crate::misc::validate::{child, Validate, ValidationError};
+use crate::misc::visit::{Visitor, VisitorMut, Walk};
 
-#[derive(Debug, Default, Clone, Serialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct AllOfRule {
-    interval: Vec<IntervalRule>,
+    intervals: Vec<IntervalRule>,
     #[serde(skip_serializing_if = "Option::is_none")]
     filter: Option<Box<FilterRule>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -25,7 +28,7 @@ impl AllOfRule {
             F: IntoIterator<Item=T>
     {
         Self {
-            interval: intervals.into_iter().map(|x| x.into()).collect(),
+            intervals: intervals.into_iter().map(|x| x.into()).collect(),
             ..self
         }
     }
@@ -51,3 +54,33 @@ impl AllOfRule {
         }
     }
 }
+
+impl Validate for AllOfRule {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if self.intervals.is_empty() {
+            errors.push(ValidationError::new(child(path, "intervals"), "interval rules are empty"));
+        }
+        self.intervals.validate_at(&child(path, "intervals"), errors);
+        self.filter.validate_at(&child(path, "filter"), errors);
+    }
+}
+
+impl Walk for AllOfRule {
+    fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        for rule in &self.intervals {
+            visitor.visit_interval_rule(rule);
+        }
+        if let Some(filter) = &self.filter {
+            filter.walk(visitor);
+        }
+    }
+
+    fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        for rule in &mut self.intervals {
+            visitor.visit_interval_rule_mut(rule);
+        }
+        if let Some(filter) = &mut self.filter {
+            filter.walk_mut(visitor);
+        }
+    }
+}
\ No newline at end of file
@@ -1,8 +1,10 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use crate::misc::script::Script;
 use crate::full_text::intervals::interval_rule::IntervalRule;
+use crate::misc::validate::{child, Validate, ValidationError};
+use crate::misc::visit::{Visitor, VisitorMut, Walk};
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all="snake_case")]
 pub enum FilterRule {
     After(IntervalRule),
@@ -16,4 +18,48 @@ pub enum FilterRule {
     Script(Script)
 }
 
+impl Validate for FilterRule {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        match self {
+            FilterRule::After(rule) => rule.validate_at(&child(path, "after"), errors),
+            FilterRule::Before(rule) => rule.validate_at(&child(path, "before"), errors),
+            FilterRule::ContainedBy(rule) => rule.validate_at(&child(path, "contained_by"), errors),
+            FilterRule::Containing(rule) => rule.validate_at(&child(path, "containing"), errors),
+            FilterRule::NotContainedBy(rule) => rule.validate_at(&child(path, "not_contained_by"), errors),
+            FilterRule::NotContaining(rule) => rule.validate_at(&child(path, "not_containing"), errors),
+            FilterRule::NotOverlapping(rule) => rule.validate_at(&child(path, "not_overlapping"), errors),
+            FilterRule::Overlapping(rule) => rule.validate_at(&child(path, "overlapping"), errors),
+            FilterRule::Script(script) => script.validate_at(&child(path, "script"), errors),
+        }
+    }
+}
+
+impl Walk for FilterRule {
+    fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        match self {
+            FilterRule::After(rule) => visitor.visit_interval_rule(rule),
+            FilterRule::Before(rule) => visitor.visit_interval_rule(rule),
+            FilterRule::ContainedBy(rule) => visitor.visit_interval_rule(rule),
+            FilterRule::Containing(rule) => visitor.visit_interval_rule(rule),
+            FilterRule::NotContainedBy(rule) => visitor.visit_interval_rule(rule),
+            FilterRule::NotContaining(rule) => visitor.visit_interval_rule(rule),
+            FilterRule::NotOverlapping(rule) => visitor.visit_interval_rule(rule),
+            FilterRule::Overlapping(rule) => visitor.visit_interval_rule(rule),
+            FilterRule::Script(_) => {}
+        }
+    }
 
+    fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        match self {
+            FilterRule::After(rule) => visitor.visit_interval_rule_mut(rule),
+            FilterRule::Before(rule) => visitor.visit_interval_rule_mut(rule),
+            FilterRule::ContainedBy(rule) => visitor.visit_interval_rule_mut(rule),
+            FilterRule::Containing(rule) => visitor.visit_interval_rule_mut(rule),
+            FilterRule::NotContainedBy(rule) => visitor.visit_interval_rule_mut(rule),
+            FilterRule::NotContaining(rule) => visitor.visit_interval_rule_mut(rule),
+            FilterRule::NotOverlapping(rule) => visitor.visit_interval_rule_mut(rule),
+            FilterRule::Overlapping(rule) => visitor.visit_interval_rule_mut(rule),
+            FilterRule::Script(_) => {}
+        }
+    }
+}
\ No newline at end of file
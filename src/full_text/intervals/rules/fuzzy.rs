@@ -1,7 +1,10 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use crate::misc::fuzziness::Fuzziness;
+use crate::misc::validate::{child, Validate, ValidationError};
+use crate::misc::visit::{Visitor, VisitorMut, Walk};
 
-#[derive(Debug, Default, Clone, Serialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct FuzzyRule {
     term: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -63,4 +66,26 @@ impl FuzzyRule {
             ..self
         }
     }
+}
+
+impl Validate for FuzzyRule {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if self.term.is_empty() {
+            errors.push(ValidationError::new(child(path, "term"), "term is empty"));
+        }
+    }
+}
+
+impl Walk for FuzzyRule {
+    fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        if let Some(field) = &self.use_field {
+            visitor.visit_field(field);
+        }
+    }
+
+    fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        if let Some(field) = &mut self.use_field {
+            visitor.visit_field_mut(field);
+        }
+    }
 }
\ No newline at end of file
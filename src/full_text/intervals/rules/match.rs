@@ -1,7 +1,10 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use crate::full_text::intervals::rules::filter::FilterRule;
+use crate::misc::validate::{child, Validate, ValidationError};
+use crate::misc::visit::{Visitor, VisitorMut, Walk};
 
-#[derive(Debug, Default, Clone, Serialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct MatchRule {
     query: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -64,3 +67,32 @@ impl MatchRule {
         }
     }
 }
+
+impl Validate for MatchRule {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if self.query.is_empty() {
+            errors.push(ValidationError::new(child(path, "query"), "query is empty"));
+        }
+        self.filter.validate_at(&child(path, "filter"), errors);
+    }
+}
+
+impl Walk for MatchRule {
+    fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        if let Some(field) = &self.use_field {
+            visitor.visit_field(field);
+        }
+        if let Some(filter) = &self.filter {
+            filter.walk(visitor);
+        }
+    }
+
+    fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        if let Some(field) = &mut self.use_field {
+            visitor.visit_field_mut(field);
+        }
+        if let Some(filter) = &mut self.filter {
+            filter.walk_mut(visitor);
+        }
+    }
+}
\ No newline at end of file
@@ -1,6 +1,9 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use crate::misc::validate::{child, Validate, ValidationError};
+use crate::misc::visit::{Visitor, VisitorMut, Walk};
 
-#[derive(Debug, Default, Clone, Serialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct PrefixRule {
     prefix: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -37,3 +40,25 @@ impl PrefixRule {
         }
     }
 }
+
+impl Validate for PrefixRule {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if self.prefix.is_empty() {
+            errors.push(ValidationError::new(child(path, "prefix"), "prefix is empty"));
+        }
+    }
+}
+
+impl Walk for PrefixRule {
+    fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        if let Some(field) = &self.use_field {
+            visitor.visit_field(field);
+        }
+    }
+
+    fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        if let Some(field) = &mut self.use_field {
+            visitor.visit_field_mut(field);
+        }
+    }
+}
\ No newline at end of file
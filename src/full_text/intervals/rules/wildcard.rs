@@ -1,6 +1,9 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use crate::misc::validate::{child, Validate, ValidationError};
+use crate::misc::visit::{Visitor, VisitorMut, Walk};
 
-#[derive(Debug, Default, Clone, Serialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct WildcardRule {
     pattern: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -36,4 +39,26 @@ impl WildcardRule {
             ..self
         }
     }
+}
+
+impl Validate for WildcardRule {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if self.pattern.is_empty() {
+            errors.push(ValidationError::new(child(path, "pattern"), "pattern is empty"));
+        }
+    }
+}
+
+impl Walk for WildcardRule {
+    fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        if let Some(field) = &self.use_field {
+            visitor.visit_field(field);
+        }
+    }
+
+    fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        if let Some(field) = &mut self.use_field {
+            visitor.visit_field_mut(field);
+        }
+    }
 }
\ No newline at end of file
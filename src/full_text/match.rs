@@ -1,10 +1,14 @@
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde::ser::SerializeMap;
 use serde_json::Value;
+use crate::misc::minimum_should_match::MinimumShouldMatch;
 use crate::misc::fuzziness::Fuzziness;
 
 use crate::misc::operator::Operator;
 use crate::misc::zero_terms_query::ZeroTermsQuery;
+use crate::misc::field_entry::{deserialize_field_entry, FieldValue};
+use crate::misc::validate::{check_field, child, Validate, ValidationError};
+use crate::misc::visit::{Visitor, VisitorMut, Walk};
 
 #[derive(Debug, Default, Clone)]
 pub struct Match {
@@ -12,7 +16,8 @@ pub struct Match {
     value: MatchValues,
 }
 
-#[derive(Debug, Default, Clone, Serialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct MatchValues {
     query: Option<Value>,
 
@@ -26,7 +31,7 @@ pub struct MatchValues {
     operator: Option<Operator>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    minimum_should_match: Option<String>,
+    minimum_should_match: Option<MinimumShouldMatch>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     analyzer: Option<String>,
@@ -58,6 +63,28 @@ impl Serialize for Match {
     }
 }
 
+impl<'de> Deserialize<'de> for Match {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        let (field, value) = deserialize_field_entry(deserializer)?;
+        Ok(Self {
+            field: Some(field),
+            value
+        })
+    }
+}
+
+impl FieldValue for MatchValues {
+    fn from_short(value: Value) -> Option<Self> {
+        Some(Self {
+            query: Some(value),
+            ..Self::default()
+        })
+    }
+}
+
 impl Match {
     pub fn new() -> Self {
         Self::default()
@@ -154,7 +181,7 @@ impl Match {
         Self { value, ..self }
     }
 
-    pub fn minimum_should_match<T: Into<String>>(self, minimum_should_match: T) -> Self {
+    pub fn minimum_should_match<T: Into<MinimumShouldMatch>>(self, minimum_should_match: T) -> Self {
         let value = MatchValues {
             minimum_should_match: Some(minimum_should_match.into()),
             ..self.value
@@ -162,4 +189,33 @@ impl Match {
         Self { value, ..self }
     }
 
+}
+
+impl Validate for Match {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        check_field(self.field.as_deref(), path, errors);
+        let path = child(path, self.field.as_deref().unwrap_or_default());
+        if self.value.query.is_none() {
+            errors.push(ValidationError::new(child(&path, "query"), "query is missing"));
+        }
+        self.value.minimum_should_match.validate_at(&child(&path, "minimum_should_match"), errors);
+    }
+}
+
+impl Walk for Match {
+    fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        if let Some(field) = &self.field {
+            visitor.visit_field(field);
+        }
+        if let Some(boost) = self.value.boost {
+            visitor.visit_boost(boost);
+        }
+    }
+
+    fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        if let Some(field) = &mut self.field {
+            visitor.visit_field_mut(field);
+        }
+        visitor.visit_boost_mut(&mut self.value.boost);
+    }
 }
\ No newline at end of file
@@ -1,13 +1,20 @@
 use serde::{
+    Deserialize,
+    Deserializer,
     Serialize,
     Serializer,
     ser::SerializeMap
 };
+use crate::misc::minimum_should_match::MinimumShouldMatch;
 use crate::misc::{
     fuzziness::Fuzziness,
     operator::Operator,
     rewrite::Rewrite
 };
+use serde_json::Value;
+use crate::misc::field_entry::{deserialize_field_entry, FieldValue};
+use crate::misc::validate::{check_field, child, Validate, ValidationError};
+use crate::misc::visit::{Visitor, VisitorMut, Walk};
 
 #[derive(Debug, Default, Clone)]
 pub struct MatchBoolPrefix {
@@ -15,7 +22,8 @@ pub struct MatchBoolPrefix {
     value: MatchBoolPrefixValue
 }
 
-#[derive(Debug, Default, Clone, Serialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 struct MatchBoolPrefixValue {
     query: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -29,7 +37,7 @@ struct MatchBoolPrefixValue {
     #[serde(skip_serializing_if = "Option::is_none")]
     max_expansions: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    minimum_should_match: Option<String>,
+    minimum_should_match: Option<MinimumShouldMatch>,
     #[serde(skip_serializing_if = "Option::is_none")]
     operator: Option<Operator>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -47,6 +55,28 @@ impl Serialize for MatchBoolPrefix {
     }
 }
 
+impl<'de> Deserialize<'de> for MatchBoolPrefix {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        let (field, value) = deserialize_field_entry(deserializer)?;
+        Ok(Self {
+            field: Some(field),
+            value
+        })
+    }
+}
+
+impl FieldValue for MatchBoolPrefixValue {
+    fn from_short(value: Value) -> Option<Self> {
+        value.as_str().map(|query| Self {
+            query: query.into(),
+            ..Self::default()
+        })
+    }
+}
+
 
 impl MatchBoolPrefix {
     pub fn new() -> Self {
@@ -125,11 +155,36 @@ impl MatchBoolPrefix {
         Self { value, ..self }
     }
 
-    pub fn minimum_should_match<T: Into<String>>(self, minimum_should_match: T) -> Self {
+    pub fn minimum_should_match<T: Into<MinimumShouldMatch>>(self, minimum_should_match: T) -> Self {
         let value = MatchBoolPrefixValue {
             minimum_should_match: Some(minimum_should_match.into()),
             ..self.value
         };
         Self { value, ..self }
     }
+}
+
+impl Validate for MatchBoolPrefix {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        check_field(self.field.as_deref(), path, errors);
+        let path = child(path, self.field.as_deref().unwrap_or_default());
+        if self.value.query.is_empty() {
+            errors.push(ValidationError::new(child(&path, "query"), "query is missing"));
+        }
+        self.value.minimum_should_match.validate_at(&child(&path, "minimum_should_match"), errors);
+    }
+}
+
+impl Walk for MatchBoolPrefix {
+    fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        if let Some(field) = &self.field {
+            visitor.visit_field(field);
+        }
+    }
+
+    fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        if let Some(field) = &mut self.field {
+            visitor.visit_field_mut(field);
+        }
+    }
 }
\ No newline at end of file
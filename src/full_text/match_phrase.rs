@@ -1,5 +1,9 @@
+use serde::{Deserialize, Deserializer};
 use serde::ser::{Serialize, SerializeMap, Serializer};
 use serde_json::Value;
+use crate::misc::field_entry::{deserialize_field_entry, FieldValue};
+use crate::misc::validate::{check_field, child, Validate, ValidationError};
+use crate::misc::visit::{Visitor, VisitorMut, Walk};
 
 #[derive(Debug, Default, Clone)]
 pub struct MatchPhrase {
@@ -47,6 +51,14 @@ impl MatchPhrase {
         Self { value, ..self }
     }
 
+    pub fn boost<T: Into<f64>>(self, boost: T) -> Self {
+        let value = MatchPhraseValues {
+            boost: Some(boost.into()),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
     pub fn zero_terms_query<T: Into<String>>(self, zero_terms_query: T) -> Self {
         let value = MatchPhraseValues {
             zero_terms_query: Some(zero_terms_query.into()),
@@ -56,7 +68,8 @@ impl MatchPhrase {
     }
 }
 
-#[derive(Debug, Default, Clone, serde::Serialize)]
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
 struct MatchPhraseValues {
     query: Option<Value>,
 
@@ -68,6 +81,9 @@ struct MatchPhraseValues {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     zero_terms_query: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    boost: Option<f64>,
 }
 
 impl Serialize for MatchPhrase {
@@ -80,3 +96,53 @@ impl Serialize for MatchPhrase {
         state.end()
     }
 }
+
+impl<'de> Deserialize<'de> for MatchPhrase {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        let (field, value) = deserialize_field_entry(deserializer)?;
+        Ok(Self {
+            field: Some(field),
+            value
+        })
+    }
+}
+
+impl FieldValue for MatchPhraseValues {
+    fn from_short(value: Value) -> Option<Self> {
+        Some(Self {
+            query: Some(value),
+            ..Self::default()
+        })
+    }
+}
+
+impl Validate for MatchPhrase {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        check_field(self.field.as_deref(), path, errors);
+        if self.value.query.is_none() {
+            let path = child(path, self.field.as_deref().unwrap_or_default());
+            errors.push(ValidationError::new(child(&path, "query"), "query is missing"));
+        }
+    }
+}
+
+impl Walk for MatchPhrase {
+    fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        if let Some(field) = &self.field {
+            visitor.visit_field(field);
+        }
+        if let Some(boost) = self.value.boost {
+            visitor.visit_boost(boost);
+        }
+    }
+
+    fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        if let Some(field) = &mut self.field {
+            visitor.visit_field_mut(field);
+        }
+        visitor.visit_boost_mut(&mut self.value.boost);
+    }
+}
\ No newline at end of file
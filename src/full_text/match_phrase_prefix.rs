@@ -1,5 +1,9 @@
+use serde::{Deserialize, Deserializer};
 use serde::ser::{Serialize, SerializeMap, Serializer};
 use serde_json::Value;
+use crate::misc::field_entry::{deserialize_field_entry, FieldValue};
+use crate::misc::validate::{check_field, child, Validate, ValidationError};
+use crate::misc::visit::{Visitor, VisitorMut, Walk};
 
 #[derive(Debug, Default, Clone)]
 pub struct MatchPhrasePrefix {
@@ -56,7 +60,8 @@ impl MatchPhrasePrefix {
     }
 }
 
-#[derive(Debug, Default, Clone, serde::Serialize)]
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
 struct MatchPhrasePrefixValues {
     query: Option<Value>,
 
@@ -79,4 +84,50 @@ impl Serialize for MatchPhrasePrefix {
         state.serialize_entry(self.field.as_deref().unwrap_or_default(), &self.value)?;
         state.end()
     }
+}
+
+impl<'de> Deserialize<'de> for MatchPhrasePrefix {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        let (field, value) = deserialize_field_entry(deserializer)?;
+        Ok(Self {
+            field: Some(field),
+            value
+        })
+    }
+}
+
+impl FieldValue for MatchPhrasePrefixValues {
+    fn from_short(value: Value) -> Option<Self> {
+        Some(Self {
+            query: Some(value),
+            ..Self::default()
+        })
+    }
+}
+
+impl Validate for MatchPhrasePrefix {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        check_field(self.field.as_deref(), path, errors);
+        if self.value.query.is_none() {
+            let path = child(path, self.field.as_deref().unwrap_or_default());
+            errors.push(ValidationError::new(child(&path, "query"), "query is missing"));
+        }
+    }
+}
+
+impl Walk for MatchPhrasePrefix {
+    fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        if let Some(field) = &self.field {
+            visitor.visit_field(field);
+        }
+    }
+
+    fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        if let Some(field) = &mut self.field {
+            visitor.visit_field_mut(field);
+        }
+    }
 }
\ No newline at end of file
@@ -1,12 +1,16 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use crate::misc::minimum_should_match::MinimumShouldMatch;
 use crate::misc::fuzziness::Fuzziness;
 use crate::misc::operator::Operator;
 use crate::misc::r#type::Type;
 use crate::misc::zero_terms_query::ZeroTermsQuery;
+use crate::misc::validate::{child, Validate, ValidationError};
+use crate::misc::visit::{walk_boosted_field, walk_boosted_field_mut, Visitor, VisitorMut, Walk};
 
-#[derive(Debug, Default, Clone, Serialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct MultiMatch {
     query: Option<Value>,
 
@@ -20,7 +24,7 @@ pub struct MultiMatch {
     operator: Option<Operator>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    minimum_should_match: Option<String>,
+    minimum_should_match: Option<MinimumShouldMatch>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     tie_breaker: Option<f64>,
@@ -100,7 +104,7 @@ impl MultiMatch {
         }
     }
 
-    pub fn minimum_should_match<T: Into<String>>(self, minimum_should_match: T) -> Self {
+    pub fn minimum_should_match<T: Into<MinimumShouldMatch>>(self, minimum_should_match: T) -> Self {
         Self {
             minimum_should_match: Some(minimum_should_match.into()),
             ..self
@@ -179,4 +183,31 @@ impl MultiMatch {
             ..self
         }
     }
+}
+
+impl Validate for MultiMatch {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if self.query.is_none() {
+            errors.push(ValidationError::new(child(path, "query"), "query is missing"));
+        }
+        self.minimum_should_match.validate_at(&child(path, "minimum_should_match"), errors);
+    }
+}
+
+impl Walk for MultiMatch {
+    fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        for field in self.fields.iter().flatten() {
+            walk_boosted_field(visitor, field);
+        }
+        if let Some(boost) = self.boost {
+            visitor.visit_boost(boost);
+        }
+    }
+
+    fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        for field in self.fields.iter_mut().flatten() {
+            walk_boosted_field_mut(visitor, field);
+        }
+        visitor.visit_boost_mut(&mut self.boost);
+    }
 }
\ No newline at end of file
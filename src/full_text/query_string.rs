@@ -1,9 +1,13 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use crate::misc::minimum_should_match::MinimumShouldMatch;
 use crate::misc::fuzziness::Fuzziness;
 use crate::misc::operator::Operator;
 use crate::misc::r#type::Type;
+use crate::misc::validate::{child, Validate, ValidationError};
+use crate::misc::visit::{Visitor, VisitorMut, Walk};
 
-#[derive(Debug, Default, Clone, Serialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct QueryString {
     query: Option<String>,
 
@@ -26,7 +30,7 @@ pub struct QueryString {
     fuzzy_prefix_length: Option<u64>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    minimum_should_match: Option<String>,
+    minimum_should_match: Option<MinimumShouldMatch>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     default_operator: Option<Operator>,
@@ -126,7 +130,7 @@ impl QueryString {
         }
     }
 
-    pub fn minimum_should_match<T: Into<String>>(self, minimum_should_match: T) -> Self {
+    pub fn minimum_should_match<T: Into<MinimumShouldMatch>>(self, minimum_should_match: T) -> Self {
         Self {
             minimum_should_match: Some(minimum_should_match.into()),
             ..self
@@ -226,4 +230,31 @@ impl QueryString {
             ..self
         }
     }
+}
+
+impl Validate for QueryString {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if self.query.is_none() {
+            errors.push(ValidationError::new(child(path, "query"), "query is missing"));
+        }
+        self.minimum_should_match.validate_at(&child(path, "minimum_should_match"), errors);
+    }
+}
+
+impl Walk for QueryString {
+    fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        if let Some(field) = &self.default_field {
+            visitor.visit_field(field);
+        }
+        if let Some(boost) = self.boost {
+            visitor.visit_boost(boost);
+        }
+    }
+
+    fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        if let Some(field) = &mut self.default_field {
+            visitor.visit_field_mut(field);
+        }
+        visitor.visit_boost_mut(&mut self.boost);
+    }
 }
\ No newline at end of file
@@ -1,13 +1,18 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use crate::misc::minimum_should_match::MinimumShouldMatch;
 use crate::misc::operator::Operator;
+use crate::misc::simple_query_string_flag::SimpleQueryStringFlags;
+use crate::misc::validate::{child, Validate, ValidationError};
+use crate::misc::visit::{walk_boosted_field, walk_boosted_field_mut, Visitor, VisitorMut, Walk};
 
-#[derive(Debug, Default, Clone, Serialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct SimpleQueryString {
     query: Option<String>,
     fields: Vec<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    flags: Option<String>,
+    flags: Option<SimpleQueryStringFlags>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     fuzzy_transpositions: Option<bool>,
@@ -19,7 +24,7 @@ pub struct SimpleQueryString {
     fuzzy_prefix_length: Option<u64>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    minimum_should_match: Option<String>,
+    minimum_should_match: Option<MinimumShouldMatch>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     default_operator: Option<Operator>,
@@ -73,7 +78,7 @@ impl SimpleQueryString {
         self.value(query)
     }
 
-    pub fn flags<T: Into<String>>(self, flags: T) -> Self {
+    pub fn flags<T: Into<SimpleQueryStringFlags>>(self, flags: T) -> Self {
         Self {
             flags: Some(flags.into()),
             ..self
@@ -101,7 +106,7 @@ impl SimpleQueryString {
         }
     }
 
-    pub fn minimum_should_match<T: Into<String>>(self, minimum_should_match: T) -> Self {
+    pub fn minimum_should_match<T: Into<MinimumShouldMatch>>(self, minimum_should_match: T) -> Self {
         Self {
             minimum_should_match: Some(minimum_should_match.into()),
             ..self
@@ -152,4 +157,27 @@ impl SimpleQueryString {
             ..self
         }
     }
+}
+
+impl Validate for SimpleQueryString {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if self.query.is_none() {
+            errors.push(ValidationError::new(child(path, "query"), "query is missing"));
+        }
+        self.minimum_should_match.validate_at(&child(path, "minimum_should_match"), errors);
+    }
+}
+
+impl Walk for SimpleQueryString {
+    fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        for field in &self.fields {
+            walk_boosted_field(visitor, field);
+        }
+    }
+
+    fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        for field in &mut self.fields {
+            walk_boosted_field_mut(visitor, field);
+        }
+    }
 }
\ No newline at end of file
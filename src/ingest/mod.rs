@@ -0,0 +1,3 @@
+pub mod processors;
+pub mod pipeline;
+pub mod simulate;
@@ -0,0 +1,95 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::ingest::processors::Processor;
+
+/// Body of `PUT _ingest/pipeline/<id>`
+/// https://opensearch.org/docs/latest/ingest-pipelines/create-ingest/
+///
+/// Examples
+/// ```
+/// use serde_json::json;
+/// use os_query_builder_rs::ingest::pipeline::Pipeline;
+/// use os_query_builder_rs::ingest::processors::{LowercaseProcessor, SetProcessor};
+///
+/// let pipeline = Pipeline::new()
+///             .description("normalize products")
+///             .processor(LowercaseProcessor::new("brand"))
+///             .processor(SetProcessor::new("indexed_at", "{{{_ingest.timestamp}}}").tag("timestamp"));
+///
+/// assert_eq!(serde_json::to_value(pipeline).unwrap(), json!({
+///     "description": "normalize products",
+///     "processors": [
+///         { "lowercase": { "field": "brand" } },
+///         { "set": { "field": "indexed_at", "value": "{{{_ingest.timestamp}}}", "tag": "timestamp" } }
+///     ]
+/// }));
+/// ```
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Pipeline {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+
+    processors: Vec<Processor>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    on_failure: Vec<Processor>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<u64>,
+
+    #[serde(rename = "_meta", skip_serializing_if = "Option::is_none")]
+    meta: Option<Value>,
+}
+
+impl Pipeline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn description<T: Into<String>>(self, description: T) -> Self {
+        Self {
+            description: Some(description.into()),
+            ..self
+        }
+    }
+
+    /// Appends a processor, processors run in order
+    pub fn processor<T: Into<Processor>>(mut self, processor: T) -> Self {
+        self.processors.push(processor.into());
+        self
+    }
+
+    pub fn processors<T, F>(mut self, processors: F) -> Self
+        where T: Into<Processor>,
+              F: IntoIterator<Item = T>
+    {
+        self.processors.extend(processors.into_iter().map(|x| x.into()));
+        self
+    }
+
+    /// Processors run when a processor without its own `on_failure` fails
+    pub fn on_failure<T, F>(self, on_failure: F) -> Self
+        where T: Into<Processor>,
+              F: IntoIterator<Item = T>
+    {
+        Self {
+            on_failure: on_failure.into_iter().map(|x| x.into()).collect(),
+            ..self
+        }
+    }
+
+    pub fn version<T: Into<u64>>(self, version: T) -> Self {
+        Self {
+            version: Some(version.into()),
+            ..self
+        }
+    }
+
+    /// Arbitrary metadata, not used by OpenSearch
+    pub fn meta<T: Into<Value>>(self, meta: T) -> Self {
+        Self {
+            meta: Some(meta.into()),
+            ..self
+        }
+    }
+}
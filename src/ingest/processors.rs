@@ -0,0 +1,991 @@
+use std::collections::BTreeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::Error;
+use serde::ser::SerializeMap;
+use serde_json::Value;
+use crate::misc::script::Script;
+
+/// Processor of an ingest pipeline, serialized as `{"<type>": {options}}`
+/// https://opensearch.org/docs/latest/ingest-pipelines/processors/index-processors/
+///
+/// Every processor takes the common options [`condition`](SetProcessor::condition) (`if`),
+/// `tag`, `description`, `ignore_failure` and `on_failure`.
+/// Other processor types, and processors whose options do not match the typed ones, are kept as [`OtherProcessor`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Processor {
+    Set(SetProcessor),
+    Remove(RemoveProcessor),
+    Rename(RenameProcessor),
+    Convert(ConvertProcessor),
+    Date(DateProcessor),
+    Grok(GrokProcessor),
+    Dissect(DissectProcessor),
+    Split(SplitProcessor),
+    Join(JoinProcessor),
+    Lowercase(LowercaseProcessor),
+    Gsub(GsubProcessor),
+    Script(ScriptProcessor),
+    Pipeline(PipelineProcessor),
+    Foreach(ForeachProcessor),
+    Json(JsonProcessor),
+    Drop(DropProcessor),
+    Fail(FailProcessor),
+    TextEmbedding(TextEmbeddingProcessor),
+    SparseEncoding(SparseEncodingProcessor),
+    #[serde(untagged)]
+    Other(OtherProcessor),
+}
+
+/// Processor without a typed builder, e.g. `uppercase`, `append` or `trim`
+///
+/// Examples
+/// ```
+/// use serde_json::json;
+/// use os_query_builder_rs::ingest::processors::{OtherProcessor, Processor};
+///
+/// let processor = Processor::from(OtherProcessor::new("uppercase", json!({ "field": "brand" })));
+/// assert_eq!(serde_json::to_value(processor).unwrap(), json!({ "uppercase": { "field": "brand" } }));
+/// ```
+#[derive(Debug, Clone)]
+pub struct OtherProcessor {
+    processor_type: String,
+    options: Value,
+}
+
+impl OtherProcessor {
+    pub fn new<T: Into<String>, U: Into<Value>>(processor_type: T, options: U) -> Self {
+        Self {
+            processor_type: processor_type.into(),
+            options: options.into(),
+        }
+    }
+
+    pub fn processor_type(&self) -> &str {
+        &self.processor_type
+    }
+
+    pub fn options(&self) -> &Value {
+        &self.options
+    }
+}
+
+impl From<OtherProcessor> for Processor {
+    fn from(processor: OtherProcessor) -> Self {
+        Processor::Other(processor)
+    }
+}
+
+impl Serialize for OtherProcessor {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(1))?;
+        state.serialize_entry(&self.processor_type, &self.options)?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for OtherProcessor {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        let map = BTreeMap::<String, Value>::deserialize(deserializer)?;
+        if map.len() != 1 {
+            return Err(D::Error::custom(format!("expected one processor type, found {}", map.len())));
+        }
+        let (processor_type, options) = map.into_iter().next().expect("one processor");
+        Ok(Self { processor_type, options })
+    }
+}
+
+/// Options shared by all processors. Keys left over by the processor are rejected here,
+/// so processors with options the types do not model are read as [`OtherProcessor`].
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(try_from = "ProcessorCommonFields")]
+pub struct ProcessorCommon {
+    #[serde(rename = "if", skip_serializing_if = "Option::is_none")]
+    condition: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tag: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ignore_failure: Option<bool>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    on_failure: Vec<Processor>,
+}
+
+#[derive(Deserialize)]
+struct ProcessorCommonFields {
+    #[serde(rename = "if")]
+    condition: Option<String>,
+    tag: Option<String>,
+    description: Option<String>,
+    ignore_failure: Option<bool>,
+    #[serde(default)]
+    on_failure: Vec<Processor>,
+    #[serde(flatten)]
+    unknown: BTreeMap<String, Value>,
+}
+
+impl TryFrom<ProcessorCommonFields> for ProcessorCommon {
+    type Error = String;
+
+    fn try_from(fields: ProcessorCommonFields) -> Result<Self, Self::Error> {
+        if let Some(key) = fields.unknown.keys().next() {
+            return Err(format!("unknown processor option `{key}`"));
+        }
+        Ok(Self {
+            condition: fields.condition,
+            tag: fields.tag,
+            description: fields.description,
+            ignore_failure: fields.ignore_failure,
+            on_failure: fields.on_failure,
+        })
+    }
+}
+
+/// https://opensearch.org/docs/latest/ingest-pipelines/processors/convert/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConvertType {
+    Integer,
+    Long,
+    Float,
+    Double,
+    String,
+    Boolean,
+    Ip,
+    /// Picks the type from the value
+    Auto,
+}
+
+/// Sets a field, the value may use `{{{field}}}` templates
+/// https://opensearch.org/docs/latest/ingest-pipelines/processors/set/
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetProcessor {
+    field: String,
+
+    value: Value,
+
+    #[serde(rename = "override", skip_serializing_if = "Option::is_none")]
+    override_existing: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ignore_empty_value: Option<bool>,
+
+    #[serde(flatten)]
+    common: ProcessorCommon,
+}
+
+impl SetProcessor {
+    pub fn new<T: Into<String>, U: Into<Value>>(field: T, value: U) -> Self {
+        Self {
+            field: field.into(),
+            value: value.into(),
+            override_existing: None,
+            ignore_empty_value: None,
+            common: ProcessorCommon::default(),
+        }
+    }
+
+    /// `override` in the body, `false` keeps existing non-null values
+    pub fn override_existing(self, override_existing: bool) -> Self {
+        Self {
+            override_existing: Some(override_existing),
+            ..self
+        }
+    }
+
+    /// Skips `null` and empty values
+    pub fn ignore_empty_value(self, ignore_empty_value: bool) -> Self {
+        Self {
+            ignore_empty_value: Some(ignore_empty_value),
+            ..self
+        }
+    }
+}
+
+/// https://opensearch.org/docs/latest/ingest-pipelines/processors/remove/
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoveProcessor {
+    #[serde(deserialize_with = "deserialize_one_or_many")]
+    field: Vec<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ignore_missing: Option<bool>,
+
+    #[serde(flatten)]
+    common: ProcessorCommon,
+}
+
+impl RemoveProcessor {
+    pub fn new<F: IntoIterator<Item=T>, T: Into<String>>(field: F) -> Self {
+        Self {
+            field: field.into_iter().map(|x| x.into()).collect(),
+            ignore_missing: None,
+            common: ProcessorCommon::default(),
+        }
+    }
+
+    /// Skips documents without the field
+    pub fn ignore_missing(self, ignore_missing: bool) -> Self {
+        Self {
+            ignore_missing: Some(ignore_missing),
+            ..self
+        }
+    }
+}
+
+/// https://opensearch.org/docs/latest/ingest-pipelines/processors/rename/
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RenameProcessor {
+    field: String,
+
+    target_field: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ignore_missing: Option<bool>,
+
+    #[serde(flatten)]
+    common: ProcessorCommon,
+}
+
+impl RenameProcessor {
+    pub fn new<T: Into<String>, U: Into<String>>(field: T, target_field: U) -> Self {
+        Self {
+            field: field.into(),
+            target_field: target_field.into(),
+            ignore_missing: None,
+            common: ProcessorCommon::default(),
+        }
+    }
+
+    /// Skips documents without the field
+    pub fn ignore_missing(self, ignore_missing: bool) -> Self {
+        Self {
+            ignore_missing: Some(ignore_missing),
+            ..self
+        }
+    }
+}
+
+/// https://opensearch.org/docs/latest/ingest-pipelines/processors/convert/
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConvertProcessor {
+    field: String,
+
+    #[serde(rename = "type")]
+    convert_type: ConvertType,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target_field: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ignore_missing: Option<bool>,
+
+    #[serde(flatten)]
+    common: ProcessorCommon,
+}
+
+impl ConvertProcessor {
+    pub fn new<T: Into<String>, U: Into<ConvertType>>(field: T, convert_type: U) -> Self {
+        Self {
+            field: field.into(),
+            convert_type: convert_type.into(),
+            target_field: None,
+            ignore_missing: None,
+            common: ProcessorCommon::default(),
+        }
+    }
+
+    /// Field the result is written to, `field` by default
+    pub fn target_field<T: Into<String>>(self, target_field: T) -> Self {
+        Self {
+            target_field: Some(target_field.into()),
+            ..self
+        }
+    }
+
+    /// Skips documents without the field
+    pub fn ignore_missing(self, ignore_missing: bool) -> Self {
+        Self {
+            ignore_missing: Some(ignore_missing),
+            ..self
+        }
+    }
+}
+
+/// Parses a date into a timestamp field
+/// https://opensearch.org/docs/latest/ingest-pipelines/processors/date/
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DateProcessor {
+    field: String,
+
+    formats: Vec<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target_field: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timezone: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    locale: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output_format: Option<String>,
+
+    #[serde(flatten)]
+    common: ProcessorCommon,
+}
+
+impl DateProcessor {
+    /// Formats are Java time patterns, `ISO8601`, `UNIX` or `UNIX_MS`, tried in order
+    pub fn new<T: Into<String>, F: IntoIterator<Item=U>, U: Into<String>>(field: T, formats: F) -> Self {
+        Self {
+            field: field.into(),
+            formats: formats.into_iter().map(|x| x.into()).collect(),
+            target_field: None,
+            timezone: None,
+            locale: None,
+            output_format: None,
+            common: ProcessorCommon::default(),
+        }
+    }
+
+    /// `@timestamp` by default
+    pub fn target_field<T: Into<String>>(self, target_field: T) -> Self {
+        Self {
+            target_field: Some(target_field.into()),
+            ..self
+        }
+    }
+
+    pub fn timezone<T: Into<String>>(self, timezone: T) -> Self {
+        Self {
+            timezone: Some(timezone.into()),
+            ..self
+        }
+    }
+
+    pub fn locale<T: Into<String>>(self, locale: T) -> Self {
+        Self {
+            locale: Some(locale.into()),
+            ..self
+        }
+    }
+
+    pub fn output_format<T: Into<String>>(self, output_format: T) -> Self {
+        Self {
+            output_format: Some(output_format.into()),
+            ..self
+        }
+    }
+}
+
+/// Extracts fields with named regular expressions, e.g. `%{IP:client.ip}`
+/// https://opensearch.org/docs/latest/ingest-pipelines/processors/grok/
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GrokProcessor {
+    field: String,
+
+    patterns: Vec<String>,
+
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    pattern_definitions: BTreeMap<String, String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trace_match: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ignore_missing: Option<bool>,
+
+    #[serde(flatten)]
+    common: ProcessorCommon,
+}
+
+impl GrokProcessor {
+    pub fn new<T: Into<String>, F: IntoIterator<Item=U>, U: Into<String>>(field: T, patterns: F) -> Self {
+        Self {
+            field: field.into(),
+            patterns: patterns.into_iter().map(|x| x.into()).collect(),
+            pattern_definitions: BTreeMap::new(),
+            trace_match: None,
+            ignore_missing: None,
+            common: ProcessorCommon::default(),
+        }
+    }
+
+    /// Adds the index of the matching pattern to `_ingest._grok_match_index`
+    pub fn trace_match(self, trace_match: bool) -> Self {
+        Self {
+            trace_match: Some(trace_match),
+            ..self
+        }
+    }
+
+    /// Skips documents without the field
+    pub fn ignore_missing(self, ignore_missing: bool) -> Self {
+        Self {
+            ignore_missing: Some(ignore_missing),
+            ..self
+        }
+    }
+
+    /// Adds a custom pattern usable as `%{NAME}`
+    pub fn pattern_definition<K: Into<String>, V: Into<String>>(mut self, name: K, pattern: V) -> Self {
+        self.pattern_definitions.insert(name.into(), pattern.into());
+        self
+    }
+}
+
+/// Extracts fields by splitting on delimiters, e.g. `%{client} - %{status}`
+/// https://opensearch.org/docs/latest/ingest-pipelines/processors/dissect/
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DissectProcessor {
+    field: String,
+
+    pattern: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    append_separator: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ignore_missing: Option<bool>,
+
+    #[serde(flatten)]
+    common: ProcessorCommon,
+}
+
+impl DissectProcessor {
+    pub fn new<T: Into<String>, U: Into<String>>(field: T, pattern: U) -> Self {
+        Self {
+            field: field.into(),
+            pattern: pattern.into(),
+            append_separator: None,
+            ignore_missing: None,
+            common: ProcessorCommon::default(),
+        }
+    }
+
+    /// Separator of appended keys
+    pub fn append_separator<T: Into<String>>(self, append_separator: T) -> Self {
+        Self {
+            append_separator: Some(append_separator.into()),
+            ..self
+        }
+    }
+
+    /// Skips documents without the field
+    pub fn ignore_missing(self, ignore_missing: bool) -> Self {
+        Self {
+            ignore_missing: Some(ignore_missing),
+            ..self
+        }
+    }
+}
+
+/// https://opensearch.org/docs/latest/ingest-pipelines/processors/split/
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SplitProcessor {
+    field: String,
+
+    /// Regular expression
+    separator: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target_field: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    preserve_trailing: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ignore_missing: Option<bool>,
+
+    #[serde(flatten)]
+    common: ProcessorCommon,
+}
+
+impl SplitProcessor {
+    pub fn new<T: Into<String>, U: Into<String>>(field: T, separator: U) -> Self {
+        Self {
+            field: field.into(),
+            separator: separator.into(),
+            target_field: None,
+            preserve_trailing: None,
+            ignore_missing: None,
+            common: ProcessorCommon::default(),
+        }
+    }
+
+    /// Field the result is written to, `field` by default
+    pub fn target_field<T: Into<String>>(self, target_field: T) -> Self {
+        Self {
+            target_field: Some(target_field.into()),
+            ..self
+        }
+    }
+
+    /// Keeps trailing empty values
+    pub fn preserve_trailing(self, preserve_trailing: bool) -> Self {
+        Self {
+            preserve_trailing: Some(preserve_trailing),
+            ..self
+        }
+    }
+
+    /// Skips documents without the field
+    pub fn ignore_missing(self, ignore_missing: bool) -> Self {
+        Self {
+            ignore_missing: Some(ignore_missing),
+            ..self
+        }
+    }
+}
+
+/// https://opensearch.org/docs/latest/ingest-pipelines/processors/join/
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JoinProcessor {
+    field: String,
+
+    separator: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target_field: Option<String>,
+
+    #[serde(flatten)]
+    common: ProcessorCommon,
+}
+
+impl JoinProcessor {
+    pub fn new<T: Into<String>, U: Into<String>>(field: T, separator: U) -> Self {
+        Self {
+            field: field.into(),
+            separator: separator.into(),
+            target_field: None,
+            common: ProcessorCommon::default(),
+        }
+    }
+
+    /// Field the result is written to, `field` by default
+    pub fn target_field<T: Into<String>>(self, target_field: T) -> Self {
+        Self {
+            target_field: Some(target_field.into()),
+            ..self
+        }
+    }
+}
+
+/// https://opensearch.org/docs/latest/ingest-pipelines/processors/lowercase/
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LowercaseProcessor {
+    field: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target_field: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ignore_missing: Option<bool>,
+
+    #[serde(flatten)]
+    common: ProcessorCommon,
+}
+
+impl LowercaseProcessor {
+    pub fn new<T: Into<String>>(field: T) -> Self {
+        Self {
+            field: field.into(),
+            target_field: None,
+            ignore_missing: None,
+            common: ProcessorCommon::default(),
+        }
+    }
+
+    /// Field the result is written to, `field` by default
+    pub fn target_field<T: Into<String>>(self, target_field: T) -> Self {
+        Self {
+            target_field: Some(target_field.into()),
+            ..self
+        }
+    }
+
+    /// Skips documents without the field
+    pub fn ignore_missing(self, ignore_missing: bool) -> Self {
+        Self {
+            ignore_missing: Some(ignore_missing),
+            ..self
+        }
+    }
+}
+
+/// Replaces matches of a regular expression
+/// https://opensearch.org/docs/latest/ingest-pipelines/processors/gsub/
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GsubProcessor {
+    field: String,
+
+    pattern: String,
+
+    replacement: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target_field: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ignore_missing: Option<bool>,
+
+    #[serde(flatten)]
+    common: ProcessorCommon,
+}
+
+impl GsubProcessor {
+    pub fn new<T: Into<String>, U: Into<String>, V: Into<String>>(field: T, pattern: U, replacement: V) -> Self {
+        Self {
+            field: field.into(),
+            pattern: pattern.into(),
+            replacement: replacement.into(),
+            target_field: None,
+            ignore_missing: None,
+            common: ProcessorCommon::default(),
+        }
+    }
+
+    /// Field the result is written to, `field` by default
+    pub fn target_field<T: Into<String>>(self, target_field: T) -> Self {
+        Self {
+            target_field: Some(target_field.into()),
+            ..self
+        }
+    }
+
+    /// Skips documents without the field
+    pub fn ignore_missing(self, ignore_missing: bool) -> Self {
+        Self {
+            ignore_missing: Some(ignore_missing),
+            ..self
+        }
+    }
+}
+
+/// Runs a script on `ctx`, the document
+/// https://opensearch.org/docs/latest/ingest-pipelines/processors/script/
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScriptProcessor {
+    #[serde(flatten)]
+    script: Script,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    params: Option<Value>,
+
+    #[serde(flatten)]
+    common: ProcessorCommon,
+}
+
+impl ScriptProcessor {
+    pub fn new<T: Into<Script>>(script: T) -> Self {
+        Self {
+            script: script.into(),
+            params: None,
+            common: ProcessorCommon::default(),
+        }
+    }
+
+    /// Parameters available as `params`
+    pub fn params<T: Into<Value>>(self, params: T) -> Self {
+        Self {
+            params: Some(params.into()),
+            ..self
+        }
+    }
+}
+
+/// Runs another pipeline
+/// https://opensearch.org/docs/latest/ingest-pipelines/processors/pipeline/
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PipelineProcessor {
+    name: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ignore_missing_pipeline: Option<bool>,
+
+    #[serde(flatten)]
+    common: ProcessorCommon,
+}
+
+impl PipelineProcessor {
+    pub fn new<T: Into<String>>(name: T) -> Self {
+        Self {
+            name: name.into(),
+            ignore_missing_pipeline: None,
+            common: ProcessorCommon::default(),
+        }
+    }
+
+    pub fn ignore_missing_pipeline(self, ignore_missing_pipeline: bool) -> Self {
+        Self {
+            ignore_missing_pipeline: Some(ignore_missing_pipeline),
+            ..self
+        }
+    }
+}
+
+/// Runs a processor on each element of an array, available as `_ingest._value`
+/// https://opensearch.org/docs/latest/ingest-pipelines/processors/foreach/
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ForeachProcessor {
+    field: String,
+
+    processor: Box<Processor>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ignore_missing: Option<bool>,
+
+    #[serde(flatten)]
+    common: ProcessorCommon,
+}
+
+impl ForeachProcessor {
+    pub fn new<T: Into<String>, U: Into<Processor>>(field: T, processor: U) -> Self {
+        Self {
+            field: field.into(),
+            processor: Box::new(processor.into()),
+            ignore_missing: None,
+            common: ProcessorCommon::default(),
+        }
+    }
+
+    /// Skips documents without the field
+    pub fn ignore_missing(self, ignore_missing: bool) -> Self {
+        Self {
+            ignore_missing: Some(ignore_missing),
+            ..self
+        }
+    }
+}
+
+/// Parses a JSON string
+/// https://opensearch.org/docs/latest/ingest-pipelines/processors/json/
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonProcessor {
+    field: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target_field: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    add_to_root: Option<bool>,
+
+    #[serde(flatten)]
+    common: ProcessorCommon,
+}
+
+impl JsonProcessor {
+    pub fn new<T: Into<String>>(field: T) -> Self {
+        Self {
+            field: field.into(),
+            target_field: None,
+            add_to_root: None,
+            common: ProcessorCommon::default(),
+        }
+    }
+
+    /// Field the result is written to, `field` by default
+    pub fn target_field<T: Into<String>>(self, target_field: T) -> Self {
+        Self {
+            target_field: Some(target_field.into()),
+            ..self
+        }
+    }
+
+    /// Merges the parsed object into the document
+    pub fn add_to_root(self, add_to_root: bool) -> Self {
+        Self {
+            add_to_root: Some(add_to_root),
+            ..self
+        }
+    }
+}
+
+/// Drops the document, usually with a condition
+/// https://opensearch.org/docs/latest/ingest-pipelines/processors/drop/
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct DropProcessor {
+    #[serde(flatten)]
+    common: ProcessorCommon,
+}
+
+impl DropProcessor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Fails the pipeline with a message
+/// https://opensearch.org/docs/latest/ingest-pipelines/processors/fail/
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FailProcessor {
+    message: String,
+
+    #[serde(flatten)]
+    common: ProcessorCommon,
+}
+
+impl FailProcessor {
+    pub fn new<T: Into<String>>(message: T) -> Self {
+        Self {
+            message: message.into(),
+            common: ProcessorCommon::default(),
+        }
+    }
+}
+
+/// Writes dense embeddings of text fields with an ML model
+/// https://opensearch.org/docs/latest/ingest-pipelines/processors/text-embedding/
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TextEmbeddingProcessor {
+    model_id: String,
+
+    field_map: BTreeMap<String, String>,
+
+    #[serde(flatten)]
+    common: ProcessorCommon,
+}
+
+impl TextEmbeddingProcessor {
+    pub fn new<T: Into<String>>(model_id: T) -> Self {
+        Self {
+            model_id: model_id.into(),
+            field_map: BTreeMap::new(),
+            common: ProcessorCommon::default(),
+        }
+    }
+
+    /// Maps a text field to the field of its embedding
+    pub fn field_map<K: Into<String>, V: Into<String>>(mut self, field: K, embedding_field: V) -> Self {
+        self.field_map.insert(field.into(), embedding_field.into());
+        self
+    }
+}
+
+/// Writes sparse token weights of text fields with an ML model
+/// https://opensearch.org/docs/latest/ingest-pipelines/processors/sparse-encoding/
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SparseEncodingProcessor {
+    model_id: String,
+
+    field_map: BTreeMap<String, String>,
+
+    #[serde(flatten)]
+    common: ProcessorCommon,
+}
+
+impl SparseEncodingProcessor {
+    pub fn new<T: Into<String>>(model_id: T) -> Self {
+        Self {
+            model_id: model_id.into(),
+            field_map: BTreeMap::new(),
+            common: ProcessorCommon::default(),
+        }
+    }
+
+    /// Maps a text field to the `rank_features` field of its tokens
+    pub fn field_map<K: Into<String>, V: Into<String>>(mut self, field: K, encoding_field: V) -> Self {
+        self.field_map.insert(field.into(), encoding_field.into());
+        self
+    }
+}
+
+/// A string or a list of strings
+fn deserialize_one_or_many<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+    where
+        D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Layout {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match Layout::deserialize(deserializer)? {
+        Layout::One(value) => vec![value],
+        Layout::Many(values) => values,
+    })
+}
+
+/// Common options and `From` of every processor
+macro_rules! processor_methods {
+    ($(($processor:ident, $variant:ident)),*) => {
+        $(
+            impl $processor {
+                /// Painless condition on `ctx`, `if` in the body, e.g. `ctx.status == 'deleted'`
+                pub fn condition<T: Into<String>>(mut self, condition: T) -> Self {
+                    self.common.condition = Some(condition.into());
+                    self
+                }
+
+                /// Identifier of the processor in errors and simulate results
+                pub fn tag<T: Into<String>>(mut self, tag: T) -> Self {
+                    self.common.tag = Some(tag.into());
+                    self
+                }
+
+                pub fn description<T: Into<String>>(mut self, description: T) -> Self {
+                    self.common.description = Some(description.into());
+                    self
+                }
+
+                /// Continues the pipeline when the processor fails
+                pub fn ignore_failure(mut self, ignore_failure: bool) -> Self {
+                    self.common.ignore_failure = Some(ignore_failure);
+                    self
+                }
+
+                /// Processors run instead of failing the pipeline, replaces the previous ones
+                pub fn on_failure<T, F>(mut self, on_failure: F) -> Self
+                    where T: Into<Processor>,
+                          F: IntoIterator<Item = T>
+                {
+                    self.common.on_failure = on_failure.into_iter().map(|x| x.into()).collect();
+                    self
+                }
+            }
+
+            impl From<$processor> for Processor {
+                fn from(processor: $processor) -> Self {
+                    Processor::$variant(processor)
+                }
+            }
+        )*
+    }
+}
+
+processor_methods! {
+    (SetProcessor, Set),
+    (RemoveProcessor, Remove),
+    (RenameProcessor, Rename),
+    (ConvertProcessor, Convert),
+    (DateProcessor, Date),
+    (GrokProcessor, Grok),
+    (DissectProcessor, Dissect),
+    (SplitProcessor, Split),
+    (JoinProcessor, Join),
+    (LowercaseProcessor, Lowercase),
+    (GsubProcessor, Gsub),
+    (ScriptProcessor, Script),
+    (PipelineProcessor, Pipeline),
+    (ForeachProcessor, Foreach),
+    (JsonProcessor, Json),
+    (DropProcessor, Drop),
+    (FailProcessor, Fail),
+    (TextEmbeddingProcessor, TextEmbedding),
+    (SparseEncodingProcessor, SparseEncoding)
+}
@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::ingest::pipeline::Pipeline;
+
+/// Body of `POST _ingest/pipeline/_simulate`, or of `POST _ingest/pipeline/<id>/_simulate` without a pipeline
+/// https://opensearch.org/docs/latest/ingest-pipelines/simulate-ingest/
+///
+/// Examples
+/// ```
+/// use serde_json::json;
+/// use os_query_builder_rs::ingest::pipeline::Pipeline;
+/// use os_query_builder_rs::ingest::processors::LowercaseProcessor;
+/// use os_query_builder_rs::ingest::simulate::SimulateRequest;
+///
+/// let simulate = SimulateRequest::new()
+///             .pipeline(Pipeline::new().processor(LowercaseProcessor::new("brand")))
+///             .doc(json!({ "brand": "FIAT" }));
+///
+/// assert_eq!(serde_json::to_value(simulate).unwrap(), json!({
+///     "pipeline": { "processors": [{ "lowercase": { "field": "brand" } }] },
+///     "docs": [{ "_source": { "brand": "FIAT" } }]
+/// }));
+/// ```
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct SimulateRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pipeline: Option<Pipeline>,
+
+    docs: Vec<SimulateDocument>,
+}
+
+impl SimulateRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pipeline to test, omitted when simulating a stored pipeline
+    pub fn pipeline<T: Into<Pipeline>>(self, pipeline: T) -> Self {
+        Self {
+            pipeline: Some(pipeline.into()),
+            ..self
+        }
+    }
+
+    /// Appends a sample document
+    pub fn doc<T: Into<SimulateDocument>>(mut self, doc: T) -> Self {
+        self.docs.push(doc.into());
+        self
+    }
+
+    pub fn docs<T, F>(mut self, docs: F) -> Self
+        where T: Into<SimulateDocument>,
+              F: IntoIterator<Item = T>
+    {
+        self.docs.extend(docs.into_iter().map(|x| x.into()));
+        self
+    }
+}
+
+/// Sample document, converts from its `_source`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimulateDocument {
+    #[serde(rename = "_index", skip_serializing_if = "Option::is_none")]
+    index: Option<String>,
+
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+
+    #[serde(rename = "_source")]
+    source: Value,
+}
+
+impl SimulateDocument {
+    pub fn new<T: Into<Value>>(source: T) -> Self {
+        Self {
+            index: None,
+            id: None,
+            source: source.into(),
+        }
+    }
+
+    /// Available to processors as `ctx._index`
+    pub fn index<T: Into<String>>(self, index: T) -> Self {
+        Self {
+            index: Some(index.into()),
+            ..self
+        }
+    }
+
+    pub fn id<T: Into<String>>(self, id: T) -> Self {
+        Self {
+            id: Some(id.into()),
+            ..self
+        }
+    }
+}
+
+impl From<Value> for SimulateDocument {
+    fn from(source: Value) -> Self {
+        Self::new(source)
+    }
+}
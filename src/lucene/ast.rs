@@ -0,0 +1,80 @@
+/// Parsed `query_string` expression, fields are resolved by the parser so
+/// `title:(quick brown)` yields two terms on `title`, `None` means the default field
+#[derive(Debug, Clone, PartialEq)]
+pub enum Node {
+    /// Clauses of a group or of an expression joined with operators
+    Bool(Vec<Clause>),
+    /// `query^2`
+    Boost(Box<Node>, f64),
+    /// `*` or `*:*`
+    MatchAll,
+    /// `field:value`
+    Term {
+        field: Option<String>,
+        value: String,
+    },
+    /// `field:"quick brown"~2`
+    Phrase {
+        field: Option<String>,
+        text: String,
+        slop: Option<u32>,
+    },
+    /// `field:value~1`, `None` distance is `AUTO`
+    Fuzzy {
+        field: Option<String>,
+        value: String,
+        distance: Option<u8>,
+    },
+    /// `field:qu?ck*`, the pattern keeps `\` escapes of literal `*`, `?` and `\`
+    Wildcard {
+        field: Option<String>,
+        pattern: String,
+    },
+    /// `field:/jo.*n/`
+    Regexp {
+        field: Option<String>,
+        pattern: String,
+    },
+    /// `field:[a TO b}`, `field:>=a`
+    Range {
+        field: Option<String>,
+        lower: Bound,
+        upper: Bound,
+    },
+    /// `_exists_:field` or `field:*`
+    Exists {
+        field: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Clause {
+    pub occur: Occur,
+    pub node: Node,
+}
+
+impl Clause {
+    pub fn new(occur: Occur, node: Node) -> Self {
+        Self { occur, node }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Occur {
+    /// `+query` or a side of `AND`
+    Must,
+    /// Optional clause, a side of `OR`
+    Should,
+    /// `-query`, `!query` or `NOT query`
+    MustNot,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Bound {
+    /// `*`
+    Unbounded,
+    /// `[value` or `value]`
+    Inclusive(String),
+    /// `{value` or `value}`
+    Exclusive(String),
+}
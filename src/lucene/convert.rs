@@ -0,0 +1,204 @@
+use std::collections::HashSet;
+use std::fmt;
+use crate::compound_query::bool::Bool;
+use crate::full_text::{match_phrase::MatchPhrase, r#match::Match};
+use crate::lucene::ast::{Bound, Node, Occur};
+use crate::misc::fuzziness::Fuzziness;
+use crate::misc::query_field::QueryField;
+use crate::term::{
+    exists::Exists,
+    fuzzy::Fuzzy,
+    range::Range,
+    regexp::Regexp,
+    term::Term,
+    wildcard::Wildcard
+};
+
+/// Converts a parsed `query_string` into structured queries: words become `match`,
+/// phrases `match_phrase` and both become `term` on keyword fields
+///
+/// Examples
+/// ```
+/// use os_query_builder_rs::lucene::convert::Converter;
+/// use os_query_builder_rs::lucene::parser::parse;
+///
+/// let node = parse("title:\"quick fox\"~2 AND status:active AND price:[10 TO *]").unwrap();
+///
+/// let query = Converter::new()
+///             .allowed_fields(["title", "status", "price"])
+///             .keyword_fields(["status"])
+///             .convert(&node)
+///             .unwrap();
+///
+/// assert!(Converter::new().allowed_fields(["title"]).convert(&node).is_err());
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Converter {
+    default_field: Option<String>,
+    allowed_fields: Option<HashSet<String>>,
+    keyword_fields: HashSet<String>,
+}
+
+impl Converter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Field for clauses without a field, they are rejected when it is not set
+    pub fn default_field<T: Into<String>>(self, default_field: T) -> Self {
+        Self {
+            default_field: Some(default_field.into()),
+            ..self
+        }
+    }
+
+    /// Fields the query may reference, any field is allowed when it is not set
+    pub fn allowed_fields<T, F>(self, allowed_fields: F) -> Self
+        where T: Into<String>,
+              F: IntoIterator<Item = T>
+    {
+        Self {
+            allowed_fields: Some(allowed_fields.into_iter().map(|x| x.into()).collect()),
+            ..self
+        }
+    }
+
+    /// Fields matched exactly with `term` instead of analyzed with `match`
+    pub fn keyword_fields<T, F>(self, keyword_fields: F) -> Self
+        where T: Into<String>,
+              F: IntoIterator<Item = T>
+    {
+        Self {
+            keyword_fields: keyword_fields.into_iter().map(|x| x.into()).collect(),
+            ..self
+        }
+    }
+
+    pub fn convert(&self, node: &Node) -> Result<QueryField, ConvertError> {
+        self.convert_boosted(node, None)
+    }
+
+    fn convert_boosted(&self, node: &Node, boost: Option<f64>) -> Result<QueryField, ConvertError> {
+        Ok(match node {
+            Node::Boost(node, value) => return self.convert_boosted(node, Some(boost.unwrap_or(1.0) * value)),
+            Node::Bool(clauses) => {
+                if let [clause] = clauses.as_slice() {
+                    if clause.occur != Occur::MustNot {
+                        return self.convert_boosted(&clause.node, boost);
+                    }
+                }
+
+                let mut query = Bool::new();
+                for clause in clauses {
+                    let child = self.convert(&clause.node)?;
+                    match clause.occur {
+                        Occur::Must => query.push_must(child),
+                        Occur::Should => query.push_should(child),
+                        Occur::MustNot => query.push_must_not(child),
+                    };
+                }
+                with_boost(query, boost, Bool::boost::<f64>).into()
+            }
+            // an empty bool matches all documents
+            Node::MatchAll => with_boost(Bool::new(), boost, Bool::boost::<f64>).into(),
+            Node::Term { field, value } => {
+                let field = self.field(field)?;
+                if self.keyword_fields.contains(&field) {
+                    with_boost(Term::new(field, value.as_str()), boost, Term::boost::<f64>).into()
+                } else {
+                    with_boost(Match::new().field(field).value(value.as_str()), boost, Match::boost::<f64>).into()
+                }
+            }
+            Node::Phrase { field, text, slop } => {
+                let field = self.field(field)?;
+                if self.keyword_fields.contains(&field) {
+                    return Ok(with_boost(Term::new(field, text.as_str()), boost, Term::boost::<f64>).into());
+                }
+                let query = MatchPhrase::new().field(field).value(text.as_str());
+                let query = match slop {
+                    Some(slop) => query.slop(*slop),
+                    None => query,
+                };
+                with_boost(query, boost, MatchPhrase::boost::<f64>).into()
+            }
+            Node::Fuzzy { field, value, distance } => {
+                let fuzziness = match distance {
+                    Some(distance) => Fuzziness::from(*distance),
+                    None => Fuzziness::Auto,
+                };
+                let query = Fuzzy::new()
+                    .field(self.field(field)?)
+                    .value(value.as_str())
+                    .fuzziness(fuzziness);
+                with_boost(query, boost, Fuzzy::boost::<f64>).into()
+            }
+            Node::Wildcard { field, pattern } => {
+                let query = Wildcard::new()
+                    .field(self.field(field)?)
+                    .value(pattern.as_str());
+                with_boost(query, boost, Wildcard::boost::<f64>).into()
+            }
+            Node::Regexp { field, pattern } => {
+                let query = Regexp::new()
+                    .field(self.field(field)?)
+                    .value(pattern.as_str());
+                with_boost(query, boost, Regexp::boost::<f64>).into()
+            }
+            Node::Range { field, lower, upper } => {
+                let query = Range::new().field(self.field(field)?);
+                let query = match lower {
+                    Bound::Inclusive(value) => query.gte(value.as_str()),
+                    Bound::Exclusive(value) => query.gt(value.as_str()),
+                    Bound::Unbounded => query,
+                };
+                let query = match upper {
+                    Bound::Inclusive(value) => query.lte(value.as_str()),
+                    Bound::Exclusive(value) => query.lt(value.as_str()),
+                    Bound::Unbounded => query,
+                };
+                with_boost(query, boost, Range::boost::<f64>).into()
+            }
+            Node::Exists { field } => {
+                let query = Exists::new().field(self.field(&Some(field.clone()))?);
+                with_boost(query, boost, Exists::boost::<f64>).into()
+            }
+        })
+    }
+
+    fn field(&self, field: &Option<String>) -> Result<String, ConvertError> {
+        let field = field.as_ref()
+            .or(self.default_field.as_ref())
+            .ok_or(ConvertError::MissingField)?;
+
+        match &self.allowed_fields {
+            Some(allowed) if !allowed.contains(field) => Err(ConvertError::FieldNotAllowed(field.clone())),
+            _ => Ok(field.clone()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConvertError {
+    /// Clause without a field and no default field
+    MissingField,
+    /// Field outside of the allow-list
+    FieldNotAllowed(String),
+}
+
+impl fmt::Display for ConvertError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConvertError::MissingField => write!(f, "clause without a field and no default field"),
+            ConvertError::FieldNotAllowed(field) => write!(f, "field `{field}` is not allowed"),
+        }
+    }
+}
+
+impl std::error::Error for ConvertError {}
+
+fn with_boost<T>(query: T, boost: Option<f64>, set: fn(T, f64) -> T) -> T {
+    match boost {
+        Some(boost) => set(query, boost),
+        None => query,
+    }
+}
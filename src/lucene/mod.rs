@@ -0,0 +1,10 @@
+//! Parsing of the `query_string` and `simple_query_string` syntax into a typed AST
+//! that converts into structured queries
+//!
+//! https://opensearch.org/docs/latest/query-dsl/full-text/query-string/#query-string-syntax
+
+pub mod ast;
+pub mod parser;
+pub mod simple;
+pub mod convert;
+pub mod render;
@@ -0,0 +1,544 @@
+use std::fmt;
+use crate::lucene::ast::{Bound, Clause, Node, Occur};
+use crate::misc::operator::Operator;
+
+/// https://opensearch.org/docs/latest/query-dsl/full-text/query-string/#query-string-syntax
+///
+/// Examples
+/// ```
+/// use os_query_builder_rs::lucene::ast::{Clause, Node, Occur};
+/// use os_query_builder_rs::lucene::parser::Parser;
+/// use os_query_builder_rs::misc::operator::Operator;
+///
+/// let node = Parser::new()
+///             .default_operator(Operator::And)
+///             .parse("title:wind -status:draft")
+///             .unwrap();
+///
+/// assert_eq!(node, Node::Bool(vec![
+///     Clause::new(Occur::Must, Node::Term { field: Some("title".into()), value: "wind".into() }),
+///     Clause::new(Occur::MustNot, Node::Term { field: Some("status".into()), value: "draft".into() }),
+/// ]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Parser {
+    default_operator: Operator,
+}
+
+impl Default for Parser {
+    fn default() -> Self {
+        Self {
+            default_operator: Operator::Or,
+        }
+    }
+}
+
+impl Parser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Operator for clauses without `AND`, `OR` or modifiers between them, `OR` by default
+    pub fn default_operator<T: Into<Operator>>(self, default_operator: T) -> Self {
+        Self {
+            default_operator: default_operator.into(),
+        }
+    }
+
+    pub fn parse(&self, query: &str) -> Result<Node, ParseError> {
+        let mut state = State {
+            chars: query.chars().collect(),
+            position: 0,
+            depth: 0,
+            default_occur: match self.default_operator {
+                Operator::And => Occur::Must,
+                Operator::Or => Occur::Should,
+            },
+        };
+
+        let clauses = state.parse_sequence(None)?;
+        state.skip_whitespace();
+        if let Some(c) = state.peek() {
+            return Err(state.error(format!("unexpected `{c}`")));
+        }
+        if clauses.is_empty() {
+            return Err(state.error("empty query"));
+        }
+        Ok(state.group(clauses))
+    }
+}
+
+/// Parses `query` with the `OR` default operator
+pub fn parse(query: &str) -> Result<Node, ParseError> {
+    Parser::new().parse(query)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    position: usize,
+    message: String,
+}
+
+impl ParseError {
+    pub(crate) fn new<T: Into<String>>(position: usize, message: T) -> Self {
+        Self {
+            position,
+            message: message.into(),
+        }
+    }
+
+    /// Character offset of the error in the query
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Clause before the operator of its group is known
+struct Pending {
+    occur: Option<Occur>,
+    node: Node,
+}
+
+struct State {
+    chars: Vec<char>,
+    position: usize,
+    /// Nesting of groups and fields, limited by [`MAX_DEPTH`]
+    depth: usize,
+    default_occur: Occur,
+}
+
+/// Deepest nesting of groups accepted, deeper queries would overflow the stack
+pub(crate) const MAX_DEPTH: usize = 256;
+
+/// Characters ending a bare term
+const TERM_END: &[char] = &['(', ')', '[', ']', '{', '}', '^', '"', '~', ':'];
+
+impl State {
+    fn error<T: Into<String>>(&self, message: T) -> ParseError {
+        ParseError::new(self.position, message)
+    }
+
+    fn enter(&mut self) -> Result<(), ParseError> {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            return Err(self.error(format!("query is nested deeper than {MAX_DEPTH} levels")));
+        }
+        Ok(())
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.position + offset).copied()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), ParseError> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.error(format!("expected `{c}`")))
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.position += 1;
+        }
+    }
+
+    /// Consumes a standalone operator word such as `AND` or a symbol such as `&&`
+    fn eat_operator(&mut self, word: &str, symbol: &str) -> bool {
+        self.skip_whitespace();
+        let matches = |s: &str| s.chars().enumerate().all(|(i, c)| self.peek_at(i) == Some(c));
+
+        if !symbol.is_empty() && matches(symbol) {
+            self.position += symbol.chars().count();
+            return true;
+        }
+
+        let length = word.chars().count();
+        let standalone = self.peek_at(length).is_none_or(|c| c.is_whitespace() || c == '(');
+        if matches(word) && standalone {
+            self.position += length;
+            return true;
+        }
+        false
+    }
+
+    fn group(&self, mut clauses: Vec<Pending>) -> Node {
+        if clauses.len() == 1 && clauses[0].occur.is_none() {
+            return clauses.pop().expect("one clause").node;
+        }
+        Node::Bool(clauses.into_iter()
+            .map(|x| Clause::new(x.occur.unwrap_or(self.default_occur), x.node))
+            .collect())
+    }
+
+    fn parse_sequence(&mut self, field: Option<&str>) -> Result<Vec<Pending>, ParseError> {
+        let mut clauses = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if matches!(self.peek(), None | Some(')')) {
+                return Ok(clauses);
+            }
+            clauses.push(self.parse_or(field)?);
+        }
+    }
+
+    fn parse_or(&mut self, field: Option<&str>) -> Result<Pending, ParseError> {
+        let mut clauses = vec![self.parse_and(field)?];
+        while self.eat_operator("OR", "||") {
+            clauses.push(self.parse_and(field)?);
+        }
+        Ok(join(clauses, Occur::Should))
+    }
+
+    fn parse_and(&mut self, field: Option<&str>) -> Result<Pending, ParseError> {
+        let mut clauses = vec![self.parse_modified(field)?];
+        while self.eat_operator("AND", "&&") {
+            clauses.push(self.parse_modified(field)?);
+        }
+        Ok(join(clauses, Occur::Must))
+    }
+
+    fn parse_modified(&mut self, field: Option<&str>) -> Result<Pending, ParseError> {
+        self.skip_whitespace();
+        let occur = if self.eat('+') {
+            Some(Occur::Must)
+        } else if self.eat('-') || self.eat('!') || self.eat_operator("NOT", "") {
+            Some(Occur::MustNot)
+        } else {
+            None
+        };
+
+        let node = self.parse_boosted(field)?;
+        Ok(Pending { occur, node })
+    }
+
+    fn parse_boosted(&mut self, field: Option<&str>) -> Result<Node, ParseError> {
+        let node = self.parse_primary(field)?;
+        if !self.eat('^') {
+            return Ok(node);
+        }
+
+        let number = self.read_number();
+        let boost = number.parse::<f64>()
+            .map_err(|_| self.error(format!("invalid boost `{number}`")))?;
+        Ok(Node::Boost(Box::new(node), boost))
+    }
+
+    fn parse_primary(&mut self, field: Option<&str>) -> Result<Node, ParseError> {
+        self.skip_whitespace();
+        let field = field.map(str::to_string);
+
+        match self.peek() {
+            None => Err(self.error("expected a query")),
+            Some('(') => {
+                self.position += 1;
+                self.enter()?;
+                let clauses = self.parse_sequence(field.as_deref())?;
+                self.expect(')')?;
+                self.depth -= 1;
+                if clauses.is_empty() {
+                    return Err(self.error("empty group"));
+                }
+                Ok(self.group(clauses))
+            }
+            Some('"') => {
+                let text = self.read_quoted()?;
+                let slop = if self.eat('~') {
+                    let number = self.read_number();
+                    Some(number.parse().map_err(|_| self.error(format!("invalid slop `{number}`")))?)
+                } else {
+                    None
+                };
+                Ok(Node::Phrase { field, text, slop })
+            }
+            Some('/') => {
+                let pattern = self.read_regexp()?;
+                Ok(Node::Regexp { field, pattern })
+            }
+            Some('[') | Some('{') => self.parse_range(field),
+            Some('>') | Some('<') => self.parse_comparison(field),
+            Some(_) => {
+                let start = self.position;
+                let term = self.read_term();
+                if term.text.is_empty() {
+                    return Err(self.error(format!("unexpected `{}`", self.peek().unwrap_or_default())));
+                }
+                if !term.escaped && matches!(term.text.as_str(), "AND" | "OR" | "NOT") {
+                    self.position = start;
+                    return Err(self.error(format!("unexpected `{}`", term.text)));
+                }
+
+                if self.eat(':') {
+                    if term.text == "_exists_" {
+                        let name = self.read_term();
+                        if name.text.is_empty() {
+                            return Err(self.error("expected a field name"));
+                        }
+                        return Ok(Node::Exists { field: name.text });
+                    }
+                    if term.text == "*" && self.peek() == Some('*') {
+                        self.position += 1;
+                        return Ok(Node::MatchAll);
+                    }
+                    self.enter()?;
+                    let node = self.parse_primary(Some(&term.text));
+                    self.depth -= 1;
+                    return node;
+                }
+
+                if term.wildcard {
+                    if term.text == "*" {
+                        return Ok(match field {
+                            Some(field) => Node::Exists { field },
+                            None => Node::MatchAll,
+                        });
+                    }
+                    return Ok(Node::Wildcard { field, pattern: term.pattern });
+                }
+
+                if self.eat('~') {
+                    let number = self.read_number();
+                    let distance = match number.as_str() {
+                        "" => None,
+                        number => Some(number.parse().map_err(|_| self.error(format!("invalid fuzziness `{number}`")))?),
+                    };
+                    return Ok(Node::Fuzzy { field, value: term.text, distance });
+                }
+
+                Ok(Node::Term { field, value: term.text })
+            }
+        }
+    }
+
+    fn parse_range(&mut self, field: Option<String>) -> Result<Node, ParseError> {
+        let inclusive_lower = self.peek() == Some('[');
+        self.position += 1;
+
+        self.skip_whitespace();
+        let lower = self.read_bound(inclusive_lower)?;
+        self.skip_whitespace();
+        if !self.eat_operator("TO", "") {
+            return Err(self.error("expected `TO`"));
+        }
+        self.skip_whitespace();
+        let upper = self.read_range_value()?;
+        self.skip_whitespace();
+
+        let inclusive_upper = match self.peek() {
+            Some(']') => true,
+            Some('}') => false,
+            _ => return Err(self.error("expected `]` or `}`")),
+        };
+        self.position += 1;
+
+        let upper = match upper {
+            Some(value) if inclusive_upper => Bound::Inclusive(value),
+            Some(value) => Bound::Exclusive(value),
+            None => Bound::Unbounded,
+        };
+        Ok(Node::Range { field, lower, upper })
+    }
+
+    fn read_bound(&mut self, inclusive: bool) -> Result<Bound, ParseError> {
+        Ok(match self.read_range_value()? {
+            Some(value) if inclusive => Bound::Inclusive(value),
+            Some(value) => Bound::Exclusive(value),
+            None => Bound::Unbounded,
+        })
+    }
+
+    /// `None` is the `*` bound
+    fn read_range_value(&mut self) -> Result<Option<String>, ParseError> {
+        if self.peek() == Some('"') {
+            return self.read_quoted().map(Some);
+        }
+
+        let mut value = String::new();
+        while let Some(c) = self.peek() {
+            if c.is_whitespace() || matches!(c, ']' | '}' | ')') {
+                break;
+            }
+            self.position += 1;
+            if c == '\\' {
+                value.extend(self.peek());
+                self.position += 1;
+            } else {
+                value.push(c);
+            }
+        }
+
+        match value.as_str() {
+            "" => Err(self.error("expected a range bound")),
+            "*" => Ok(None),
+            _ => Ok(Some(value)),
+        }
+    }
+
+    fn parse_comparison(&mut self, field: Option<String>) -> Result<Node, ParseError> {
+        let greater = self.eat('>');
+        if !greater {
+            self.expect('<')?;
+        }
+        let inclusive = self.eat('=');
+
+        let value = match self.read_range_value()? {
+            Some(value) if inclusive => Bound::Inclusive(value),
+            Some(value) => Bound::Exclusive(value),
+            None => Bound::Unbounded,
+        };
+        let (lower, upper) = if greater {
+            (value, Bound::Unbounded)
+        } else {
+            (Bound::Unbounded, value)
+        };
+        Ok(Node::Range { field, lower, upper })
+    }
+
+    fn read_quoted(&mut self) -> Result<String, ParseError> {
+        let start = self.position;
+        self.expect('"')?;
+        let mut text = String::new();
+        loop {
+            match self.peek() {
+                None => {
+                    self.position = start;
+                    return Err(self.error("unterminated phrase"));
+                }
+                Some('"') => {
+                    self.position += 1;
+                    return Ok(text);
+                }
+                Some('\\') => {
+                    self.position += 1;
+                    text.extend(self.peek());
+                    self.position += 1;
+                }
+                Some(c) => {
+                    self.position += 1;
+                    text.push(c);
+                }
+            }
+        }
+    }
+
+    fn read_regexp(&mut self) -> Result<String, ParseError> {
+        let start = self.position;
+        self.expect('/')?;
+        let mut pattern = String::new();
+        loop {
+            match self.peek() {
+                None => {
+                    self.position = start;
+                    return Err(self.error("unterminated regular expression"));
+                }
+                Some('/') => {
+                    self.position += 1;
+                    return Ok(pattern);
+                }
+                Some('\\') => {
+                    self.position += 1;
+                    match self.peek() {
+                        Some('/') => pattern.push('/'),
+                        Some(c) => {
+                            pattern.push('\\');
+                            pattern.push(c);
+                        }
+                        None => continue,
+                    }
+                    self.position += 1;
+                }
+                Some(c) => {
+                    self.position += 1;
+                    pattern.push(c);
+                }
+            }
+        }
+    }
+
+    fn read_number(&mut self) -> String {
+        let mut number = String::new();
+        while let Some(c) = self.peek().filter(|c| c.is_ascii_digit() || *c == '.') {
+            number.push(c);
+            self.position += 1;
+        }
+        number
+    }
+
+    fn read_term(&mut self) -> BareTerm {
+        let mut term = BareTerm::default();
+        while let Some(c) = self.peek() {
+            if c.is_whitespace() || TERM_END.contains(&c) {
+                break;
+            }
+            self.position += 1;
+
+            if c != '\\' {
+                term.wildcard |= matches!(c, '*' | '?');
+                term.text.push(c);
+                term.pattern.push(c);
+                continue;
+            }
+
+            let Some(escaped) = self.peek() else {
+                break;
+            };
+            self.position += 1;
+            term.escaped = true;
+            term.text.push(escaped);
+            if matches!(escaped, '*' | '?' | '\\') {
+                term.pattern.push('\\');
+            }
+            term.pattern.push(escaped);
+        }
+        term
+    }
+}
+
+/// Joins clauses of `AND` or `OR`, clauses without modifiers get `occur`
+fn join(mut clauses: Vec<Pending>, occur: Occur) -> Pending {
+    if clauses.len() == 1 {
+        return clauses.pop().expect("one clause");
+    }
+    Pending {
+        occur: None,
+        node: Node::Bool(clauses.into_iter()
+            .map(|x| Clause::new(x.occur.unwrap_or(occur), x.node))
+            .collect()),
+    }
+}
+
+#[derive(Default)]
+struct BareTerm {
+    /// Unescaped text
+    text: String,
+    /// Text keeping escapes of wildcard characters
+    pattern: String,
+    wildcard: bool,
+    escaped: bool,
+}
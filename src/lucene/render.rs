@@ -0,0 +1,335 @@
+use std::fmt;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use crate::compound_query::bool::Bool;
+use crate::misc::query_field::QueryField;
+
+/// Characters escaped with `\` in terms
+const RESERVED: &[char] = &['+', '-', '=', '&', '|', '>', '<', '!', '(', ')', '{', '}', '[', ']', '^', '"', '~', '*', '?', ':', '\\', '/'];
+
+impl QueryField {
+    /// Renders the query as an equivalent `query_string`, the rendered string
+    /// relies on the `OR` default operator
+    ///
+    /// `term` and `terms` queries render as `field:value`, which `query_string` analyzes with the
+    /// search analyzer of the field. They are equivalent on `keyword` and other non-analyzed fields,
+    /// but may match other documents on `text` fields.
+    ///
+    /// Examples
+    /// ```
+    /// use os_query_builder_rs::compound_query::bool::Bool;
+    /// use os_query_builder_rs::full_text::match_phrase::MatchPhrase;
+    /// use os_query_builder_rs::misc::query_field::QueryField;
+    /// use os_query_builder_rs::term::{range::Range, term::Term};
+    ///
+    /// let query = QueryField::from(Bool::new()
+    ///             .must(vec![QueryField::from(MatchPhrase::new().field("title").value("quick fox").slop(2u64))])
+    ///             .filter(vec![Range::new().field("price").gte(10).lt(20)])
+    ///             .must_not(vec![Term::new("status", "draft:1")]));
+    ///
+    /// assert_eq!(query.to_query_string().unwrap(), r#"+title:"quick fox"~2 +price:[10 TO 20} -status:draft\:1"#);
+    /// ```
+    pub fn to_query_string(&self) -> Result<String, RenderError> {
+        Ok(render(self)?.text)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RenderError {
+    /// Query type without a `query_string` equivalent
+    Unsupported(&'static str),
+    /// Parameter of a supported query without a `query_string` equivalent
+    UnsupportedParameter {
+        query: &'static str,
+        parameter: String,
+    },
+    /// Query without values, e.g. `terms` with an empty list
+    Empty(&'static str),
+}
+
+impl fmt::Display for RenderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RenderError::Unsupported(query) => write!(f, "`{query}` query can not be rendered as query_string"),
+            RenderError::UnsupportedParameter { query, parameter } => {
+                write!(f, "`{parameter}` of `{query}` query can not be rendered as query_string")
+            }
+            RenderError::Empty(query) => write!(f, "`{query}` query without values can not be rendered as query_string"),
+        }
+    }
+}
+
+impl std::error::Error for RenderError {}
+
+struct Rendered {
+    text: String,
+    /// Several clauses that need parentheses when nested
+    compound: bool,
+}
+
+impl Rendered {
+    fn single(text: String) -> Self {
+        Self { text, compound: false }
+    }
+
+    fn nested(self) -> String {
+        if self.compound {
+            format!("({})", self.text)
+        } else {
+            self.text
+        }
+    }
+}
+
+fn render(query: &QueryField) -> Result<Rendered, RenderError> {
+    let name = query.name();
+    match query {
+        QueryField::Bool(query) => render_bool(query),
+        QueryField::Term(query) => {
+            let (field, value) = field_entry(name, query, &["value", "boost"])?;
+            let text = format!("{}:{}", escape(&field), value_term(&value["value"]));
+            Ok(Rendered::single(boosted(text, &value)))
+        }
+        QueryField::Terms(query) => {
+            let Value::Object(object) = to_value(query) else {
+                return Err(RenderError::Unsupported(name));
+            };
+            let mut boost = Map::new();
+            let mut terms = None;
+            for (key, value) in object {
+                match (key.as_str(), value) {
+                    ("boost", value) => {
+                        boost.insert(key, value);
+                    }
+                    (_, Value::Array(values)) if terms.is_none() => terms = Some((key, values)),
+                    (_, Value::Object(_)) => return Err(RenderError::UnsupportedParameter { query: name, parameter: "lookup".into() }),
+                    _ => return Err(RenderError::Unsupported(name)),
+                }
+            }
+            let (field, values) = terms.ok_or(RenderError::Unsupported(name))?;
+            let values: Vec<String> = values.iter().map(value_term).collect();
+            let text = match values.as_slice() {
+                [] => return Err(RenderError::Empty(name)),
+                [value] => format!("{}:{value}", escape(&field)),
+                values => format!("{}:({})", escape(&field), values.join(" OR ")),
+            };
+            Ok(Rendered::single(boosted(text, &Value::Object(boost))))
+        }
+        QueryField::Match(query) => {
+            let (field, value) = field_entry(name, query, &["query", "operator", "fuzziness", "boost"])?;
+            let fuzziness = match value.get("fuzziness") {
+                Some(Value::String(_)) => "~".to_string(),
+                Some(Value::Number(n)) => format!("~{n}"),
+                _ => String::new(),
+            };
+            let operator = match value.get("operator").and_then(Value::as_str) {
+                Some("and") => " AND ",
+                _ => " OR ",
+            };
+            let words: Vec<String> = text_of(&value["query"])
+                .split_whitespace()
+                .map(|x| format!("{}{fuzziness}", escape(x)))
+                .collect();
+            let text = match words.as_slice() {
+                [] => return Err(RenderError::Empty(name)),
+                [word] => format!("{}:{word}", escape(&field)),
+                words => format!("{}:({})", escape(&field), words.join(operator)),
+            };
+            Ok(Rendered::single(boosted(text, &value)))
+        }
+        QueryField::MatchPhrase(query) => {
+            let (field, value) = field_entry(name, query, &["query", "slop", "boost"])?;
+            let slop = match value.get("slop") {
+                Some(slop) => format!("~{slop}"),
+                None => String::new(),
+            };
+            let text = format!("{}:{}{slop}", escape(&field), phrase(&text_of(&value["query"])));
+            Ok(Rendered::single(boosted(text, &value)))
+        }
+        QueryField::Range(query) => {
+            let (field, value) = field_entry(name, query, &["gt", "gte", "lt", "lte", "boost"])?;
+            let bound = |inclusive: &str, exclusive: &str| match (value.get(inclusive), value.get(exclusive)) {
+                (Some(value), _) => (true, value_term(value)),
+                (None, Some(value)) => (false, value_term(value)),
+                (None, None) => (true, "*".to_string()),
+            };
+            let (lower_inclusive, lower) = bound("gte", "gt");
+            let (upper_inclusive, upper) = bound("lte", "lt");
+            let text = format!(
+                "{}:{}{lower} TO {upper}{}",
+                escape(&field),
+                if lower_inclusive { '[' } else { '{' },
+                if upper_inclusive { ']' } else { '}' }
+            );
+            Ok(Rendered::single(boosted(text, &value)))
+        }
+        QueryField::Prefix(query) => {
+            let (field, value) = field_entry(name, query, &["value", "boost"])?;
+            let text = format!("{}:{}*", escape(&field), escape(&text_of(&value["value"])));
+            Ok(Rendered::single(boosted(text, &value)))
+        }
+        QueryField::Wildcard(query) => {
+            let (field, value) = field_entry(name, query, &["value", "boost"])?;
+            let text = format!("{}:{}", escape(&field), wildcard(&text_of(&value["value"])));
+            Ok(Rendered::single(boosted(text, &value)))
+        }
+        QueryField::Regexp(query) => {
+            let (field, value) = field_entry(name, query, &["value", "boost"])?;
+            let pattern = text_of(&value["value"]).replace('/', "\\/");
+            let text = format!("{}:/{pattern}/", escape(&field));
+            Ok(Rendered::single(boosted(text, &value)))
+        }
+        QueryField::Fuzzy(query) => {
+            let (field, value) = field_entry(name, query, &["value", "fuzziness", "boost"])?;
+            let fuzziness = match value.get("fuzziness") {
+                Some(Value::Number(n)) => n.to_string(),
+                _ => String::new(),
+            };
+            let text = format!("{}:{}~{fuzziness}", escape(&field), escape(&text_of(&value["value"])));
+            Ok(Rendered::single(boosted(text, &value)))
+        }
+        QueryField::Exists(query) => {
+            let value = to_value(query);
+            check_parameters(name, &value, &["field", "boost"])?;
+            let text = format!("_exists_:{}", escape(&text_of(&value["field"])));
+            Ok(Rendered::single(boosted(text, &value)))
+        }
+        _ => Err(RenderError::Unsupported(name)),
+    }
+}
+
+fn render_bool(query: &Bool) -> Result<Rendered, RenderError> {
+    if query.minimum_should_match.is_some() {
+        return Err(RenderError::UnsupportedParameter { query: "bool", parameter: "minimum_should_match".into() });
+    }
+
+    let render_all = |clauses: &Option<Vec<QueryField>>| -> Result<Vec<String>, RenderError> {
+        clauses.iter()
+            .flatten()
+            .map(|x| render(x).map(Rendered::nested))
+            .collect()
+    };
+
+    let required: Vec<String> = render_all(&query.must)?.into_iter()
+        .chain(render_all(&query.filter)?)
+        .map(|x| format!("+{x}"))
+        .collect();
+    let prohibited: Vec<String> = render_all(&query.must_not)?.into_iter()
+        .map(|x| format!("-{x}"))
+        .collect();
+    let should = render_all(&query.should)?;
+
+    let mut clauses = Vec::new();
+    if required.is_empty() && should.is_empty() {
+        if prohibited.is_empty() {
+            return Ok(Rendered::single(boosted_bool("*:*".to_string(), false, query.boost)));
+        }
+        // a query of only prohibited clauses matches all other documents
+        clauses.push("*:*".to_string());
+    }
+    clauses.extend(required);
+    clauses.extend(prohibited);
+
+    let (text, compound) = if clauses.is_empty() {
+        (should.join(" OR "), should.len() > 1)
+    } else {
+        clauses.extend(should);
+        // `+` and `-` prefixes need parentheses even for one clause
+        (clauses.join(" "), true)
+    };
+
+    Ok(match query.boost {
+        Some(boost) => Rendered::single(boosted_bool(text, compound, Some(boost))),
+        None => Rendered { text, compound },
+    })
+}
+
+fn boosted_bool(text: String, compound: bool, boost: Option<f64>) -> String {
+    match boost {
+        Some(boost) if compound => format!("({text})^{}", Value::from(boost)),
+        Some(boost) => format!("{text}^{}", Value::from(boost)),
+        None => text,
+    }
+}
+
+fn to_value<T: Serialize>(query: &T) -> Value {
+    serde_json::to_value(query).unwrap_or_default()
+}
+
+/// Field and parameters of a `{"<field>": {...}}` query
+fn field_entry<T: Serialize>(name: &'static str, query: &T, parameters: &[&str]) -> Result<(String, Value), RenderError> {
+    let Value::Object(object) = to_value(query) else {
+        return Err(RenderError::Unsupported(name));
+    };
+    let Some((field, value)) = object.into_iter().next() else {
+        return Err(RenderError::Unsupported(name));
+    };
+    check_parameters(name, &value, parameters)?;
+    Ok((field, value))
+}
+
+fn check_parameters(name: &'static str, value: &Value, parameters: &[&str]) -> Result<(), RenderError> {
+    let unsupported = value.as_object()
+        .and_then(|x| x.keys().find(|key| !parameters.contains(&key.as_str())));
+    match unsupported {
+        Some(parameter) => Err(RenderError::UnsupportedParameter { query: name, parameter: parameter.clone() }),
+        None => Ok(()),
+    }
+}
+
+fn boosted(text: String, value: &Value) -> String {
+    match value.get("boost") {
+        Some(boost) => format!("{text}^{boost}"),
+        None => text,
+    }
+}
+
+fn text_of(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+/// Single term, values with whitespace become phrases
+fn value_term(value: &Value) -> String {
+    let text = text_of(value);
+    if text.chars().any(char::is_whitespace) || text.is_empty() {
+        phrase(&text)
+    } else {
+        escape(&text)
+    }
+}
+
+fn phrase(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if RESERVED.contains(&c) || c.is_whitespace() {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Keeps `*` and `?` wildcards and `\` escapes of the wildcard syntax
+fn wildcard(pattern: &str) -> String {
+    let mut escaped = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' | '?' => escaped.push(c),
+            '\\' => {
+                escaped.push('\\');
+                escaped.extend(chars.next());
+            }
+            c => escaped.push_str(&escape(&c.to_string())),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,363 @@
+use crate::lucene::ast::{Clause, Node, Occur};
+use crate::lucene::parser::{ParseError, MAX_DEPTH};
+use crate::misc::operator::Operator;
+use crate::misc::simple_query_string_flag::SimpleQueryStringFlags;
+
+/// https://opensearch.org/docs/latest/query-dsl/full-text/simple-query-string/#simple-query-string-syntax
+///
+/// Interprets search box input like `simple_query_string` does: operators are applied
+/// from left to right and characters of disabled operators are matched as text.
+/// In strict mode disabled operators and unbalanced groups or quotes are rejected instead.
+///
+/// Examples
+/// ```
+/// use os_query_builder_rs::lucene::ast::{Clause, Node, Occur};
+/// use os_query_builder_rs::lucene::simple::SimpleParser;
+/// use os_query_builder_rs::misc::simple_query_string_flag::SimpleQueryStringFlags;
+///
+/// let parser = SimpleParser::new()
+///             .flags(SimpleQueryStringFlags::AND | SimpleQueryStringFlags::WHITESPACE)
+///             .strict(true);
+///
+/// assert_eq!(parser.parse("wind + rain").unwrap(), Node::Bool(vec![
+///     Clause::new(Occur::Must, Node::Term { field: None, value: "wind".into() }),
+///     Clause::new(Occur::Must, Node::Term { field: None, value: "rain".into() }),
+/// ]));
+/// assert!(parser.parse("wind | rain").is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct SimpleParser {
+    flags: SimpleQueryStringFlags,
+    default_operator: Operator,
+    strict: bool,
+}
+
+impl Default for SimpleParser {
+    fn default() -> Self {
+        Self {
+            flags: SimpleQueryStringFlags::ALL,
+            default_operator: Operator::Or,
+            strict: false,
+        }
+    }
+}
+
+impl SimpleParser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enabled operators, all by default
+    pub fn flags<T: Into<SimpleQueryStringFlags>>(self, flags: T) -> Self {
+        Self {
+            flags: flags.into(),
+            ..self
+        }
+    }
+
+    /// Operator between words without `+` or `|`, `OR` by default
+    pub fn default_operator<T: Into<Operator>>(self, default_operator: T) -> Self {
+        Self {
+            default_operator: default_operator.into(),
+            ..self
+        }
+    }
+
+    /// Rejects input using disabled operators instead of matching them as text
+    pub fn strict(self, strict: bool) -> Self {
+        Self {
+            strict,
+            ..self
+        }
+    }
+
+    pub fn parse(&self, query: &str) -> Result<Node, ParseError> {
+        let mut state = State {
+            chars: query.chars().collect(),
+            position: 0,
+            flags: self.flags,
+            strict: self.strict,
+            default_occur: match self.default_operator {
+                Operator::And => Occur::Must,
+                Operator::Or => Occur::Should,
+            },
+        };
+
+        state.parse_sequence(0)?
+            .ok_or_else(|| ParseError::new(0, "empty query"))
+    }
+}
+
+struct State {
+    chars: Vec<char>,
+    position: usize,
+    flags: SimpleQueryStringFlags,
+    strict: bool,
+    default_occur: Occur,
+}
+
+/// Word and whether it is negated with `-`
+type Pending = (bool, Node);
+
+impl State {
+    fn enabled(&self, flag: SimpleQueryStringFlags) -> bool {
+        self.flags.contains(flag)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Flag of the operator starting with `c` at the current position
+    fn operator_flag(&self, c: char, word_start: bool) -> Option<SimpleQueryStringFlags> {
+        match c {
+            '+' => Some(SimpleQueryStringFlags::AND),
+            '|' => Some(SimpleQueryStringFlags::OR),
+            '-' if word_start => Some(SimpleQueryStringFlags::NOT),
+            '(' | ')' => Some(SimpleQueryStringFlags::PRECEDENCE),
+            '"' => Some(SimpleQueryStringFlags::PHRASE),
+            '~' => Some(SimpleQueryStringFlags::FUZZY),
+            '\\' => Some(SimpleQueryStringFlags::ESCAPE),
+            _ => None,
+        }
+    }
+
+    fn disabled(&self, c: char) -> ParseError {
+        ParseError::new(self.position, format!("operator `{c}` is disabled"))
+    }
+
+    fn skip_whitespace(&mut self) {
+        if self.enabled(SimpleQueryStringFlags::WHITESPACE) {
+            while self.peek().is_some_and(char::is_whitespace) {
+                self.position += 1;
+            }
+        }
+    }
+
+    fn parse_sequence(&mut self, depth: usize) -> Result<Option<Node>, ParseError> {
+        let mut clauses: Vec<Pending> = Vec::new();
+        let mut occur = None;
+        let mut next = None;
+
+        loop {
+            self.skip_whitespace();
+            let Some(c) = self.peek() else {
+                break;
+            };
+
+            match c {
+                ')' if self.enabled(SimpleQueryStringFlags::PRECEDENCE) => {
+                    if depth > 0 {
+                        break;
+                    }
+                    if self.strict {
+                        return Err(ParseError::new(self.position, "unexpected `)`"));
+                    }
+                    self.position += 1;
+                    continue;
+                }
+                '+' if self.enabled(SimpleQueryStringFlags::AND) => {
+                    self.position += 1;
+                    next = Some(Occur::Must);
+                    continue;
+                }
+                '|' if self.enabled(SimpleQueryStringFlags::OR) => {
+                    self.position += 1;
+                    next = Some(Occur::Should);
+                    continue;
+                }
+                _ => {}
+            }
+
+            let negated = self.enabled(SimpleQueryStringFlags::NOT) && self.eat('-');
+            let Some(node) = self.parse_primary(depth)? else {
+                continue;
+            };
+
+            let operator = next.take().unwrap_or(self.default_occur);
+            if !clauses.is_empty() {
+                match occur {
+                    Some(current) if current != operator => {
+                        clauses = vec![(false, build(clauses, current))];
+                        occur = Some(operator);
+                    }
+                    _ => occur = Some(operator),
+                }
+            }
+            clauses.push((negated, node));
+        }
+
+        if clauses.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(build(clauses, occur.unwrap_or(self.default_occur))))
+    }
+
+    fn parse_primary(&mut self, depth: usize) -> Result<Option<Node>, ParseError> {
+        match self.peek() {
+            Some('(') if self.enabled(SimpleQueryStringFlags::PRECEDENCE) => {
+                let start = self.position;
+                if depth == MAX_DEPTH {
+                    return Err(ParseError::new(start, format!("query is nested deeper than {MAX_DEPTH} levels")));
+                }
+                self.position += 1;
+                let node = self.parse_sequence(depth + 1)?;
+                if !self.eat(')') && self.strict {
+                    return Err(ParseError::new(start, "unclosed `(`"));
+                }
+                Ok(node)
+            }
+            Some('"') if self.enabled(SimpleQueryStringFlags::PHRASE) => self.parse_phrase(),
+            _ => self.parse_word(),
+        }
+    }
+
+    fn parse_phrase(&mut self) -> Result<Option<Node>, ParseError> {
+        let start = self.position;
+        self.position += 1;
+
+        let mut text = String::new();
+        loop {
+            match self.peek() {
+                None if self.strict => return Err(ParseError::new(start, "unterminated phrase")),
+                None => break,
+                Some('"') => {
+                    self.position += 1;
+                    break;
+                }
+                Some('\\') if self.enabled(SimpleQueryStringFlags::ESCAPE) => {
+                    self.position += 1;
+                    text.extend(self.peek());
+                    self.position += 1;
+                }
+                Some(c) => {
+                    self.position += 1;
+                    text.push(c);
+                }
+            }
+        }
+
+        let slop = if self.peek() == Some('~') && self.enabled(SimpleQueryStringFlags::NEAR) {
+            self.position += 1;
+            self.read_number()
+        } else {
+            None
+        };
+
+        if text.trim().is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(Node::Phrase { field: None, text, slop }))
+    }
+
+    fn parse_word(&mut self) -> Result<Option<Node>, ParseError> {
+        let start = self.position;
+        let mut text = String::new();
+        let mut pattern = String::new();
+        let mut prefix = false;
+
+        while let Some(c) = self.peek() {
+            if c.is_whitespace() && self.enabled(SimpleQueryStringFlags::WHITESPACE) {
+                break;
+            }
+            if let Some(flag) = self.operator_flag(c, self.position == start) {
+                let enabled = match c {
+                    '~' => self.enabled(SimpleQueryStringFlags::FUZZY),
+                    _ => self.enabled(flag),
+                };
+                if enabled && c != '\\' {
+                    break;
+                }
+                if !enabled && self.strict {
+                    return Err(self.disabled(c));
+                }
+                if enabled {
+                    self.position += 1;
+                    let Some(escaped) = self.peek() else {
+                        break;
+                    };
+                    self.position += 1;
+                    text.push(escaped);
+                    pattern.push_str(&escape_wildcard(escaped));
+                    prefix = false;
+                    continue;
+                }
+            }
+
+            self.position += 1;
+            text.push(c);
+            pattern.push_str(&escape_wildcard(c));
+            prefix = c == '*';
+        }
+
+        if text.trim().is_empty() {
+            // a disabled or dangling operator, skip it
+            if self.position == start {
+                self.position += 1;
+            }
+            return Ok(None);
+        }
+
+        if self.peek() == Some('~') && self.enabled(SimpleQueryStringFlags::FUZZY) {
+            self.position += 1;
+            let distance = self.read_number();
+            return Ok(Some(Node::Fuzzy { field: None, value: text.trim().to_string(), distance }));
+        }
+
+        if prefix {
+            if !self.enabled(SimpleQueryStringFlags::PREFIX) {
+                if self.strict {
+                    return Err(ParseError::new(self.position - 1, "operator `*` is disabled"));
+                }
+            } else {
+                pattern.truncate(pattern.len() - "\\*".len());
+                pattern.push('*');
+                return Ok(Some(Node::Wildcard { field: None, pattern }));
+            }
+        }
+
+        Ok(Some(Node::Term { field: None, value: text.trim().to_string() }))
+    }
+
+    fn read_number<T: std::str::FromStr>(&mut self) -> Option<T> {
+        let start = self.position;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.position += 1;
+        }
+        self.chars[start..self.position].iter().collect::<String>().parse().ok()
+    }
+}
+
+fn escape_wildcard(c: char) -> String {
+    match c {
+        '*' | '?' | '\\' => format!("\\{c}"),
+        c => c.to_string(),
+    }
+}
+
+/// Clauses joined with one operator, `-word` under `OR` matches everything except the word
+fn build(mut clauses: Vec<Pending>, occur: Occur) -> Node {
+    let negate = |node| Node::Bool(vec![Clause::new(Occur::MustNot, node)]);
+
+    if clauses.len() == 1 {
+        let (negated, node) = clauses.pop().expect("one clause");
+        return if negated { negate(node) } else { node };
+    }
+
+    Node::Bool(clauses.into_iter()
+        .map(|(negated, node)| match (negated, occur) {
+            (true, Occur::Must) => Clause::new(Occur::MustNot, node),
+            (true, _) => Clause::new(occur, negate(node)),
+            (false, _) => Clause::new(occur, node),
+        })
+        .collect())
+}
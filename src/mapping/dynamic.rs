@@ -0,0 +1,168 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::Error;
+use serde_json::Value;
+use crate::mapping::field::FieldMapping;
+
+/// How unmapped fields of new documents are handled
+/// https://opensearch.org/docs/latest/field-types/index/#dynamic-mapping-parameters
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dynamic {
+    /// Adds the fields to the mapping
+    True,
+    /// Keeps the fields in `_source` without indexing them
+    False,
+    /// Rejects the document
+    Strict,
+    /// Rejects the document unless a dynamic template matches the field
+    StrictAllowTemplates,
+}
+
+impl From<bool> for Dynamic {
+    fn from(value: bool) -> Self {
+        if value { Dynamic::True } else { Dynamic::False }
+    }
+}
+
+impl Serialize for Dynamic {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+    {
+        match self {
+            Dynamic::True => serializer.serialize_bool(true),
+            Dynamic::False => serializer.serialize_bool(false),
+            Dynamic::Strict => serializer.serialize_str("strict"),
+            Dynamic::StrictAllowTemplates => serializer.serialize_str("strict_allow_templates"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Dynamic {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        match Value::deserialize(deserializer)? {
+            Value::Bool(value) => Ok(value.into()),
+            Value::String(value) => match value.as_str() {
+                "true" => Ok(Dynamic::True),
+                "false" => Ok(Dynamic::False),
+                "strict" => Ok(Dynamic::Strict),
+                "strict_allow_templates" => Ok(Dynamic::StrictAllowTemplates),
+                _ => Err(D::Error::custom(format!("unknown dynamic value `{value}`"))),
+            },
+            value => Err(D::Error::custom(format!("expected a boolean or a string for dynamic, found {value}"))),
+        }
+    }
+}
+
+/// Mapping applied to new fields matching the conditions
+/// https://opensearch.org/docs/latest/field-types/index/#dynamic-templates
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DynamicTemplate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    match_mapping_type: Option<MatchMappingType>,
+
+    #[serde(rename = "match", skip_serializing_if = "Option::is_none")]
+    match_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unmatch: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path_match: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path_unmatch: Option<String>,
+
+    mapping: TemplateMapping,
+}
+
+/// Typed field mapping, or any mapping such as one without `type` or with `{dynamic_type}`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+enum TemplateMapping {
+    Field(FieldMapping),
+    Raw(Value),
+}
+
+impl DynamicTemplate {
+    pub fn new<T: Into<FieldMapping>>(mapping: T) -> Self {
+        Self::with_mapping(TemplateMapping::Field(mapping.into()))
+    }
+
+    /// Template with an untyped mapping, e.g. `{"type": "{dynamic_type}", "index": false}`
+    /// or `{"doc_values": false}` which keeps the detected type
+    pub fn raw<T: Into<Value>>(mapping: T) -> Self {
+        Self::with_mapping(TemplateMapping::Raw(mapping.into()))
+    }
+
+    fn with_mapping(mapping: TemplateMapping) -> Self {
+        Self {
+            match_mapping_type: None,
+            match_name: None,
+            unmatch: None,
+            path_match: None,
+            path_unmatch: None,
+            mapping,
+        }
+    }
+
+    /// Typed mapping, `None` for a raw mapping
+    pub fn get_mapping(&self) -> Option<&FieldMapping> {
+        match &self.mapping {
+            TemplateMapping::Field(mapping) => Some(mapping),
+            TemplateMapping::Raw(_) => None,
+        }
+    }
+
+    /// JSON type detected for the new field
+    pub fn match_mapping_type<T: Into<MatchMappingType>>(self, match_mapping_type: T) -> Self {
+        Self {
+            match_mapping_type: Some(match_mapping_type.into()),
+            ..self
+        }
+    }
+
+    /// Wildcard pattern of the field name, `match` in the body
+    pub fn match_name<T: Into<String>>(self, match_name: T) -> Self {
+        Self {
+            match_name: Some(match_name.into()),
+            ..self
+        }
+    }
+
+    pub fn unmatch<T: Into<String>>(self, unmatch: T) -> Self {
+        Self {
+            unmatch: Some(unmatch.into()),
+            ..self
+        }
+    }
+
+    /// Wildcard pattern of the full dotted path of the field
+    pub fn path_match<T: Into<String>>(self, path_match: T) -> Self {
+        Self {
+            path_match: Some(path_match.into()),
+            ..self
+        }
+    }
+
+    pub fn path_unmatch<T: Into<String>>(self, path_unmatch: T) -> Self {
+        Self {
+            path_unmatch: Some(path_unmatch.into()),
+            ..self
+        }
+    }
+}
+
+/// https://opensearch.org/docs/latest/field-types/index/#dynamic-templates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MatchMappingType {
+    Boolean,
+    Date,
+    Double,
+    Long,
+    Object,
+    String,
+}
@@ -0,0 +1,149 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::Error;
+use serde_json::{Map, Value};
+use crate::mapping::{
+    scalar::{BooleanField, DateField, IpField},
+    geo::{GeoPointField, GeoShapeField},
+    knn::KnnVectorField,
+    numeric::{NumericField, ScaledFloatField},
+    object::{FlattenedField, JoinField, NestedField, ObjectField},
+    rank_feature::{RankFeatureField, RankFeaturesField},
+    text::{CompletionField, KeywordField, SearchAsYouTypeField, TextField}
+};
+
+/// Mapping of a field, serialized with its `type`
+/// https://opensearch.org/docs/latest/field-types/supported-field-types/index/
+///
+/// Types with a single options struct convert from it, numeric and date types are picked by variant:
+/// ```
+/// use os_query_builder_rs::mapping::field::FieldMapping;
+/// use os_query_builder_rs::mapping::numeric::NumericField;
+/// use os_query_builder_rs::mapping::text::KeywordField;
+///
+/// let price = FieldMapping::Double(NumericField::new().coerce(false));
+/// let brand = FieldMapping::from(KeywordField::new().ignore_above(256u32));
+///
+/// assert_eq!(serde_json::to_string(&price).unwrap(), r#"{"type":"double","coerce":false}"#);
+/// assert_eq!(serde_json::to_string(&brand).unwrap(), r#"{"type":"keyword","ignore_above":256}"#);
+/// ```
+///
+/// Unknown options are rejected. Objects read from `GET _mapping` have no `type`,
+/// a mapping with `properties` and without `type` is an `object`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(remote = "Self", tag = "type", rename_all = "snake_case")]
+pub enum FieldMapping {
+    Text(TextField),
+    Keyword(KeywordField),
+    Long(NumericField),
+    Integer(NumericField),
+    Short(NumericField),
+    Byte(NumericField),
+    Double(NumericField),
+    Float(NumericField),
+    HalfFloat(NumericField),
+    UnsignedLong(NumericField),
+    ScaledFloat(ScaledFloatField),
+    Date(DateField),
+    DateNanos(DateField),
+    Boolean(BooleanField),
+    Ip(IpField),
+    GeoPoint(GeoPointField),
+    GeoShape(GeoShapeField),
+    Object(ObjectField),
+    Nested(NestedField),
+    #[serde(rename = "flat_object", alias = "flattened")]
+    Flattened(FlattenedField),
+    Join(JoinField),
+    KnnVector(KnnVectorField),
+    RankFeature(RankFeatureField),
+    RankFeatures(RankFeaturesField),
+    Completion(CompletionField),
+    SearchAsYouType(SearchAsYouTypeField),
+}
+
+impl FieldMapping {
+    /// Name of the type, as in the `type` key
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            FieldMapping::Text(_) => "text",
+            FieldMapping::Keyword(_) => "keyword",
+            FieldMapping::Long(_) => "long",
+            FieldMapping::Integer(_) => "integer",
+            FieldMapping::Short(_) => "short",
+            FieldMapping::Byte(_) => "byte",
+            FieldMapping::Double(_) => "double",
+            FieldMapping::Float(_) => "float",
+            FieldMapping::HalfFloat(_) => "half_float",
+            FieldMapping::UnsignedLong(_) => "unsigned_long",
+            FieldMapping::ScaledFloat(_) => "scaled_float",
+            FieldMapping::Date(_) => "date",
+            FieldMapping::DateNanos(_) => "date_nanos",
+            FieldMapping::Boolean(_) => "boolean",
+            FieldMapping::Ip(_) => "ip",
+            FieldMapping::GeoPoint(_) => "geo_point",
+            FieldMapping::GeoShape(_) => "geo_shape",
+            FieldMapping::Object(_) => "object",
+            FieldMapping::Nested(_) => "nested",
+            FieldMapping::Flattened(_) => "flat_object",
+            FieldMapping::Join(_) => "join",
+            FieldMapping::KnnVector(_) => "knn_vector",
+            FieldMapping::RankFeature(_) => "rank_feature",
+            FieldMapping::RankFeatures(_) => "rank_features",
+            FieldMapping::Completion(_) => "completion",
+            FieldMapping::SearchAsYouType(_) => "search_as_you_type",
+        }
+    }
+}
+
+macro_rules! from_field {
+    ($(($field:ident, $variant:ident)),*) => {
+        $(
+            impl From<$field> for FieldMapping {
+                fn from(field: $field) -> Self {
+                    FieldMapping::$variant(field)
+                }
+            }
+        )*
+    }
+}
+
+from_field! {
+    (TextField, Text),
+    (KeywordField, Keyword),
+    (ScaledFloatField, ScaledFloat),
+    (BooleanField, Boolean),
+    (IpField, Ip),
+    (GeoPointField, GeoPoint),
+    (GeoShapeField, GeoShape),
+    (ObjectField, Object),
+    (NestedField, Nested),
+    (FlattenedField, Flattened),
+    (JoinField, Join),
+    (KnnVectorField, KnnVector),
+    (RankFeatureField, RankFeature),
+    (RankFeaturesField, RankFeatures),
+    (CompletionField, Completion),
+    (SearchAsYouTypeField, SearchAsYouType)
+}
+
+impl Serialize for FieldMapping {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+    {
+        FieldMapping::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for FieldMapping {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        let mut map = Map::<String, Value>::deserialize(deserializer)?;
+        if !map.contains_key("type") && map.contains_key("properties") {
+            map.insert("type".to_string(), Value::from("object"));
+        }
+        FieldMapping::deserialize(Value::Object(map)).map_err(D::Error::custom)
+    }
+}
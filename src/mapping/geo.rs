@@ -0,0 +1,103 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Latitude and longitude pair
+/// https://opensearch.org/docs/latest/field-types/supported-field-types/geo-point/
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GeoPointField {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ignore_malformed: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ignore_z_value: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    null_value: Option<Value>,
+}
+
+impl GeoPointField {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Indexes the document without the field instead of rejecting it
+    pub fn ignore_malformed(self, ignore_malformed: bool) -> Self {
+        Self {
+            ignore_malformed: Some(ignore_malformed),
+            ..self
+        }
+    }
+
+    /// Accepts and drops the third coordinate
+    pub fn ignore_z_value(self, ignore_z_value: bool) -> Self {
+        Self {
+            ignore_z_value: Some(ignore_z_value),
+            ..self
+        }
+    }
+
+    /// Point indexed instead of `null`
+    pub fn null_value<T: Into<Value>>(self, null_value: T) -> Self {
+        Self {
+            null_value: Some(null_value.into()),
+            ..self
+        }
+    }
+}
+
+/// Shape such as a polygon or a line
+/// https://opensearch.org/docs/latest/field-types/supported-field-types/geo-shape/
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GeoShapeField {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    orientation: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    coerce: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ignore_malformed: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ignore_z_value: Option<bool>,
+}
+
+impl GeoShapeField {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Vertex order of polygons, `right` (counterclockwise) or `left`
+    pub fn orientation<T: Into<String>>(self, orientation: T) -> Self {
+        Self {
+            orientation: Some(orientation.into()),
+            ..self
+        }
+    }
+
+    /// Closes unclosed polygons
+    pub fn coerce(self, coerce: bool) -> Self {
+        Self {
+            coerce: Some(coerce),
+            ..self
+        }
+    }
+
+    /// Indexes the document without the field instead of rejecting it
+    pub fn ignore_malformed(self, ignore_malformed: bool) -> Self {
+        Self {
+            ignore_malformed: Some(ignore_malformed),
+            ..self
+        }
+    }
+
+    /// Accepts and drops the third coordinate
+    pub fn ignore_z_value(self, ignore_z_value: bool) -> Self {
+        Self {
+            ignore_z_value: Some(ignore_z_value),
+            ..self
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use std::collections::BTreeMap;
+use serde::{Deserialize, Serialize};
+use crate::mapping::dynamic::{Dynamic, DynamicTemplate};
+use crate::mapping::field::FieldMapping;
+
+/// Mappings of an index, the `mappings` of the create-index body or the `_mapping` endpoint body
+/// https://opensearch.org/docs/latest/field-types/
+///
+/// Examples
+/// ```
+/// use serde_json::json;
+/// use os_query_builder_rs::mapping::dynamic::Dynamic;
+/// use os_query_builder_rs::mapping::field::FieldMapping;
+/// use os_query_builder_rs::mapping::index_mapping::IndexMapping;
+/// use os_query_builder_rs::mapping::numeric::NumericField;
+/// use os_query_builder_rs::mapping::text::{KeywordField, TextField};
+///
+/// let mapping = IndexMapping::new()
+///             .dynamic(Dynamic::Strict)
+///             .property("title", TextField::new().analyzer("english").field("raw", KeywordField::new()))
+///             .property("price", FieldMapping::Double(NumericField::new()));
+///
+/// assert_eq!(serde_json::to_value(mapping).unwrap(), json!({
+///     "dynamic": "strict",
+///     "properties": {
+///         "price": { "type": "double" },
+///         "title": { "type": "text", "analyzer": "english", "fields": { "raw": { "type": "keyword" } } }
+///     }
+/// }));
+/// ```
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct IndexMapping {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dynamic: Option<Dynamic>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    dynamic_templates: Vec<BTreeMap<String, DynamicTemplate>>,
+
+    #[serde(rename = "_source", skip_serializing_if = "Option::is_none")]
+    source: Option<SourceField>,
+
+    #[serde(rename = "_routing", skip_serializing_if = "Option::is_none")]
+    routing: Option<RoutingField>,
+
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    properties: BTreeMap<String, FieldMapping>,
+}
+
+impl IndexMapping {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn dynamic<T: Into<Dynamic>>(self, dynamic: T) -> Self {
+        Self {
+            dynamic: Some(dynamic.into()),
+            ..self
+        }
+    }
+
+    /// Appends a template, templates are tried in order
+    pub fn dynamic_template<K: Into<String>, T: Into<DynamicTemplate>>(mut self, name: K, template: T) -> Self {
+        self.dynamic_templates.push(BTreeMap::from([(name.into(), template.into())]));
+        self
+    }
+
+    pub fn source<T: Into<SourceField>>(self, source: T) -> Self {
+        Self {
+            source: Some(source.into()),
+            ..self
+        }
+    }
+
+    pub fn routing<T: Into<RoutingField>>(self, routing: T) -> Self {
+        Self {
+            routing: Some(routing.into()),
+            ..self
+        }
+    }
+
+    /// Adds a field, objects and nested fields hold their own properties
+    pub fn property<K: Into<String>, T: Into<FieldMapping>>(mut self, name: K, field: T) -> Self {
+        self.properties.insert(name.into(), field.into());
+        self
+    }
+
+    pub fn get_property(&self, name: &str) -> Option<&FieldMapping> {
+        self.properties.get(name)
+    }
+}
+
+/// https://opensearch.org/docs/latest/field-types/metadata-fields/source/
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct SourceField {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enabled: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    includes: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    excludes: Option<Vec<String>>,
+}
+
+impl SourceField {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `false` does not store the original documents, which breaks reindex and update
+    pub fn enabled(self, enabled: bool) -> Self {
+        Self {
+            enabled: Some(enabled),
+            ..self
+        }
+    }
+
+    pub fn includes<F, T>(self, includes: F) -> Self
+        where
+            F: IntoIterator<Item=T>,
+            T: Into<String>
+    {
+        Self {
+            includes: Some(includes.into_iter().map(|x| x.into()).collect()),
+            ..self
+        }
+    }
+
+    pub fn excludes<F, T>(self, excludes: F) -> Self
+        where
+            F: IntoIterator<Item=T>,
+            T: Into<String>
+    {
+        Self {
+            excludes: Some(excludes.into_iter().map(|x| x.into()).collect()),
+            ..self
+        }
+    }
+}
+
+/// https://opensearch.org/docs/latest/field-types/metadata-fields/routing/
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct RoutingField {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    required: Option<bool>,
+}
+
+impl RoutingField {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rejects indexing, getting and deleting documents without a routing value
+    pub fn required(self, required: bool) -> Self {
+        Self {
+            required: Some(required),
+        }
+    }
+}
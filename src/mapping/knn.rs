@@ -0,0 +1,113 @@
+use std::collections::BTreeMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Vector for k-NN search, requires `index.knn` in the index settings
+/// https://opensearch.org/docs/latest/field-types/supported-field-types/knn-vector/
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct KnnVectorField {
+    dimension: u32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    method: Option<KnnMethod>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    model_id: Option<String>,
+}
+
+impl KnnVectorField {
+    pub fn new<T: Into<u32>>(dimension: T) -> Self {
+        Self {
+            dimension: dimension.into(),
+            method: None,
+            model_id: None,
+        }
+    }
+
+    /// Algorithm of the approximate search
+    pub fn method<T: Into<KnnMethod>>(self, method: T) -> Self {
+        Self {
+            method: Some(method.into()),
+            ..self
+        }
+    }
+
+    /// Trained model used instead of a method
+    pub fn model_id<T: Into<String>>(self, model_id: T) -> Self {
+        Self {
+            model_id: Some(model_id.into()),
+            ..self
+        }
+    }
+}
+
+/// https://opensearch.org/docs/latest/search-plugins/knn/knn-index/#method-definitions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct KnnMethod {
+    name: String,
+
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    parameters: BTreeMap<String, Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    engine: Option<KnnEngine>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    space_type: Option<SpaceType>,
+}
+
+impl KnnMethod {
+    /// `hnsw` or `ivf`
+    pub fn new<T: Into<String>>(name: T) -> Self {
+        Self {
+            name: name.into(),
+            parameters: BTreeMap::new(),
+            engine: None,
+            space_type: None,
+        }
+    }
+
+    pub fn engine<T: Into<KnnEngine>>(self, engine: T) -> Self {
+        Self {
+            engine: Some(engine.into()),
+            ..self
+        }
+    }
+
+    /// Distance function of the vector space
+    pub fn space_type<T: Into<SpaceType>>(self, space_type: T) -> Self {
+        Self {
+            space_type: Some(space_type.into()),
+            ..self
+        }
+    }
+
+    /// Adds a parameter of the method, e.g. `ef_construction` or `m` of `hnsw`
+    pub fn parameter<K: Into<String>, V: Into<Value>>(mut self, name: K, value: V) -> Self {
+        self.parameters.insert(name.into(), value.into());
+        self
+    }
+}
+
+/// https://opensearch.org/docs/latest/search-plugins/knn/knn-index/#method-definitions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KnnEngine {
+    Faiss,
+    Lucene,
+    Nmslib,
+}
+
+/// https://opensearch.org/docs/latest/search-plugins/knn/approximate-knn/#spaces
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SpaceType {
+    L1,
+    L2,
+    Linf,
+    Cosinesimil,
+    Innerproduct,
+    Hamming,
+}
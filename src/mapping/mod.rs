@@ -0,0 +1,10 @@
+pub mod index_mapping;
+pub mod field;
+pub mod dynamic;
+pub mod text;
+pub mod numeric;
+pub mod scalar;
+pub mod geo;
+pub mod object;
+pub mod rank_feature;
+pub mod knn;
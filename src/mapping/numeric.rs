@@ -0,0 +1,183 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Options of `long`, `integer`, `short`, `byte`, `double`, `float`, `half_float` and `unsigned_long` fields,
+/// the type is the [`FieldMapping`](crate::mapping::field::FieldMapping) variant
+/// https://opensearch.org/docs/latest/field-types/supported-field-types/numeric/
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NumericField {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    coerce: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ignore_malformed: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    index: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    doc_values: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    store: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    null_value: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    copy_to: Option<Vec<String>>,
+}
+
+impl NumericField {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Converts strings and truncates fractions
+    pub fn coerce(self, coerce: bool) -> Self {
+        Self {
+            coerce: Some(coerce),
+            ..self
+        }
+    }
+
+    /// Indexes the document without the field instead of rejecting it
+    pub fn ignore_malformed(self, ignore_malformed: bool) -> Self {
+        Self {
+            ignore_malformed: Some(ignore_malformed),
+            ..self
+        }
+    }
+
+    /// Whether the field is searchable
+    pub fn index(self, index: bool) -> Self {
+        Self {
+            index: Some(index),
+            ..self
+        }
+    }
+
+    /// Whether the field is stored column-wise for sorting and aggregations
+    pub fn doc_values(self, doc_values: bool) -> Self {
+        Self {
+            doc_values: Some(doc_values),
+            ..self
+        }
+    }
+
+    /// Whether the field is stored apart from `_source`
+    pub fn store(self, store: bool) -> Self {
+        Self {
+            store: Some(store),
+            ..self
+        }
+    }
+
+    /// Value indexed instead of `null`
+    pub fn null_value<T: Into<Value>>(self, null_value: T) -> Self {
+        Self {
+            null_value: Some(null_value.into()),
+            ..self
+        }
+    }
+
+    /// Fields the value is copied to
+    pub fn copy_to<F, T>(self, copy_to: F) -> Self
+        where
+            F: IntoIterator<Item=T>,
+            T: Into<String>
+    {
+        Self {
+            copy_to: Some(copy_to.into_iter().map(|x| x.into()).collect()),
+            ..self
+        }
+    }
+}
+
+/// Floating point stored as a `long` multiplied by `scaling_factor`
+/// https://opensearch.org/docs/latest/field-types/supported-field-types/numeric/#scaled-float-field-type
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScaledFloatField {
+    scaling_factor: f64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    coerce: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ignore_malformed: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    index: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    doc_values: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    store: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    null_value: Option<f64>,
+}
+
+impl ScaledFloatField {
+    pub fn new<T: Into<f64>>(scaling_factor: T) -> Self {
+        Self {
+            scaling_factor: scaling_factor.into(),
+            coerce: None,
+            ignore_malformed: None,
+            index: None,
+            doc_values: None,
+            store: None,
+            null_value: None,
+        }
+    }
+
+    pub fn coerce(self, coerce: bool) -> Self {
+        Self {
+            coerce: Some(coerce),
+            ..self
+        }
+    }
+
+    /// Indexes the document without the field instead of rejecting it
+    pub fn ignore_malformed(self, ignore_malformed: bool) -> Self {
+        Self {
+            ignore_malformed: Some(ignore_malformed),
+            ..self
+        }
+    }
+
+    /// Whether the field is searchable
+    pub fn index(self, index: bool) -> Self {
+        Self {
+            index: Some(index),
+            ..self
+        }
+    }
+
+    /// Whether the field is stored column-wise for sorting and aggregations
+    pub fn doc_values(self, doc_values: bool) -> Self {
+        Self {
+            doc_values: Some(doc_values),
+            ..self
+        }
+    }
+
+    /// Whether the field is stored apart from `_source`
+    pub fn store(self, store: bool) -> Self {
+        Self {
+            store: Some(store),
+            ..self
+        }
+    }
+
+    /// Value indexed instead of `null`
+    pub fn null_value<T: Into<f64>>(self, null_value: T) -> Self {
+        Self {
+            null_value: Some(null_value.into()),
+            ..self
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use std::collections::BTreeMap;
+use serde::{Deserialize, Serialize};
+use crate::mapping::dynamic::Dynamic;
+use crate::mapping::field::FieldMapping;
+
+/// JSON object whose fields are flattened into the parent document
+/// https://opensearch.org/docs/latest/field-types/supported-field-types/object/
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ObjectField {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dynamic: Option<Dynamic>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enabled: Option<bool>,
+
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    properties: BTreeMap<String, FieldMapping>,
+}
+
+impl ObjectField {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Overrides the `dynamic` setting of the parent
+    pub fn dynamic<T: Into<Dynamic>>(self, dynamic: T) -> Self {
+        Self {
+            dynamic: Some(dynamic.into()),
+            ..self
+        }
+    }
+
+    /// `false` keeps the object in `_source` without parsing it
+    pub fn enabled(self, enabled: bool) -> Self {
+        Self {
+            enabled: Some(enabled),
+            ..self
+        }
+    }
+
+    /// Adds a field of the object
+    pub fn property<K: Into<String>, T: Into<FieldMapping>>(mut self, name: K, property: T) -> Self {
+        self.properties.insert(name.into(), property.into());
+        self
+    }
+}
+
+/// Array of objects indexed as separate hidden documents, for `nested` queries
+/// https://opensearch.org/docs/latest/field-types/supported-field-types/nested/
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NestedField {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dynamic: Option<Dynamic>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    include_in_parent: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    include_in_root: Option<bool>,
+
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    properties: BTreeMap<String, FieldMapping>,
+}
+
+impl NestedField {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Overrides the `dynamic` setting of the parent
+    pub fn dynamic<T: Into<Dynamic>>(self, dynamic: T) -> Self {
+        Self {
+            dynamic: Some(dynamic.into()),
+            ..self
+        }
+    }
+
+    /// Also indexes the fields into the parent document
+    pub fn include_in_parent(self, include_in_parent: bool) -> Self {
+        Self {
+            include_in_parent: Some(include_in_parent),
+            ..self
+        }
+    }
+
+    /// Also indexes the fields into the root document
+    pub fn include_in_root(self, include_in_root: bool) -> Self {
+        Self {
+            include_in_root: Some(include_in_root),
+            ..self
+        }
+    }
+
+    /// Adds a field of the nested objects
+    pub fn property<K: Into<String>, T: Into<FieldMapping>>(mut self, name: K, property: T) -> Self {
+        self.properties.insert(name.into(), property.into());
+        self
+    }
+}
+
+/// Object indexed as a single field of its leaf values, `flat_object` in OpenSearch
+/// https://opensearch.org/docs/latest/field-types/supported-field-types/flat-object/
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FlattenedField {}
+
+impl FlattenedField {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Parent-child relation between documents of the same index
+/// https://opensearch.org/docs/latest/field-types/supported-field-types/join/
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct JoinField {
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    relations: BTreeMap<String, Vec<String>>,
+}
+
+impl JoinField {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds children of a parent relation
+    pub fn relation<P, F, T>(mut self, parent: P, children: F) -> Self
+        where
+            P: Into<String>,
+            F: IntoIterator<Item=T>,
+            T: Into<String>
+    {
+        self.relations
+            .entry(parent.into())
+            .or_default()
+            .extend(children.into_iter().map(|x| x.into()));
+        self
+    }
+}
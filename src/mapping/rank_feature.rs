@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+/// Numeric feature boosting scores in `rank_feature` queries
+/// https://opensearch.org/docs/latest/field-types/supported-field-types/rank/
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RankFeatureField {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    positive_score_impact: Option<bool>,
+}
+
+impl RankFeatureField {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `false` for features that lower the relevance as they grow
+    pub fn positive_score_impact(self, positive_score_impact: bool) -> Self {
+        Self {
+            positive_score_impact: Some(positive_score_impact),
+        }
+    }
+}
+
+/// Map of numeric features boosting scores in `rank_feature` queries
+/// https://opensearch.org/docs/latest/field-types/supported-field-types/rank/
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RankFeaturesField {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    positive_score_impact: Option<bool>,
+}
+
+impl RankFeaturesField {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `false` for features that lower the relevance as they grow
+    pub fn positive_score_impact(self, positive_score_impact: bool) -> Self {
+        Self {
+            positive_score_impact: Some(positive_score_impact),
+        }
+    }
+}
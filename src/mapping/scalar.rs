@@ -0,0 +1,216 @@
+use serde::{Deserialize, Serialize};
+
+/// Options of `date` and `date_nanos` fields
+/// https://opensearch.org/docs/latest/field-types/supported-field-types/date/
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DateField {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    format: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ignore_malformed: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    index: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    doc_values: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    store: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    null_value: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    copy_to: Option<Vec<String>>,
+}
+
+impl DateField {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Formats separated by `||`, e.g. `strict_date_optional_time||epoch_millis`
+    pub fn format<T: Into<String>>(self, format: T) -> Self {
+        Self {
+            format: Some(format.into()),
+            ..self
+        }
+    }
+
+    /// Indexes the document without the field instead of rejecting it
+    pub fn ignore_malformed(self, ignore_malformed: bool) -> Self {
+        Self {
+            ignore_malformed: Some(ignore_malformed),
+            ..self
+        }
+    }
+
+    /// Whether the field is searchable
+    pub fn index(self, index: bool) -> Self {
+        Self {
+            index: Some(index),
+            ..self
+        }
+    }
+
+    /// Whether the field is stored column-wise for sorting and aggregations
+    pub fn doc_values(self, doc_values: bool) -> Self {
+        Self {
+            doc_values: Some(doc_values),
+            ..self
+        }
+    }
+
+    /// Whether the field is stored apart from `_source`
+    pub fn store(self, store: bool) -> Self {
+        Self {
+            store: Some(store),
+            ..self
+        }
+    }
+
+    /// Value indexed instead of `null`
+    pub fn null_value<T: Into<String>>(self, null_value: T) -> Self {
+        Self {
+            null_value: Some(null_value.into()),
+            ..self
+        }
+    }
+
+    /// Fields the value is copied to
+    pub fn copy_to<F, T>(self, copy_to: F) -> Self
+        where
+            F: IntoIterator<Item=T>,
+            T: Into<String>
+    {
+        Self {
+            copy_to: Some(copy_to.into_iter().map(|x| x.into()).collect()),
+            ..self
+        }
+    }
+}
+
+/// https://opensearch.org/docs/latest/field-types/supported-field-types/boolean/
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BooleanField {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    index: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    doc_values: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    store: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    null_value: Option<bool>,
+}
+
+impl BooleanField {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the field is searchable
+    pub fn index(self, index: bool) -> Self {
+        Self {
+            index: Some(index),
+            ..self
+        }
+    }
+
+    /// Whether the field is stored column-wise for sorting and aggregations
+    pub fn doc_values(self, doc_values: bool) -> Self {
+        Self {
+            doc_values: Some(doc_values),
+            ..self
+        }
+    }
+
+    /// Whether the field is stored apart from `_source`
+    pub fn store(self, store: bool) -> Self {
+        Self {
+            store: Some(store),
+            ..self
+        }
+    }
+
+    /// Value indexed instead of `null`
+    pub fn null_value(self, null_value: bool) -> Self {
+        Self {
+            null_value: Some(null_value),
+            ..self
+        }
+    }
+}
+
+/// IPv4 or IPv6 address
+/// https://opensearch.org/docs/latest/field-types/supported-field-types/ip/
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct IpField {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ignore_malformed: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    index: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    doc_values: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    store: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    null_value: Option<String>,
+}
+
+impl IpField {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Indexes the document without the field instead of rejecting it
+    pub fn ignore_malformed(self, ignore_malformed: bool) -> Self {
+        Self {
+            ignore_malformed: Some(ignore_malformed),
+            ..self
+        }
+    }
+
+    /// Whether the field is searchable
+    pub fn index(self, index: bool) -> Self {
+        Self {
+            index: Some(index),
+            ..self
+        }
+    }
+
+    /// Whether the field is stored column-wise for sorting and aggregations
+    pub fn doc_values(self, doc_values: bool) -> Self {
+        Self {
+            doc_values: Some(doc_values),
+            ..self
+        }
+    }
+
+    /// Whether the field is stored apart from `_source`
+    pub fn store(self, store: bool) -> Self {
+        Self {
+            store: Some(store),
+            ..self
+        }
+    }
+
+    /// Value indexed instead of `null`
+    pub fn null_value<T: Into<String>>(self, null_value: T) -> Self {
+        Self {
+            null_value: Some(null_value.into()),
+            ..self
+        }
+    }
+}
@@ -0,0 +1,375 @@
+use std::collections::BTreeMap;
+use serde::{Deserialize, Serialize};
+use crate::mapping::field::FieldMapping;
+
+/// Analyzed full-text field
+/// https://opensearch.org/docs/latest/field-types/supported-field-types/text/
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TextField {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    analyzer: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    search_analyzer: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    index: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    store: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fielddata: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    norms: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    index_options: Option<IndexOptions>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    copy_to: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    fields: BTreeMap<String, FieldMapping>,
+}
+
+impl TextField {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Analyzer used at index time
+    pub fn analyzer<T: Into<String>>(self, analyzer: T) -> Self {
+        Self {
+            analyzer: Some(analyzer.into()),
+            ..self
+        }
+    }
+
+    /// Analyzer used at search time, defaults to `analyzer`
+    pub fn search_analyzer<T: Into<String>>(self, search_analyzer: T) -> Self {
+        Self {
+            search_analyzer: Some(search_analyzer.into()),
+            ..self
+        }
+    }
+
+    /// Whether the field is searchable
+    pub fn index(self, index: bool) -> Self {
+        Self {
+            index: Some(index),
+            ..self
+        }
+    }
+
+    /// Whether the field is stored apart from `_source`
+    pub fn store(self, store: bool) -> Self {
+        Self {
+            store: Some(store),
+            ..self
+        }
+    }
+
+    /// Enables sorting and aggregations on the analyzed terms, memory intensive
+    pub fn fielddata(self, fielddata: bool) -> Self {
+        Self {
+            fielddata: Some(fielddata),
+            ..self
+        }
+    }
+
+    /// Whether the field length is stored for scoring
+    pub fn norms(self, norms: bool) -> Self {
+        Self {
+            norms: Some(norms),
+            ..self
+        }
+    }
+
+    /// Information indexed for search and highlighting
+    pub fn index_options<T: Into<IndexOptions>>(self, index_options: T) -> Self {
+        Self {
+            index_options: Some(index_options.into()),
+            ..self
+        }
+    }
+
+    /// Fields the value is copied to
+    pub fn copy_to<F, T>(self, copy_to: F) -> Self
+        where
+            F: IntoIterator<Item=T>,
+            T: Into<String>
+    {
+        Self {
+            copy_to: Some(copy_to.into_iter().map(|x| x.into()).collect()),
+            ..self
+        }
+    }
+
+    /// Adds a multi-field, e.g. a `keyword` subfield for sorting
+    pub fn field<K: Into<String>, T: Into<FieldMapping>>(mut self, name: K, field: T) -> Self {
+        self.fields.insert(name.into(), field.into());
+        self
+    }
+}
+
+/// Information indexed by `index_options`, each option includes the previous ones
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IndexOptions {
+    /// Document numbers only
+    Docs,
+    /// Term frequencies
+    Freqs,
+    /// Term positions, required for phrase queries
+    Positions,
+    /// Character offsets, used by the unified highlighter
+    Offsets,
+}
+
+/// Exact-value field for filtering, sorting and aggregations
+/// https://opensearch.org/docs/latest/field-types/supported-field-types/keyword/
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct KeywordField {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    normalizer: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ignore_above: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    index: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    doc_values: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    norms: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    store: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    null_value: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    copy_to: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    fields: BTreeMap<String, FieldMapping>,
+}
+
+impl KeywordField {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Normalizer applied to the value before indexing
+    pub fn normalizer<T: Into<String>>(self, normalizer: T) -> Self {
+        Self {
+            normalizer: Some(normalizer.into()),
+            ..self
+        }
+    }
+
+    /// Values longer than this are not indexed
+    pub fn ignore_above<T: Into<u32>>(self, ignore_above: T) -> Self {
+        Self {
+            ignore_above: Some(ignore_above.into()),
+            ..self
+        }
+    }
+
+    /// Whether the field is searchable
+    pub fn index(self, index: bool) -> Self {
+        Self {
+            index: Some(index),
+            ..self
+        }
+    }
+
+    /// Whether the field is stored column-wise for sorting and aggregations
+    pub fn doc_values(self, doc_values: bool) -> Self {
+        Self {
+            doc_values: Some(doc_values),
+            ..self
+        }
+    }
+
+    /// Whether the field length is stored for scoring
+    pub fn norms(self, norms: bool) -> Self {
+        Self {
+            norms: Some(norms),
+            ..self
+        }
+    }
+
+    /// Whether the field is stored apart from `_source`
+    pub fn store(self, store: bool) -> Self {
+        Self {
+            store: Some(store),
+            ..self
+        }
+    }
+
+    /// Value indexed instead of `null`
+    pub fn null_value<T: Into<String>>(self, null_value: T) -> Self {
+        Self {
+            null_value: Some(null_value.into()),
+            ..self
+        }
+    }
+
+    /// Fields the value is copied to
+    pub fn copy_to<F, T>(self, copy_to: F) -> Self
+        where
+            F: IntoIterator<Item=T>,
+            T: Into<String>
+    {
+        Self {
+            copy_to: Some(copy_to.into_iter().map(|x| x.into()).collect()),
+            ..self
+        }
+    }
+
+    /// Adds a multi-field
+    pub fn field<K: Into<String>, T: Into<FieldMapping>>(mut self, name: K, field: T) -> Self {
+        self.fields.insert(name.into(), field.into());
+        self
+    }
+}
+
+/// Field for suggestions with the completion suggester
+/// https://opensearch.org/docs/latest/field-types/supported-field-types/completion/
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CompletionField {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    analyzer: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    search_analyzer: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    preserve_separators: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    preserve_position_increments: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_input_length: Option<u32>,
+}
+
+impl CompletionField {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Analyzer used at index time
+    pub fn analyzer<T: Into<String>>(self, analyzer: T) -> Self {
+        Self {
+            analyzer: Some(analyzer.into()),
+            ..self
+        }
+    }
+
+    /// Analyzer used at search time, defaults to `analyzer`
+    pub fn search_analyzer<T: Into<String>>(self, search_analyzer: T) -> Self {
+        Self {
+            search_analyzer: Some(search_analyzer.into()),
+            ..self
+        }
+    }
+
+    pub fn preserve_separators(self, preserve_separators: bool) -> Self {
+        Self {
+            preserve_separators: Some(preserve_separators),
+            ..self
+        }
+    }
+
+    pub fn preserve_position_increments(self, preserve_position_increments: bool) -> Self {
+        Self {
+            preserve_position_increments: Some(preserve_position_increments),
+            ..self
+        }
+    }
+
+    /// Maximum length of an input, longer ones are truncated
+    pub fn max_input_length<T: Into<u32>>(self, max_input_length: T) -> Self {
+        Self {
+            max_input_length: Some(max_input_length.into()),
+            ..self
+        }
+    }
+}
+
+/// Text field with shingle and prefix subfields for as-you-type completion
+/// https://opensearch.org/docs/latest/field-types/supported-field-types/search-as-you-type/
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SearchAsYouTypeField {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    analyzer: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    search_analyzer: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_shingle_size: Option<u8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    index: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    store: Option<bool>,
+}
+
+impl SearchAsYouTypeField {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Analyzer used at index time
+    pub fn analyzer<T: Into<String>>(self, analyzer: T) -> Self {
+        Self {
+            analyzer: Some(analyzer.into()),
+            ..self
+        }
+    }
+
+    /// Analyzer used at search time, defaults to `analyzer`
+    pub fn search_analyzer<T: Into<String>>(self, search_analyzer: T) -> Self {
+        Self {
+            search_analyzer: Some(search_analyzer.into()),
+            ..self
+        }
+    }
+
+    /// Largest shingle subfield, from 2 to 4
+    pub fn max_shingle_size<T: Into<u8>>(self, max_shingle_size: T) -> Self {
+        Self {
+            max_shingle_size: Some(max_shingle_size.into()),
+            ..self
+        }
+    }
+
+    /// Whether the field is searchable
+    pub fn index(self, index: bool) -> Self {
+        Self {
+            index: Some(index),
+            ..self
+        }
+    }
+
+    /// Whether the field is stored apart from `_source`
+    pub fn store(self, store: bool) -> Self {
+        Self {
+            store: Some(store),
+            ..self
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Field entry for `docvalue_fields` and `fields` of the search body
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct FieldAndFormat {
+    field: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    format: Option<String>,
+}
+
+impl FieldAndFormat {
+
+    pub fn new<T: Into<String>>(field: T) -> Self {
+        Self {
+            field: field.into(),
+            format: None
+        }
+    }
+
+    pub fn format<T: Into<String>>(self, format: T) -> Self {
+        Self {
+            format: Some(format.into()),
+            ..self
+        }
+    }
+}
+
+impl From<&str> for FieldAndFormat {
+    fn from(val: &str) -> Self {
+        Self::new(val)
+    }
+}
+
+impl From<String> for FieldAndFormat {
+    fn from(val: String) -> Self {
+        Self::new(val)
+    }
+}
+
+
+impl<'de> Deserialize<'de> for FieldAndFormat {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Inner {
+            field: String,
+            format: Option<String>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Layout {
+            Field(String),
+            Object(Inner),
+        }
+
+        Ok(match Layout::deserialize(deserializer)? {
+            Layout::Field(field) => Self::new(field),
+            Layout::Object(Inner { field, format }) => Self { field, format },
+        })
+    }
+}
\ No newline at end of file
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Deserializer};
+use serde::de::{DeserializeOwned, Error};
+use serde_json::{Map, Value};
+
+/// Value of queries with the `{"<field>": {...}}` layout
+pub(crate) trait FieldValue: DeserializeOwned {
+    /// Builds the value from the short form, e.g. `{"term": {"brand": "FIAT"}}`
+    fn from_short(_value: Value) -> Option<Self> {
+        None
+    }
+}
+
+/// Reads the single `{"<field>": <value>}` entry
+pub(crate) fn deserialize_field_entry<'de, D, V>(deserializer: D) -> Result<(String, V), D::Error>
+    where
+        D: Deserializer<'de>,
+        V: FieldValue
+{
+    let map = Map::<String, Value>::deserialize(deserializer)?;
+    if map.len() != 1 {
+        return Err(D::Error::custom(format!("expected a single field, found {}", map.len())));
+    }
+
+    let (field, value) = map.into_iter().next().expect("map has one entry");
+    let value = match value {
+        Value::Object(_) => V::deserialize(value).map_err(D::Error::custom)?,
+        short => V::from_short(short)
+            .ok_or_else(|| D::Error::custom(format!("invalid value of field `{field}`")))?,
+    };
+
+    Ok((field, value))
+}
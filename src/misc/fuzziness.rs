@@ -1,4 +1,6 @@
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::Error;
+use serde_json::Value;
 
 #[derive(Debug, Clone)]
 pub enum Fuzziness {
@@ -22,4 +24,22 @@ impl Serialize for Fuzziness {
             Fuzziness::Uint(u) => serializer.serialize_u64(*u),
         }
     }
-}
\ No newline at end of file
+}
+
+impl<'de> Deserialize<'de> for Fuzziness {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        match Value::deserialize(deserializer)? {
+            Value::Number(n) => n.as_u64()
+                .map(Fuzziness::Uint)
+                .ok_or_else(|| D::Error::custom(format!("invalid fuzziness `{n}`"))),
+            Value::String(s) if s.eq_ignore_ascii_case("auto") => Ok(Fuzziness::Auto),
+            Value::String(s) => s.parse()
+                .map(Fuzziness::Uint)
+                .map_err(|_| D::Error::custom(format!("invalid fuzziness `{s}`"))),
+            other => Err(D::Error::custom(format!("invalid fuzziness `{other}`"))),
+        }
+    }
+}
@@ -0,0 +1,277 @@
+use std::fmt;
+use std::str::FromStr;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::Error;
+use serde_json::Value;
+use crate::misc::validate::{Validate, ValidationError};
+
+/// https://opensearch.org/docs/latest/query-dsl/minimum-should-match/
+///
+/// Examples
+/// ```
+/// use os_query_builder_rs::misc::minimum_should_match::{CombinationValue, MinimumShouldMatch};
+/// use os_query_builder_rs::misc::validate::Validate;
+///
+/// let percentage = MinimumShouldMatch::percentage(90).unwrap();
+/// assert_eq!(percentage.to_string(), "90%");
+/// assert!(MinimumShouldMatch::percentage(150).is_err());
+///
+/// let combination = MinimumShouldMatch::combination(2, -1)
+///             .and_combination(5, CombinationValue::percentage(75).unwrap());
+/// assert_eq!(combination.to_string(), "2<-1 5<75%");
+///
+/// let parsed: MinimumShouldMatch = "3<90%".parse().unwrap();
+/// assert_eq!(parsed, MinimumShouldMatch::combination(3, CombinationValue::percentage(90).unwrap()));
+///
+/// // strings passed to builders are parsed, invalid ones are reported by `Validate`
+/// assert_eq!(MinimumShouldMatch::from("90%"), percentage);
+/// assert!(MinimumShouldMatch::from("90 %").validate().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MinimumShouldMatch {
+    /// Fixed number of clauses, a negative value is the number of clauses that may be missing
+    Count(i32),
+    /// Percentage of clauses rounded down, a negative value is the percentage that may be missing
+    Percentage(i32),
+    /// Conditional values applied when there are more optional clauses than the threshold
+    Combinations(Vec<Combination>),
+    /// String that could not be parsed, serialized as it is and reported by [`Validate`]
+    Invalid(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Combination {
+    clauses: u32,
+    value: CombinationValue,
+}
+
+impl Combination {
+    pub fn clauses(&self) -> u32 {
+        self.clauses
+    }
+
+    pub fn value(&self) -> CombinationValue {
+        self.value
+    }
+}
+
+/// Value of a [`Combination`], combinations can not be nested
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CombinationValue {
+    Count(i32),
+    Percentage(i32),
+}
+
+impl CombinationValue {
+    pub fn count(count: i32) -> Self {
+        Self::Count(count)
+    }
+
+    /// Fails when `percentage` is outside of `-100..=100`
+    pub fn percentage(percentage: i32) -> Result<Self, ParseMinimumShouldMatchError> {
+        check_percentage(percentage).map(Self::Percentage)
+    }
+}
+
+impl MinimumShouldMatch {
+    pub fn count(count: i32) -> Self {
+        Self::Count(count)
+    }
+
+    /// Fails when `percentage` is outside of `-100..=100`
+    pub fn percentage(percentage: i32) -> Result<Self, ParseMinimumShouldMatchError> {
+        check_percentage(percentage).map(Self::Percentage)
+    }
+
+    /// Applies `value` when there are more than `clauses` optional clauses, all clauses are required otherwise
+    pub fn combination<T: Into<CombinationValue>>(clauses: u32, value: T) -> Self {
+        Self::Combinations(vec![Combination { clauses, value: value.into() }])
+    }
+
+    /// Adds another condition. A count or a percentage is kept as the `0<value` condition,
+    /// which applies it whenever there are optional clauses. An invalid value is kept as it is.
+    pub fn and_combination<T: Into<CombinationValue>>(self, clauses: u32, value: T) -> Self {
+        let mut combinations = match self {
+            Self::Invalid(_) => return self,
+            Self::Combinations(combinations) => combinations,
+            Self::Count(count) => vec![Combination { clauses: 0, value: CombinationValue::Count(count) }],
+            Self::Percentage(percentage) => vec![Combination { clauses: 0, value: CombinationValue::Percentage(percentage) }],
+        };
+        combinations.push(Combination { clauses, value: value.into() });
+        Self::Combinations(combinations)
+    }
+}
+
+fn check_percentage(percentage: i32) -> Result<i32, ParseMinimumShouldMatchError> {
+    if (-100..=100).contains(&percentage) {
+        Ok(percentage)
+    } else {
+        Err(ParseMinimumShouldMatchError(format!("{percentage}%")))
+    }
+}
+
+impl fmt::Display for MinimumShouldMatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Count(count) => CombinationValue::Count(*count).fmt(f),
+            Self::Percentage(percentage) => CombinationValue::Percentage(*percentage).fmt(f),
+            Self::Combinations(combinations) => {
+                for (index, combination) in combinations.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(" ")?;
+                    }
+                    write!(f, "{}<{}", combination.clauses, combination.value)?;
+                }
+                Ok(())
+            }
+            Self::Invalid(value) => f.write_str(value),
+        }
+    }
+}
+
+impl fmt::Display for CombinationValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Count(count) => write!(f, "{count}"),
+            Self::Percentage(percentage) => write!(f, "{percentage}%"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseMinimumShouldMatchError(String);
+
+impl fmt::Display for ParseMinimumShouldMatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid minimum_should_match `{}`", self.0)
+    }
+}
+
+impl std::error::Error for ParseMinimumShouldMatchError {}
+
+impl FromStr for MinimumShouldMatch {
+    type Err = ParseMinimumShouldMatchError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseMinimumShouldMatchError(s.to_string());
+        let s = s.trim();
+
+        if !s.contains('<') {
+            return match parse_single(s).ok_or_else(error)? {
+                CombinationValue::Count(count) => Ok(Self::Count(count)),
+                CombinationValue::Percentage(percentage) => Ok(Self::Percentage(percentage)),
+            };
+        }
+
+        let mut combinations = Vec::new();
+        for condition in s.split_whitespace() {
+            let (clauses, value) = condition.split_once('<').ok_or_else(error)?;
+            combinations.push(Combination {
+                clauses: clauses.parse().map_err(|_| error())?,
+                value: parse_single(value).ok_or_else(error)?,
+            });
+        }
+        Ok(Self::Combinations(combinations))
+    }
+}
+
+fn parse_single(s: &str) -> Option<CombinationValue> {
+    match s.strip_suffix('%') {
+        Some(percentage) => percentage.parse().ok()
+            .and_then(|x| check_percentage(x).ok())
+            .map(CombinationValue::Percentage),
+        None => s.parse().ok().map(CombinationValue::Count),
+    }
+}
+
+/// Invalid strings are kept as [`MinimumShouldMatch::Invalid`], use [`str::parse`] to get the error right away
+impl From<&str> for MinimumShouldMatch {
+    fn from(val: &str) -> Self {
+        val.parse().unwrap_or_else(|_| Self::Invalid(val.to_string()))
+    }
+}
+
+impl From<String> for MinimumShouldMatch {
+    fn from(val: String) -> Self {
+        val.parse().unwrap_or(Self::Invalid(val))
+    }
+}
+
+impl From<i32> for CombinationValue {
+    fn from(val: i32) -> Self {
+        Self::Count(val)
+    }
+}
+
+impl From<i32> for MinimumShouldMatch {
+    fn from(val: i32) -> Self {
+        Self::Count(val)
+    }
+}
+
+impl From<usize> for MinimumShouldMatch {
+    fn from(val: usize) -> Self {
+        Self::Count(i32::try_from(val).unwrap_or(i32::MAX))
+    }
+}
+
+impl From<u32> for MinimumShouldMatch {
+    fn from(val: u32) -> Self {
+        Self::Count(i32::try_from(val).unwrap_or(i32::MAX))
+    }
+}
+
+macro_rules! from_types {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for MinimumShouldMatch {
+                fn from(val: $ty) -> Self {
+                    Self::Count(val.into())
+                }
+            }
+        )*
+    }
+}
+
+from_types! {
+    i8,
+    i16,
+    u8,
+    u16
+}
+
+impl Validate for MinimumShouldMatch {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if let Self::Invalid(value) = self {
+            errors.push(ValidationError::new(path, ParseMinimumShouldMatchError(value.clone()).to_string()));
+        }
+    }
+}
+
+impl Serialize for MinimumShouldMatch {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+    {
+        match self {
+            MinimumShouldMatch::Count(count) => serializer.serialize_i32(*count),
+            other => serializer.collect_str(other),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for MinimumShouldMatch {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        match Value::deserialize(deserializer)? {
+            Value::Number(n) => n.as_i64()
+                .and_then(|x| i32::try_from(x).ok())
+                .map(MinimumShouldMatch::Count)
+                .ok_or_else(|| D::Error::custom(format!("invalid minimum_should_match `{n}`"))),
+            Value::String(s) => s.parse().map_err(D::Error::custom),
+            other => Err(D::Error::custom(format!("invalid minimum_should_match `{other}`"))),
+        }
+    }
+}
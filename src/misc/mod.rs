@@ -1,10 +1,23 @@
 pub mod operator;
 pub mod r#type;
 pub mod fuzziness;
+pub mod minimum_should_match;
 pub mod zero_terms_query;
 pub mod query_field;
 pub mod rewrite;
 pub mod regexp_flag;
+pub mod simple_query_string_flag;
 pub mod relation;
 pub mod script;
-
+pub(crate) mod field_entry;
+pub mod track_total_hits;
+pub mod field_and_format;
+pub mod script_field;
+pub mod source_filter;
+pub mod top_hits;
+pub mod sort;
+pub mod runtime_field;
+pub mod validate;
+pub mod visit;
+pub mod normalize;
+mod query_ops;
\ No newline at end of file
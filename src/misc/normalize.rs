@@ -0,0 +1,253 @@
+use std::collections::HashMap;
+use crate::compound_query::bool::Bool;
+use crate::misc::minimum_should_match::MinimumShouldMatch;
+use crate::misc::query_field::QueryField;
+use crate::misc::visit::{VisitorMut, Walk};
+use crate::term::{
+    term_type::TermType,
+    terms::Terms
+};
+
+/// Simplifies a query tree without changing the set of matched documents:
+/// - `bool` clauses nested in `must`, `filter`, `must_not` and `should` are lifted into the parent when it keeps the meaning
+/// - `bool` with a single `must` or `should` clause (or a single `filter` clause in filter context) is replaced by the clause
+/// - empty `bool` in `must` and `filter` is dropped, it matches all documents
+/// - `minimum_should_match: 0` is set when lifting or dropping leaves `should` without `must` and `filter`,
+///   so the `should` clauses stay optional
+/// - duplicated clauses are removed
+/// - plain `term` clauses on the same field in `must_not` and in filter context `should` are merged into `terms`
+/// - with [`Normalizer::must_to_filter`] non-scoring clauses are moved from `must` to `filter`
+///
+/// Scores of scoring clauses may change when duplicates are removed.
+///
+/// Examples
+/// ```
+/// use serde_json::json;
+/// use os_query_builder_rs::compound_query::bool::Bool;
+/// use os_query_builder_rs::full_text::r#match::Match;
+/// use os_query_builder_rs::misc::normalize::Normalizer;
+/// use os_query_builder_rs::term::term::Term;
+///
+/// let query = Bool::new().must(vec![
+///     Bool::new().must(vec![Match::new().field("title").value("wind")])
+/// ]);
+///
+/// let normalized = Normalizer::new().normalize(query);
+/// assert_eq!(json!(normalized), json!({ "match": { "title": { "query": "wind" } } }));
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Normalizer {
+    must_to_filter: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Context {
+    Scoring,
+    Filter,
+}
+
+impl Normalizer {
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Moves term-level queries, `constant_score` and non-scoring `bool` clauses from `must` to `filter`
+    pub fn must_to_filter(self, must_to_filter: bool) -> Self {
+        Self {
+            must_to_filter
+        }
+    }
+
+    pub fn normalize<T: Into<QueryField>>(&self, query: T) -> QueryField {
+        self.normalize_in(query.into(), Context::Scoring)
+    }
+
+    fn normalize_in(&self, query: QueryField, context: Context) -> QueryField {
+        match query {
+            QueryField::Bool(query) => self.normalize_bool(query, context),
+            mut query => {
+                let context = match query {
+                    QueryField::ConstantScore(_) => Context::Filter,
+                    _ => Context::Scoring,
+                };
+                query.walk_mut(&mut Children { normalizer: self, context });
+                query
+            }
+        }
+    }
+
+    fn normalize_bool(&self, query: Bool, context: Context) -> QueryField {
+        let normalize = |clauses: Option<Vec<QueryField>>, context| -> Vec<QueryField> {
+            clauses.into_iter()
+                .flatten()
+                .map(|x| self.normalize_in(x, context))
+                .collect()
+        };
+
+        let mut minimum_should_match = query.minimum_should_match;
+        let has_required = [&query.must, &query.filter].into_iter().flatten().any(|x| !x.is_empty());
+        let boost = query.boost;
+        let mut must = Vec::new();
+        let mut filter = Vec::new();
+        let mut must_not = Vec::new();
+        let mut should = Vec::new();
+
+        for clause in normalize(query.must, context) {
+            match clause {
+                QueryField::Bool(inner) if inner.should.is_none() && inner.minimum_should_match.is_none() && inner.boost.is_none() => {
+                    must.extend(inner.must.into_iter().flatten());
+                    filter.extend(inner.filter.into_iter().flatten());
+                    must_not.extend(inner.must_not.into_iter().flatten());
+                }
+                clause if self.must_to_filter && (context == Context::Filter || !is_scoring(&clause)) => filter.push(clause),
+                clause => must.push(clause),
+            }
+        }
+
+        for clause in normalize(query.filter, Context::Filter) {
+            match clause {
+                QueryField::Bool(inner) if inner.should.is_none() && inner.minimum_should_match.is_none() && inner.boost.is_none() => {
+                    filter.extend(inner.must.into_iter().flatten());
+                    filter.extend(inner.filter.into_iter().flatten());
+                    must_not.extend(inner.must_not.into_iter().flatten());
+                }
+                clause => filter.push(clause),
+            }
+        }
+
+        for clause in normalize(query.must_not, Context::Filter) {
+            match clause {
+                // not (a or b) is (not a) and (not b)
+                QueryField::Bool(inner) if is_only_should(&inner) => must_not.extend(inner.should.into_iter().flatten()),
+                clause => must_not.push(clause),
+            }
+        }
+
+        for clause in normalize(query.should, context) {
+            match clause {
+                QueryField::Bool(inner) if minimum_should_match.is_none() && is_only_should(&inner) => {
+                    should.extend(inner.should.into_iter().flatten())
+                }
+                clause => should.push(clause),
+            }
+        }
+
+        // without `must` and `filter` at least one `should` clause is required,
+        // keep them optional when lifting or dropping took all required clauses away
+        if has_required && must.is_empty() && filter.is_empty() && !should.is_empty() && minimum_should_match.is_none() {
+            minimum_should_match = Some(MinimumShouldMatch::Count(0));
+        }
+
+        dedup(&mut must);
+        dedup(&mut filter);
+        dedup(&mut must_not);
+        if minimum_should_match.is_none() {
+            dedup(&mut should);
+        }
+
+        must_not = merge_terms(must_not);
+        if context == Context::Filter && minimum_should_match.is_none() {
+            should = merge_terms(should);
+        }
+
+        let clauses = must.len() + filter.len() + must_not.len() + should.len();
+        if clauses == 1 && minimum_should_match.is_none() && boost.is_none() {
+            if must.len() == 1 || should.len() == 1 {
+                return must.pop().or(should.pop()).expect("one clause");
+            }
+            if filter.len() == 1 && context == Context::Filter {
+                return filter.pop().expect("one clause");
+            }
+        }
+
+        let non_empty = |clauses: Vec<QueryField>| Some(clauses).filter(|x| !x.is_empty());
+        QueryField::Bool(Bool {
+            must: non_empty(must),
+            must_not: non_empty(must_not),
+            filter: non_empty(filter),
+            should: non_empty(should),
+            minimum_should_match,
+            boost
+        })
+    }
+}
+
+/// Normalizes direct children of non-bool queries
+struct Children<'a> {
+    normalizer: &'a Normalizer,
+    context: Context,
+}
+
+impl VisitorMut for Children<'_> {
+    fn visit_query_mut(&mut self, query: &mut QueryField) {
+        let child = std::mem::replace(query, QueryField::Bool(Bool::new()));
+        *query = self.normalizer.normalize_in(child, self.context);
+    }
+}
+
+/// Non-empty `should` without other clauses, an empty `bool` matches all documents
+fn is_only_should(query: &Bool) -> bool {
+    query.should.as_ref().is_some_and(|x| !x.is_empty())
+        && query.must.is_none() && query.filter.is_none() && query.must_not.is_none() && query.minimum_should_match.is_none()
+        && query.boost.is_none()
+}
+
+fn is_scoring(query: &QueryField) -> bool {
+    match query {
+        QueryField::Term(_)
+        | QueryField::Terms(_)
+        | QueryField::TermsSet(_)
+        | QueryField::Range(_)
+        | QueryField::Exists(_)
+        | QueryField::IDs(_)
+        | QueryField::Prefix(_)
+        | QueryField::Wildcard(_)
+        | QueryField::Regexp(_)
+        | QueryField::ConstantScore(_) => false,
+        QueryField::Bool(query) => query.must.is_some() || query.should.is_some(),
+        _ => true,
+    }
+}
+
+fn dedup(clauses: &mut Vec<QueryField>) {
+    let mut seen = Vec::with_capacity(clauses.len());
+    clauses.retain(|clause| {
+        let json = serde_json::to_value(clause).ok();
+        let duplicate = json.is_some() && seen.contains(&json);
+        seen.push(json);
+        !duplicate
+    });
+}
+
+/// Merges plain `term` clauses on the same field, the first clause keeps its position
+fn merge_terms(clauses: Vec<QueryField>) -> Vec<QueryField> {
+    let mut values: HashMap<String, Vec<TermType>> = HashMap::new();
+    for clause in &clauses {
+        if let QueryField::Term(term) = clause {
+            if let Some((field, value)) = term.as_plain() {
+                values.entry(field.to_string()).or_default().push(value.clone());
+            }
+        }
+    }
+
+    let mut merged = Vec::with_capacity(clauses.len());
+    for clause in clauses {
+        let field = match &clause {
+            QueryField::Term(term) => term.as_plain().map(|(field, _)| field.to_string()),
+            _ => None,
+        };
+
+        match field {
+            Some(field) if values.get(&field).is_some_and(|x| x.len() > 1) => {
+                let values = values.remove(&field).expect("values of the field");
+                merged.push(Terms::new_with_terms_query(field, values).into());
+            }
+            // the field is already merged into `terms`
+            Some(field) if !values.contains_key(&field) => {}
+            _ => merged.push(clause),
+        }
+    }
+
+    merged
+}
@@ -1,4 +1,6 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use crate::misc::validate::{child, Validate, ValidationError};
+use crate::misc::visit::{Visitor, VisitorMut, Walk};
 use crate::compound_query::{
     bool::Bool,
     boosting::Boosting,
@@ -28,7 +30,7 @@ use crate::term::{
     wildcard::Wildcard
 };
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum QueryField {
     /// https://opensearch.org/docs/latest/query-dsl/full-text/match/
@@ -79,6 +81,123 @@ pub enum QueryField {
     Range(Range),
 }
 
+impl QueryField {
+
+    /// Key of the query in the DSL, e.g. `"match_phrase"`
+    pub fn name(&self) -> &'static str {
+        match self {
+            QueryField::Match(_) => "match",
+            QueryField::MultiMatch(_) => "multi_match",
+            QueryField::QueryString(_) => "query_string",
+            QueryField::SimpleQueryString(_) => "simple_query_string",
+            QueryField::MatchPhrase(_) => "match_phrase",
+            QueryField::MatchPhrasePrefix(_) => "match_phrase_prefix",
+            QueryField::MatchBoolPrefix(_) => "match_bool_prefix",
+            QueryField::Intervals(_) => "intervals",
+            QueryField::Terms(_) => "terms",
+            QueryField::Term(_) => "term",
+            QueryField::TermsSet(_) => "terms_set",
+            QueryField::Bool(_) => "bool",
+            QueryField::Boosting(_) => "boosting",
+            QueryField::ConstantScore(_) => "constant_score",
+            QueryField::DisMax(_) => "dis_max",
+            QueryField::Wildcard(_) => "wildcard",
+            QueryField::IDs(_) => "ids",
+            QueryField::Fuzzy(_) => "fuzzy",
+            QueryField::Prefix(_) => "prefix",
+            QueryField::Regexp(_) => "regexp",
+            QueryField::Exists(_) => "exists",
+            QueryField::Range(_) => "range",
+        }
+    }
+}
+
+impl Validate for QueryField {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        let path = child(path, self.name());
+        match self {
+            QueryField::Match(query) => query.validate_at(&path, errors),
+            QueryField::MultiMatch(query) => query.validate_at(&path, errors),
+            QueryField::QueryString(query) => query.validate_at(&path, errors),
+            QueryField::SimpleQueryString(query) => query.validate_at(&path, errors),
+            QueryField::MatchPhrase(query) => query.validate_at(&path, errors),
+            QueryField::MatchPhrasePrefix(query) => query.validate_at(&path, errors),
+            QueryField::MatchBoolPrefix(query) => query.validate_at(&path, errors),
+            QueryField::Intervals(query) => query.validate_at(&path, errors),
+            QueryField::Terms(query) => query.validate_at(&path, errors),
+            QueryField::Term(query) => query.validate_at(&path, errors),
+            QueryField::TermsSet(query) => query.validate_at(&path, errors),
+            QueryField::Bool(query) => query.validate_at(&path, errors),
+            QueryField::Boosting(query) => query.validate_at(&path, errors),
+            QueryField::ConstantScore(query) => query.validate_at(&path, errors),
+            QueryField::DisMax(query) => query.validate_at(&path, errors),
+            QueryField::Wildcard(query) => query.validate_at(&path, errors),
+            QueryField::IDs(query) => query.validate_at(&path, errors),
+            QueryField::Fuzzy(query) => query.validate_at(&path, errors),
+            QueryField::Prefix(query) => query.validate_at(&path, errors),
+            QueryField::Regexp(query) => query.validate_at(&path, errors),
+            QueryField::Exists(query) => query.validate_at(&path, errors),
+            QueryField::Range(query) => query.validate_at(&path, errors),
+        }
+    }
+}
+
+impl Walk for QueryField {
+    fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        match self {
+            QueryField::Match(query) => query.walk(visitor),
+            QueryField::MultiMatch(query) => query.walk(visitor),
+            QueryField::QueryString(query) => query.walk(visitor),
+            QueryField::SimpleQueryString(query) => query.walk(visitor),
+            QueryField::MatchPhrase(query) => query.walk(visitor),
+            QueryField::MatchPhrasePrefix(query) => query.walk(visitor),
+            QueryField::MatchBoolPrefix(query) => query.walk(visitor),
+            QueryField::Intervals(query) => query.walk(visitor),
+            QueryField::Terms(query) => query.walk(visitor),
+            QueryField::Term(query) => query.walk(visitor),
+            QueryField::TermsSet(query) => query.walk(visitor),
+            QueryField::Bool(query) => query.walk(visitor),
+            QueryField::Boosting(query) => query.walk(visitor),
+            QueryField::ConstantScore(query) => query.walk(visitor),
+            QueryField::DisMax(query) => query.walk(visitor),
+            QueryField::Wildcard(query) => query.walk(visitor),
+            QueryField::IDs(query) => query.walk(visitor),
+            QueryField::Fuzzy(query) => query.walk(visitor),
+            QueryField::Prefix(query) => query.walk(visitor),
+            QueryField::Regexp(query) => query.walk(visitor),
+            QueryField::Exists(query) => query.walk(visitor),
+            QueryField::Range(query) => query.walk(visitor),
+        }
+    }
+
+    fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        match self {
+            QueryField::Match(query) => query.walk_mut(visitor),
+            QueryField::MultiMatch(query) => query.walk_mut(visitor),
+            QueryField::QueryString(query) => query.walk_mut(visitor),
+            QueryField::SimpleQueryString(query) => query.walk_mut(visitor),
+            QueryField::MatchPhrase(query) => query.walk_mut(visitor),
+            QueryField::MatchPhrasePrefix(query) => query.walk_mut(visitor),
+            QueryField::MatchBoolPrefix(query) => query.walk_mut(visitor),
+            QueryField::Intervals(query) => query.walk_mut(visitor),
+            QueryField::Terms(query) => query.walk_mut(visitor),
+            QueryField::Term(query) => query.walk_mut(visitor),
+            QueryField::TermsSet(query) => query.walk_mut(visitor),
+            QueryField::Bool(query) => query.walk_mut(visitor),
+            QueryField::Boosting(query) => query.walk_mut(visitor),
+            QueryField::ConstantScore(query) => query.walk_mut(visitor),
+            QueryField::DisMax(query) => query.walk_mut(visitor),
+            QueryField::Wildcard(query) => query.walk_mut(visitor),
+            QueryField::IDs(query) => query.walk_mut(visitor),
+            QueryField::Fuzzy(query) => query.walk_mut(visitor),
+            QueryField::Prefix(query) => query.walk_mut(visitor),
+            QueryField::Regexp(query) => query.walk_mut(visitor),
+            QueryField::Exists(query) => query.walk_mut(visitor),
+            QueryField::Range(query) => query.walk_mut(visitor),
+        }
+    }
+}
+
 macro_rules! from_types {
     ($($ty:ident),*) => {
         $(
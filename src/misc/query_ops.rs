@@ -0,0 +1,209 @@
+use std::ops::{BitAnd, BitOr, Not};
+use crate::compound_query::{
+    bool::Bool,
+    boosting::Boosting,
+    constant_score::ConstantScore,
+    disjunction_max::DisMax
+};
+use crate::full_text::{
+    match_boolean_prefix::MatchBoolPrefix,
+    match_phrase::MatchPhrase,
+    match_phrase_prefix::MatchPhrasePrefix,
+    multi_match::MultiMatch,
+    query_string::QueryString,
+    r#match::Match,
+    simple_query_string::SimpleQueryString,
+    intervals::interval::Intervals
+};
+use crate::misc::query_field::QueryField;
+use crate::term::{
+    exists::Exists,
+    fuzzy::Fuzzy,
+    ids::IDs,
+    prefix::Prefix,
+    range::Range,
+    regexp::Regexp,
+    term::Term,
+    terms::Terms,
+    terms_set::TermsSet,
+    wildcard::Wildcard
+};
+
+impl QueryField {
+
+    /// Wraps the query into `bool.filter`, so it is kept in filter context when combined with `&`.
+    ///
+    /// `a & b` puts both queries into `must`, `a | b` into `should` and `!a` into `must_not`.
+    /// Nested `&` and `|` are flattened into one `bool`, `filtered` puts
+    /// a query into `filter` instead of `must`.
+    ///
+    /// Examples
+    /// ```
+    /// use serde_json::json;
+    /// use os_query_builder_rs::full_text::r#match::Match;
+    /// use os_query_builder_rs::term::term::Term;
+    ///
+    /// let query = Match::new().field("title").value("wind")
+    ///     & Term::new("brand", "FIAT").filtered()
+    ///     & !Term::new("status", "deleted");
+    ///
+    /// assert_eq!(json!(query), json!({
+    ///     "bool": {
+    ///         "must": [{ "match": { "title": { "query": "wind" } } }],
+    ///         "filter": [{ "term": { "brand": { "value": "FIAT" } } }],
+    ///         "must_not": [{ "term": { "status": { "value": "deleted" } } }]
+    ///     }
+    /// }));
+    /// ```
+    pub fn filtered(self) -> QueryField {
+        QueryField::Bool(Bool {
+            filter: Some(vec![self]),
+            ..Bool::new()
+        })
+    }
+}
+
+/// `bool` without `should`, its clauses can be merged into another conjunction
+fn is_conjunction(query: &Bool) -> bool {
+    query.should.is_none() && query.minimum_should_match.is_none() && query.boost.is_none()
+}
+
+/// `bool` with only `should`, its clauses can be merged into another disjunction
+fn is_disjunction(query: &Bool) -> bool {
+    query.should.as_ref().is_some_and(|x| !x.is_empty())
+        && query.must.is_none()
+        && query.filter.is_none()
+        && query.must_not.is_none()
+        && query.minimum_should_match.is_none()
+        && query.boost.is_none()
+}
+
+fn push(clauses: &mut Option<Vec<QueryField>>, values: Option<Vec<QueryField>>) {
+    if let Some(values) = values {
+        clauses.get_or_insert_with(Vec::new).extend(values);
+    }
+}
+
+impl<T: Into<QueryField>> BitAnd<T> for QueryField {
+    type Output = QueryField;
+
+    fn bitand(self, rhs: T) -> Self::Output {
+        let mut conjunction = match self {
+            QueryField::Bool(query) if is_conjunction(&query) => query,
+            query => Bool {
+                must: Some(vec![query]),
+                ..Bool::new()
+            },
+        };
+
+        match rhs.into() {
+            QueryField::Bool(rhs) if is_conjunction(&rhs) => {
+                push(&mut conjunction.must, rhs.must);
+                push(&mut conjunction.filter, rhs.filter);
+                push(&mut conjunction.must_not, rhs.must_not);
+            }
+            rhs => push(&mut conjunction.must, Some(vec![rhs])),
+        }
+
+        QueryField::Bool(conjunction)
+    }
+}
+
+impl<T: Into<QueryField>> BitOr<T> for QueryField {
+    type Output = QueryField;
+
+    fn bitor(self, rhs: T) -> Self::Output {
+        let mut should = match self {
+            QueryField::Bool(query) if is_disjunction(&query) => query.should.unwrap_or_default(),
+            query => vec![query],
+        };
+
+        match rhs.into() {
+            QueryField::Bool(rhs) if is_disjunction(&rhs) => should.extend(rhs.should.unwrap_or_default()),
+            rhs => should.push(rhs),
+        }
+
+        QueryField::Bool(Bool {
+            should: Some(should),
+            ..Bool::new()
+        })
+    }
+}
+
+impl Not for QueryField {
+    type Output = QueryField;
+
+    fn not(self) -> Self::Output {
+        match self {
+            // !!a is a
+            QueryField::Bool(Bool { must: None, filter: None, should: None, minimum_should_match: None, boost: None, must_not: Some(mut must_not) })
+                if must_not.len() == 1 => must_not.pop().expect("one clause"),
+            query => QueryField::Bool(Bool {
+                must_not: Some(vec![query]),
+                ..Bool::new()
+            }),
+        }
+    }
+}
+
+macro_rules! ops_types {
+    ($($ty:ident),*) => {
+        $(
+            impl $ty {
+                /// See [`QueryField::filtered`]
+                pub fn filtered(self) -> QueryField {
+                    QueryField::from(self).filtered()
+                }
+            }
+
+            impl<T: Into<QueryField>> BitAnd<T> for $ty {
+                type Output = QueryField;
+
+                fn bitand(self, rhs: T) -> Self::Output {
+                    QueryField::from(self) & rhs
+                }
+            }
+
+            impl<T: Into<QueryField>> BitOr<T> for $ty {
+                type Output = QueryField;
+
+                fn bitor(self, rhs: T) -> Self::Output {
+                    QueryField::from(self) | rhs
+                }
+            }
+
+            impl Not for $ty {
+                type Output = QueryField;
+
+                fn not(self) -> Self::Output {
+                    !QueryField::from(self)
+                }
+            }
+        )*
+    }
+}
+
+ops_types! {
+    Match,
+    MultiMatch,
+    QueryString,
+    SimpleQueryString,
+    MatchPhrase,
+    MatchPhrasePrefix,
+    MatchBoolPrefix,
+    Terms,
+    Term,
+    TermsSet,
+    Bool,
+    Boosting,
+    ConstantScore,
+    DisMax,
+    Wildcard,
+    IDs,
+    Fuzzy,
+    Prefix,
+    Regexp,
+    Exists,
+    Range,
+    Intervals
+}
@@ -1,4 +1,5 @@
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::Error;
 
 /// all values from docs elasticsearch, exclude constant_score_blended
 /// https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-multi-term-rewrite.html
@@ -27,4 +28,26 @@ impl Serialize for Rewrite {
             Rewrite::ConstantScoreBoolean => serializer.serialize_str("constant_score_boolean")
         }
     }
+}
+
+impl<'de> Deserialize<'de> for Rewrite {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        let top_terms = |prefix: &str| value
+            .strip_prefix(prefix)
+            .and_then(|x| x.parse::<u64>().ok());
+
+        match value.as_str() {
+            "constant_score" => Ok(Rewrite::ConstantScore),
+            "scoring_boolean" => Ok(Rewrite::ScoringBoolean),
+            "constant_score_boolean" => Ok(Rewrite::ConstantScoreBoolean),
+            _ => top_terms("top_terms_boost_").map(Rewrite::TopTermsBoost)
+                .or_else(|| top_terms("top_terms_blended_freqs_").map(Rewrite::TopTermsBlendedFreqs))
+                .or_else(|| top_terms("top_terms_").map(Rewrite::TopTerms))
+                .ok_or_else(|| D::Error::custom(format!("unknown rewrite `{value}`")))
+        }
+    }
 }
\ No newline at end of file
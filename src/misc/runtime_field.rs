@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+use crate::misc::script::Script;
+use crate::misc::validate::{child, Validate, ValidationError};
+
+/// https://opensearch.org/docs/latest/field-types/supported-field-types/derived/
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuntimeFieldType {
+    Keyword,
+    Long,
+    Double,
+    Date,
+    Boolean,
+    Ip,
+    GeoPoint,
+}
+
+/// Field computed by a script at search time, see [`Query::runtime_mappings`](crate::model::Query::runtime_mappings)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuntimeField {
+    #[serde(skip)]
+    name: String,
+    #[serde(rename = "type")]
+    field_type: RuntimeFieldType,
+    script: Script,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    format: Option<String>,
+}
+
+impl RuntimeField {
+
+    pub fn new<T, F, S>(name: T, field_type: F, script: S) -> Self
+        where T: Into<String>,
+              F: Into<RuntimeFieldType>,
+              S: Into<Script>
+    {
+        Self {
+            name: name.into(),
+            field_type: field_type.into(),
+            script: script.into(),
+            format: None
+        }
+    }
+
+    /// Date format, used with [`RuntimeFieldType::Date`]
+    pub fn format<T: Into<String>>(self, format: T) -> Self {
+        Self {
+            format: Some(format.into()),
+            ..self
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn with_name(self, name: String) -> Self {
+        Self {
+            name,
+            ..self
+        }
+    }
+}
+
+impl Validate for RuntimeField {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        self.script.validate_at(&child(path, "script"), errors);
+    }
+}
\ No newline at end of file
@@ -1,6 +1,7 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use crate::misc::validate::{child, Validate, ValidationError};
 
-#[derive(Debug, Default, Clone, Serialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct Script {
     source: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -21,10 +22,18 @@ impl Script {
         }
     }
 
-    pub fn lang<T: Into<String> + Serialize>(self, source: T) -> Self {
+    pub fn lang<T: Into<String> + Serialize>(self, lang: T) -> Self {
         Self {
-            source: source.into(),
+            lang: Some(lang.into()),
             ..self
         }
     }
+}
+
+impl Validate for Script {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if self.source.is_empty() {
+            errors.push(ValidationError::new(child(path, "source"), "source is empty"));
+        }
+    }
 }
\ No newline at end of file
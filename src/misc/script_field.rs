@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+use crate::misc::script::Script;
+use crate::misc::validate::{child, Validate, ValidationError};
+
+/// https://opensearch.org/docs/latest/search-plugins/searching-data/retrieve-specific-fields/#using-script_fields
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ScriptField {
+    script: Script,
+}
+
+impl ScriptField {
+
+    pub fn new<T: Into<Script>>(script: T) -> Self {
+        Self {
+            script: script.into()
+        }
+    }
+}
+
+impl From<Script> for ScriptField {
+    fn from(val: Script) -> Self {
+        Self::new(val)
+    }
+}
+
+impl Validate for ScriptField {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        self.script.validate_at(&child(path, "script"), errors);
+    }
+}
\ No newline at end of file
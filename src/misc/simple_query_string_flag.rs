@@ -0,0 +1,182 @@
+use std::fmt;
+use std::ops::{BitAnd, BitOr, BitOrAssign, Not};
+use std::str::FromStr;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::Error;
+
+/// https://opensearch.org/docs/latest/query-dsl/full-text/simple-query-string/#simple-query-string-syntax
+///
+/// Examples
+/// ```
+/// use os_query_builder_rs::misc::simple_query_string_flag::SimpleQueryStringFlags;
+///
+/// let flags = SimpleQueryStringFlags::AND | SimpleQueryStringFlags::PHRASE | SimpleQueryStringFlags::PREFIX;
+/// assert_eq!(flags.to_string(), "AND|PREFIX|PHRASE");
+/// assert!(flags.contains(SimpleQueryStringFlags::PHRASE));
+///
+/// let parsed: SimpleQueryStringFlags = "and|phrase|prefix".parse().unwrap();
+/// assert_eq!(parsed, flags);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SimpleQueryStringFlags(u16);
+
+impl SimpleQueryStringFlags {
+    /// No operators, the whole input is matched as text
+    pub const NONE: Self = Self(0);
+    /// `+`
+    pub const AND: Self = Self(1);
+    /// `|`
+    pub const OR: Self = Self(1 << 1);
+    /// `-`
+    pub const NOT: Self = Self(1 << 2);
+    /// `*` at the end of a word
+    pub const PREFIX: Self = Self(1 << 3);
+    /// `"`
+    pub const PHRASE: Self = Self(1 << 4);
+    /// `(` and `)`
+    pub const PRECEDENCE: Self = Self(1 << 5);
+    /// `\`
+    pub const ESCAPE: Self = Self(1 << 6);
+    /// Whitespace splits words
+    pub const WHITESPACE: Self = Self(1 << 7);
+    /// `~N` after a word
+    pub const FUZZY: Self = Self(1 << 8);
+    /// `~N` after a phrase, same flag as [`SimpleQueryStringFlags::SLOP`]
+    pub const NEAR: Self = Self(1 << 9);
+    /// `~N` after a phrase, same flag as [`SimpleQueryStringFlags::NEAR`]
+    pub const SLOP: Self = Self::NEAR;
+    pub const ALL: Self = Self((1 << 10) - 1);
+
+    const NAMES: [(&'static str, Self); 10] = [
+        ("AND", Self::AND),
+        ("OR", Self::OR),
+        ("NOT", Self::NOT),
+        ("PREFIX", Self::PREFIX),
+        ("PHRASE", Self::PHRASE),
+        ("PRECEDENCE", Self::PRECEDENCE),
+        ("ESCAPE", Self::ESCAPE),
+        ("WHITESPACE", Self::WHITESPACE),
+        ("FUZZY", Self::FUZZY),
+        ("SLOP", Self::SLOP),
+    ];
+
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn insert(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+
+    pub fn remove(&mut self, other: Self) {
+        self.0 &= !other.0;
+    }
+}
+
+impl Default for SimpleQueryStringFlags {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+impl BitOr for SimpleQueryStringFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for SimpleQueryStringFlags {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.insert(rhs);
+    }
+}
+
+impl BitAnd for SimpleQueryStringFlags {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self(self.0 & rhs.0)
+    }
+}
+
+impl Not for SimpleQueryStringFlags {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        Self(!self.0 & Self::ALL.0)
+    }
+}
+
+impl fmt::Display for SimpleQueryStringFlags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if *self == Self::ALL {
+            return f.write_str("ALL");
+        }
+        if self.is_empty() {
+            return f.write_str("NONE");
+        }
+
+        let names: Vec<&str> = Self::NAMES.iter()
+            .filter(|(_, flag)| self.contains(*flag))
+            .map(|(name, _)| *name)
+            .collect();
+        f.write_str(&names.join("|"))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseFlagsError(String);
+
+impl fmt::Display for ParseFlagsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown simple_query_string flag `{}`", self.0)
+    }
+}
+
+impl std::error::Error for ParseFlagsError {}
+
+impl FromStr for SimpleQueryStringFlags {
+    type Err = ParseFlagsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut flags = Self::NONE;
+        for name in s.split('|').map(str::trim) {
+            flags |= match name.to_ascii_uppercase().as_str() {
+                "ALL" => Self::ALL,
+                "NONE" => Self::NONE,
+                "NEAR" => Self::NEAR,
+                upper => Self::NAMES.iter()
+                    .find(|(flag_name, _)| *flag_name == upper)
+                    .map(|(_, flag)| *flag)
+                    .ok_or_else(|| ParseFlagsError(name.to_string()))?,
+            };
+        }
+        Ok(flags)
+    }
+}
+
+impl Serialize for SimpleQueryStringFlags {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SimpleQueryStringFlags {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(D::Error::custom)
+    }
+}
@@ -0,0 +1,170 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::Error;
+use serde::ser::SerializeMap;
+use serde_json::{Map, Value};
+
+/// Entry of `sort` in the search body, serialized as `{"<field>": {options}}`.
+/// `_score`, `_doc` and derived fields are sorted like any other field.
+/// https://opensearch.org/docs/latest/search-plugins/searching-data/sort/
+///
+/// Examples
+/// ```
+/// use serde_json::json;
+/// use os_query_builder_rs::misc::sort::{SortField, SortOrder};
+/// use os_query_builder_rs::model::Query;
+///
+/// let query = Query::new().sort([
+///     SortField::new("price").order(SortOrder::Desc).missing("_last"),
+///     SortField::new("_score")
+/// ]);
+///
+/// assert_eq!(json!(query), json!({
+///     "sort": [{ "price": { "order": "desc", "missing": "_last" } }, "_score"]
+/// }));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SortField {
+    field: String,
+    options: SortOptions,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SortOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    order: Option<SortOrder>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mode: Option<SortMode>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    missing: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unmapped_type: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+/// Value picked from multi-valued fields
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortMode {
+    Min,
+    Max,
+    Sum,
+    Avg,
+    Median,
+}
+
+impl SortField {
+    pub fn new<T: Into<String>>(field: T) -> Self {
+        Self {
+            field: field.into(),
+            options: SortOptions::default(),
+        }
+    }
+
+    pub fn order<T: Into<SortOrder>>(self, order: T) -> Self {
+        let options = SortOptions {
+            order: Some(order.into()),
+            ..self.options
+        };
+        Self { options, ..self }
+    }
+
+    pub fn mode<T: Into<SortMode>>(self, mode: T) -> Self {
+        let options = SortOptions {
+            mode: Some(mode.into()),
+            ..self.options
+        };
+        Self { options, ..self }
+    }
+
+    /// `_first`, `_last` or a value used for documents without the field
+    pub fn missing<T: Into<Value>>(self, missing: T) -> Self {
+        let options = SortOptions {
+            missing: Some(missing.into()),
+            ..self.options
+        };
+        Self { options, ..self }
+    }
+
+    /// Type used for indices without a mapping of the field
+    pub fn unmapped_type<T: Into<String>>(self, unmapped_type: T) -> Self {
+        let options = SortOptions {
+            unmapped_type: Some(unmapped_type.into()),
+            ..self.options
+        };
+        Self { options, ..self }
+    }
+
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+}
+
+impl From<&str> for SortField {
+    fn from(field: &str) -> Self {
+        Self::new(field)
+    }
+}
+
+impl From<String> for SortField {
+    fn from(field: String) -> Self {
+        Self::new(field)
+    }
+}
+
+/// A field without options is serialized as its name
+impl Serialize for SortField {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+    {
+        if self.options == SortOptions::default() {
+            return serializer.serialize_str(&self.field);
+        }
+        let mut state = serializer.serialize_map(Some(1))?;
+        state.serialize_entry(&self.field, &self.options)?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for SortField {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Layout {
+            Field(String),
+            Object(Map<String, Value>),
+        }
+
+        match Layout::deserialize(deserializer)? {
+            Layout::Field(field) => Ok(Self::new(field)),
+            Layout::Object(map) => {
+                if map.len() != 1 {
+                    return Err(D::Error::custom(format!("expected one field in a sort entry, found {}", map.len())));
+                }
+                let (field, options) = map.into_iter().next().expect("one field");
+                let options = match options {
+                    // `{"price": "desc"}`
+                    Value::String(order) => SortOptions {
+                        order: Some(SortOrder::deserialize(Value::String(order)).map_err(D::Error::custom)?),
+                        ..SortOptions::default()
+                    },
+                    options => SortOptions::deserialize(options).map_err(D::Error::custom)?,
+                };
+                Ok(Self { field, options })
+            }
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+
+/// `_source` of the search body
+/// https://opensearch.org/docs/latest/search-plugins/searching-data/retrieve-specific-fields/
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SourceFilter {
+    /// `true` or `false` to return or skip the whole document
+    Enabled(bool),
+    /// List of fields, wildcard patterns are allowed
+    Fields(Vec<String>),
+    /// `includes` and `excludes` patterns
+    Rules(SourceRules),
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct SourceRules {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    includes: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    excludes: Option<Vec<String>>,
+}
+
+impl SourceRules {
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn includes<F, T>(self, includes: F) -> Self
+        where
+            F: IntoIterator<Item=T>,
+            T: Into<String>
+    {
+        Self {
+            includes: Some(includes.into_iter().map(|x| x.into()).collect()),
+            ..self
+        }
+    }
+
+    pub fn excludes<F, T>(self, excludes: F) -> Self
+        where
+            F: IntoIterator<Item=T>,
+            T: Into<String>
+    {
+        Self {
+            excludes: Some(excludes.into_iter().map(|x| x.into()).collect()),
+            ..self
+        }
+    }
+}
+
+impl From<bool> for SourceFilter {
+    fn from(val: bool) -> Self {
+        Self::Enabled(val)
+    }
+}
+
+impl From<&str> for SourceFilter {
+    fn from(val: &str) -> Self {
+        Self::Fields(vec![val.into()])
+    }
+}
+
+impl From<String> for SourceFilter {
+    fn from(val: String) -> Self {
+        Self::Fields(vec![val])
+    }
+}
+
+impl<T: Into<String>> From<Vec<T>> for SourceFilter {
+    fn from(val: Vec<T>) -> Self {
+        Self::Fields(val.into_iter().map(|x| x.into()).collect())
+    }
+}
+
+impl<T: Into<String>, const N: usize> From<[T; N]> for SourceFilter {
+    fn from(val: [T; N]) -> Self {
+        Self::Fields(val.into_iter().map(|x| x.into()).collect())
+    }
+}
+
+impl<T: Into<String>> FromIterator<T> for SourceFilter {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::Fields(iter.into_iter().map(|x| x.into()).collect())
+    }
+}
+
+impl From<SourceRules> for SourceFilter {
+    fn from(val: SourceRules) -> Self {
+        Self::Rules(val)
+    }
+}
@@ -0,0 +1,196 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::misc::{
+    field_and_format::FieldAndFormat,
+    source_filter::SourceFilter
+};
+
+/// `top_hits` aggregation, the most relevant documents of each bucket
+/// https://opensearch.org/docs/latest/aggregations/metric/top-hits/
+///
+/// Examples
+/// ```
+/// use serde_json::json;
+/// use os_query_builder_rs::misc::source_filter::SourceRules;
+/// use os_query_builder_rs::misc::top_hits::TopHits;
+/// use os_query_builder_rs::model::Query;
+///
+/// let top_hits = TopHits::new()
+///             .size(3usize)
+///             .source(SourceRules::new().excludes(["embedding"]));
+///
+/// let query = Query::new().aggs(json!({
+///     "brands": {
+///         "terms": { "field": "brand" },
+///         "aggs": { "top_products": top_hits }
+///     }
+/// }));
+///
+/// assert_eq!(json!(query), json!({
+///     "aggs": {
+///         "brands": {
+///             "terms": { "field": "brand" },
+///             "aggs": { "top_products": { "top_hits": { "size": 3, "_source": { "excludes": ["embedding"] } } } }
+///         }
+///     }
+/// }));
+/// ```
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct TopHits {
+    top_hits: HitsOptions,
+}
+
+/// `inner_hits` of `nested`, `has_child` and `has_parent` queries, the matching inner documents
+/// https://opensearch.org/docs/latest/search-plugins/searching-data/inner-hits/
+///
+/// Examples
+/// ```
+/// use serde_json::json;
+/// use os_query_builder_rs::misc::top_hits::InnerHits;
+///
+/// let inner_hits = InnerHits::new()
+///             .name("matched_reviews")
+///             .size(2usize)
+///             .source(["reviews.text"]);
+///
+/// assert_eq!(json!(inner_hits), json!({ "name": "matched_reviews", "size": 2, "_source": ["reviews.text"] }));
+/// ```
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct InnerHits {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+
+    #[serde(flatten)]
+    options: HitsOptions,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+struct HitsOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    from: Option<usize>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    size: Option<usize>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort: Option<Value>,
+
+    #[serde(rename = "_source", skip_serializing_if = "Option::is_none")]
+    source: Option<SourceFilter>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    docvalue_fields: Option<Vec<FieldAndFormat>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    explain: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seq_no_primary_term: Option<bool>,
+}
+
+impl TopHits {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl InnerHits {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Key of the inner hits in the response, the path or type by default
+    pub fn name<T: Into<String>>(self, name: T) -> Self {
+        Self {
+            name: Some(name.into()),
+            ..self
+        }
+    }
+}
+
+/// Options shared by `top_hits` and `inner_hits`
+macro_rules! hits_options_methods {
+    ($($name:ident: $field:ident),*) => {
+        $(
+            #[allow(clippy::needless_update)]
+            impl $name {
+                pub fn from<T: Into<usize>>(self, from: T) -> Self {
+                    let $field = HitsOptions {
+                        from: Some(from.into()),
+                        ..self.$field
+                    };
+                    Self { $field, ..self }
+                }
+
+                pub fn size<T: Into<usize>>(self, size: T) -> Self {
+                    let $field = HitsOptions {
+                        size: Some(size.into()),
+                        ..self.$field
+                    };
+                    Self { $field, ..self }
+                }
+
+                pub fn sort<T: Into<Value>>(self, sort: T) -> Self {
+                    let $field = HitsOptions {
+                        sort: Some(sort.into()),
+                        ..self.$field
+                    };
+                    Self { $field, ..self }
+                }
+
+                /// `false`, a list of fields or [`SourceRules`](crate::misc::source_filter::SourceRules)
+                pub fn source<T: Into<SourceFilter>>(self, source: T) -> Self {
+                    let $field = HitsOptions {
+                        source: Some(source.into()),
+                        ..self.$field
+                    };
+                    Self { $field, ..self }
+                }
+
+                pub fn docvalue_fields<F, T>(self, docvalue_fields: F) -> Self
+                    where
+                        F: IntoIterator<Item=T>,
+                        T: Into<FieldAndFormat>
+                {
+                    let $field = HitsOptions {
+                        docvalue_fields: Some(docvalue_fields.into_iter().map(|x| x.into()).collect()),
+                        ..self.$field
+                    };
+                    Self { $field, ..self }
+                }
+
+                pub fn explain(self, explain: bool) -> Self {
+                    let $field = HitsOptions {
+                        explain: Some(explain),
+                        ..self.$field
+                    };
+                    Self { $field, ..self }
+                }
+
+                pub fn version(self, version: bool) -> Self {
+                    let $field = HitsOptions {
+                        version: Some(version),
+                        ..self.$field
+                    };
+                    Self { $field, ..self }
+                }
+
+                pub fn seq_no_primary_term(self, seq_no_primary_term: bool) -> Self {
+                    let $field = HitsOptions {
+                        seq_no_primary_term: Some(seq_no_primary_term),
+                        ..self.$field
+                    };
+                    Self { $field, ..self }
+                }
+            }
+        )*
+    }
+}
+
+hits_options_methods! {
+    TopHits: top_hits,
+    InnerHits: options
+}
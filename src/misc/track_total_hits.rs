@@ -0,0 +1,85 @@
+use std::fmt;
+use serde::{Deserialize, Serialize};
+
+/// https://opensearch.org/docs/latest/api-reference/search/#request-body
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum TrackTotalHits {
+    Track(bool),
+    UpTo(u64),
+}
+
+impl From<bool> for TrackTotalHits {
+    fn from(val: bool) -> Self {
+        Self::Track(val)
+    }
+}
+
+macro_rules! from_types {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for TrackTotalHits {
+                fn from(val: $ty) -> Self {
+                    Self::UpTo(val.into())
+                }
+            }
+        )*
+    }
+}
+
+from_types! {
+    u8,
+    u16,
+    u32,
+    u64
+}
+
+impl From<usize> for TrackTotalHits {
+    fn from(val: usize) -> Self {
+        Self::UpTo(val as u64)
+    }
+}
+
+/// Signed integers are accepted when they are not negative
+///
+/// Examples
+/// ```
+/// use os_query_builder_rs::misc::track_total_hits::TrackTotalHits;
+/// use os_query_builder_rs::model::Query;
+///
+/// let query = Query::new().track_total_hits(TrackTotalHits::try_from(1000_i32).unwrap());
+///
+/// assert!(TrackTotalHits::try_from(-1_i32).is_err());
+/// ```
+macro_rules! try_from_types {
+    ($($ty:ty),*) => {
+        $(
+            impl TryFrom<$ty> for TrackTotalHits {
+                type Error = NegativeTrackTotalHits;
+
+                fn try_from(val: $ty) -> Result<Self, Self::Error> {
+                    u64::try_from(val)
+                        .map(Self::UpTo)
+                        .map_err(|_| NegativeTrackTotalHits(val.into()))
+                }
+            }
+        )*
+    }
+}
+
+try_from_types! {
+    i32,
+    i64
+}
+
+/// `track_total_hits` must not be negative
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NegativeTrackTotalHits(pub i64);
+
+impl fmt::Display for NegativeTrackTotalHits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "track_total_hits must not be negative, got {}", self.0)
+    }
+}
+
+impl std::error::Error for NegativeTrackTotalHits {}
@@ -0,0 +1,119 @@
+use std::fmt;
+
+/// Checks that the query produces DSL accepted by OpenSearch
+///
+/// Examples
+/// ```
+/// use os_query_builder_rs::misc::query_field::QueryField;
+/// use os_query_builder_rs::misc::validate::Validate;
+/// use os_query_builder_rs::term::range::Range;
+///
+/// let errors = QueryField::from(Range::new().gte(10)).validate().unwrap_err();
+/// assert_eq!(errors.to_string(), "/range: field is missing");
+/// ```
+pub trait Validate {
+    /// Pushes errors of this node and its children. `path` is a JSON pointer to the node.
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>);
+
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = Vec::new();
+        self.validate_at("", &mut errors);
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationErrors(errors))
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError {
+    /// JSON pointer to the invalid node, e.g. `/query/bool/must/0/term`
+    pub path: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationErrors(pub Vec<ValidationError>);
+
+impl ValidationError {
+
+    pub fn new<T, F>(path: T, message: F) -> Self
+        where T: Into<String>,
+              F: Into<String>
+    {
+        Self {
+            path: path.into(),
+            message: message.into()
+        }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+impl fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, error) in self.0.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{error}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+impl std::error::Error for ValidationErrors {}
+
+impl<T: Validate> Validate for Option<T> {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if let Some(value) = self {
+            value.validate_at(path, errors);
+        }
+    }
+}
+
+impl<T: Validate> Validate for Box<T> {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        self.as_ref().validate_at(path, errors);
+    }
+}
+
+/// Items are validated at `<path>/<index>`
+impl<T: Validate> Validate for Vec<T> {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        for (i, value) in self.iter().enumerate() {
+            value.validate_at(&child(path, i), errors);
+        }
+    }
+}
+
+/// Appends an escaped segment to the JSON pointer
+pub(crate) fn child<T: fmt::Display>(path: &str, segment: T) -> String {
+    let segment = segment.to_string()
+        .replace('~', "~0")
+        .replace('/', "~1");
+    format!("{path}/{segment}")
+}
+
+/// Pushes an error when the field of `{"<field>": {...}}` queries is missing or empty
+pub(crate) fn check_field(field: Option<&str>, path: &str, errors: &mut Vec<ValidationError>) -> bool {
+    match field {
+        Some(field) if !field.is_empty() => true,
+        Some(_) => {
+            errors.push(ValidationError::new(path, "field is empty"));
+            false
+        }
+        None => {
+            errors.push(ValidationError::new(path, "field is missing"));
+            false
+        }
+    }
+}
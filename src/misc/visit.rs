@@ -0,0 +1,108 @@
+use crate::full_text::intervals::interval_rule::IntervalRule;
+use crate::misc::query_field::QueryField;
+
+/// Read-only pass over a query tree.
+/// Default methods walk into children, override them and call [`Walk::walk`] to keep walking.
+///
+/// Examples
+/// ```
+/// use os_query_builder_rs::compound_query::bool::Bool;
+/// use os_query_builder_rs::full_text::r#match::Match;
+/// use os_query_builder_rs::misc::query_field::QueryField;
+/// use os_query_builder_rs::misc::visit::Visitor;
+/// use os_query_builder_rs::term::term::Term;
+///
+/// #[derive(Default)]
+/// struct Fields(Vec<String>);
+///
+/// impl Visitor for Fields {
+///     fn visit_field(&mut self, field: &str) {
+///         self.0.push(field.to_string());
+///     }
+/// }
+///
+/// let query = QueryField::from(Bool::new()
+///     .must(vec![Match::new().field("title").value("wind")])
+///     .filter(vec![Term::new("brand", "FIAT")]));
+///
+/// let mut fields = Fields::default();
+/// fields.visit_query(&query);
+/// assert_eq!(fields.0, vec!["title", "brand"]);
+/// ```
+pub trait Visitor {
+    fn visit_query(&mut self, query: &QueryField) {
+        query.walk(self);
+    }
+
+    fn visit_interval_rule(&mut self, rule: &IntervalRule) {
+        rule.walk(self);
+    }
+
+    /// Field referenced by the query, `^boost` suffixes of multi-field queries are cut off
+    fn visit_field(&mut self, _field: &str) {}
+
+    fn visit_boost(&mut self, _boost: f64) {}
+}
+
+/// Mutable pass over a query tree, e.g. to rename fields or to replace whole clauses.
+/// Default methods walk into children, override them and call [`Walk::walk_mut`] to keep walking.
+///
+/// Examples
+/// ```
+/// use serde_json::json;
+/// use os_query_builder_rs::compound_query::bool::Bool;
+/// use os_query_builder_rs::misc::query_field::QueryField;
+/// use os_query_builder_rs::misc::visit::VisitorMut;
+/// use os_query_builder_rs::term::term::Term;
+///
+/// struct StripBoost;
+///
+/// impl VisitorMut for StripBoost {
+///     fn visit_boost_mut(&mut self, boost: &mut Option<f64>) {
+///         *boost = None;
+///     }
+/// }
+///
+/// let mut query = QueryField::from(Bool::new().filter(vec![Term::new("brand", "FIAT").boost(2)]));
+/// StripBoost.visit_query_mut(&mut query);
+///
+/// assert_eq!(json!(query), json!({ "bool": { "filter": [{ "term": { "brand": { "value": "FIAT" } } }] } }));
+/// ```
+pub trait VisitorMut {
+    fn visit_query_mut(&mut self, query: &mut QueryField) {
+        query.walk_mut(self);
+    }
+
+    fn visit_interval_rule_mut(&mut self, rule: &mut IntervalRule) {
+        rule.walk_mut(self);
+    }
+
+    /// Field referenced by the query, `^boost` suffixes of multi-field queries are kept
+    fn visit_field_mut(&mut self, _field: &mut String) {}
+
+    fn visit_boost_mut(&mut self, _boost: &mut Option<f64>) {}
+}
+
+/// Passes children of the node to the visitor
+pub trait Walk {
+    fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V);
+
+    fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V);
+}
+
+/// Field of `multi_match` and `simple_query_string`, may end with `^boost`
+pub(crate) fn walk_boosted_field<V: Visitor + ?Sized>(visitor: &mut V, field: &str) {
+    let name = field.split_once('^').map_or(field, |(name, _)| name);
+    visitor.visit_field(name);
+}
+
+pub(crate) fn walk_boosted_field_mut<V: VisitorMut + ?Sized>(visitor: &mut V, field: &mut String) {
+    match field.split_once('^') {
+        Some((name, boost)) => {
+            let mut name = name.to_string();
+            visitor.visit_field_mut(&mut name);
+            *field = format!("{name}^{boost}");
+        }
+        None => visitor.visit_field_mut(field),
+    }
+}
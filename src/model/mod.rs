@@ -0,0 +1,425 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use crate::misc::{
+    field_and_format::FieldAndFormat,
+    query_field::QueryField,
+    runtime_field::RuntimeField,
+    script_field::ScriptField,
+    sort::SortField,
+    source_filter::SourceFilter,
+    track_total_hits::TrackTotalHits,
+    validate::{child, Validate, ValidationError, ValidationErrors},
+    visit::{Visitor, VisitorMut, Walk}
+};
+
+
+/// Examples
+/// ```
+/// use os_query_builder_rs::full_text::multi_match::MultiMatch;
+/// use os_query_builder_rs::misc::minimum_should_match::MinimumShouldMatch;
+/// use os_query_builder_rs::misc::operator::Operator;
+/// use os_query_builder_rs::misc::query_field::QueryField;
+/// use os_query_builder_rs::misc::r#type::Type;
+/// use os_query_builder_rs::model::Query;
+///
+/// let multi_match = MultiMatch::new()
+///             .fields(vec!["brands", "articles"])
+///             .value("oc47")
+///             .operator(Operator::And)
+///             .query_type(Type::BestFields)
+///             .boost(2)
+///             .minimum_should_match(MinimumShouldMatch::percentage(90).unwrap());
+///
+/// let query = Query::new()
+///             .source(vec!["test"])
+///             .query(multi_match);
+/// ```
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Query {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    from: Option<usize>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    size: Option<usize>,
+
+    #[serde(rename = "_source", skip_serializing_if = "Option::is_none")]
+    source: Option<SourceFilter>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query: Option<QueryField>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort: Option<Vec<SortField>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    aggs: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    post_filter: Option<QueryField>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    track_total_hits: Option<TrackTotalHits>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_score: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timeout: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    terminate_after: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    explain: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seq_no_primary_term: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    track_scores: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stats: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    profile: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    indices_boost: Option<Vec<HashMap<String, f64>>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stored_fields: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    docvalue_fields: Option<Vec<FieldAndFormat>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fields: Option<Vec<FieldAndFormat>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    script_fields: Option<HashMap<String, ScriptField>>,
+
+    #[serde(
+        rename = "derived",
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "deserialize_runtime_mappings"
+    )]
+    runtime_mappings: Option<HashMap<String, RuntimeField>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ext: Option<Value>,
+}
+
+impl Query {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `false`, a list of fields or [`SourceRules`](crate::misc::source_filter::SourceRules)
+    pub fn source<T: Into<SourceFilter>>(self, source: T) -> Self {
+        Self {
+            source: Some(source.into()),
+            ..self
+        }
+    }
+
+    /// List of fields from any iterator, see [`Query::source`]
+    pub fn source_fields<F, T>(self, fields: F) -> Self
+        where
+            F: IntoIterator<Item=T>,
+            T: Into<String>
+    {
+        Self {
+            source: Some(fields.into_iter().collect()),
+            ..self
+        }
+    }
+
+    pub fn query<T: Into<QueryField> + Serialize>(self, query: T) -> Self {
+        Self {
+            query: Some(query.into()),
+            ..self
+        }
+    }
+
+    pub fn from<T: Into<usize> + Serialize>(self, from: T) -> Self {
+        Self {
+            from: Some(from.into()),
+            ..self
+        }
+    }
+
+    pub fn size<T: Into<usize> + Serialize>(self, size: T) -> Self {
+        Self {
+            size: Some(size.into()),
+            ..self
+        }
+    }
+
+    pub fn sort<F, T>(self, sort: F) -> Self
+        where
+            F: IntoIterator<Item=T>,
+            T: Into<SortField>
+    {
+        Self {
+            sort: Some(sort.into_iter().map(Into::into).collect()),
+            ..self
+        }
+    }
+
+    pub fn aggs<T: Into<Value> + Serialize>(self, aggs: T) -> Self {
+        Self {
+            aggs: Some(aggs.into()),
+            ..self
+        }
+    }
+
+    pub fn post_filter<T: Into<QueryField> + Serialize>(self, post_filter: T) -> Self {
+        Self {
+            post_filter: Some(post_filter.into()),
+            ..self
+        }
+    }
+
+    /// `true`, `false` or the number of hits to count accurately
+    pub fn track_total_hits<T: Into<TrackTotalHits> + Serialize>(self, track_total_hits: T) -> Self {
+        Self {
+            track_total_hits: Some(track_total_hits.into()),
+            ..self
+        }
+    }
+
+    pub fn min_score<T: Into<f64> + Serialize>(self, min_score: T) -> Self {
+        Self {
+            min_score: Some(min_score.into()),
+            ..self
+        }
+    }
+
+    /// Time units, e.g. `"10s"` or `"500ms"`
+    pub fn timeout<T: Into<String> + Serialize>(self, timeout: T) -> Self {
+        Self {
+            timeout: Some(timeout.into()),
+            ..self
+        }
+    }
+
+    pub fn terminate_after<T: Into<u64> + Serialize>(self, terminate_after: T) -> Self {
+        Self {
+            terminate_after: Some(terminate_after.into()),
+            ..self
+        }
+    }
+
+    pub fn explain(self, explain: bool) -> Self {
+        Self {
+            explain: Some(explain),
+            ..self
+        }
+    }
+
+    pub fn version(self, version: bool) -> Self {
+        Self {
+            version: Some(version),
+            ..self
+        }
+    }
+
+    pub fn seq_no_primary_term(self, seq_no_primary_term: bool) -> Self {
+        Self {
+            seq_no_primary_term: Some(seq_no_primary_term),
+            ..self
+        }
+    }
+
+    pub fn track_scores(self, track_scores: bool) -> Self {
+        Self {
+            track_scores: Some(track_scores),
+            ..self
+        }
+    }
+
+    pub fn stats<F, T>(self, stats: F) -> Self
+        where
+            F: IntoIterator<Item=T>,
+            T: Into<String>
+    {
+        Self {
+            stats: Some(stats.into_iter().map(|x| x.into()).collect()),
+            ..self
+        }
+    }
+
+    pub fn profile(self, profile: bool) -> Self {
+        Self {
+            profile: Some(profile),
+            ..self
+        }
+    }
+
+    /// Pairs of index name (or alias) and boost, order is preserved
+    pub fn indices_boost<F, K, V>(self, indices_boost: F) -> Self
+        where
+            F: IntoIterator<Item=(K, V)>,
+            K: Into<String>,
+            V: Into<f64>
+    {
+        Self {
+            indices_boost: Some(indices_boost
+                .into_iter()
+                .map(|(index, boost)| HashMap::from([(index.into(), boost.into())]))
+                .collect()),
+            ..self
+        }
+    }
+
+    pub fn stored_fields<F, T>(self, stored_fields: F) -> Self
+        where
+            F: IntoIterator<Item=T>,
+            T: Into<String>
+    {
+        Self {
+            stored_fields: Some(stored_fields.into_iter().map(|x| x.into()).collect()),
+            ..self
+        }
+    }
+
+    pub fn docvalue_fields<F, T>(self, docvalue_fields: F) -> Self
+        where
+            F: IntoIterator<Item=T>,
+            T: Into<FieldAndFormat>
+    {
+        Self {
+            docvalue_fields: Some(docvalue_fields.into_iter().map(|x| x.into()).collect()),
+            ..self
+        }
+    }
+
+    pub fn fields<F, T>(self, fields: F) -> Self
+        where
+            F: IntoIterator<Item=T>,
+            T: Into<FieldAndFormat>
+    {
+        Self {
+            fields: Some(fields.into_iter().map(|x| x.into()).collect()),
+            ..self
+        }
+    }
+
+    pub fn script_fields<F, K, V>(self, script_fields: F) -> Self
+        where
+            F: IntoIterator<Item=(K, V)>,
+            K: Into<String>,
+            V: Into<ScriptField>
+    {
+        Self {
+            script_fields: Some(script_fields
+                .into_iter()
+                .map(|(name, script)| (name.into(), script.into()))
+                .collect()),
+            ..self
+        }
+    }
+
+    /// Fields computed at search time. OpenSearch calls them derived fields
+    /// and expects them under the `derived` key of the search body.
+    /// They can be referenced by name like any mapped field.
+    pub fn runtime_mappings<F, T>(self, runtime_mappings: F) -> Self
+        where
+            F: IntoIterator<Item=T>,
+            T: Into<RuntimeField>
+    {
+        Self {
+            runtime_mappings: Some(runtime_mappings
+                .into_iter()
+                .map(|x| {
+                    let field = x.into();
+                    (field.name().to_string(), field)
+                })
+                .collect()),
+            ..self
+        }
+    }
+
+    /// Validates the whole body and returns it back, errors point to invalid nodes
+    ///
+    /// Examples
+    /// ```
+    /// use os_query_builder_rs::compound_query::bool::Bool;
+    /// use os_query_builder_rs::model::Query;
+    /// use os_query_builder_rs::term::range::Range;
+    ///
+    /// let errors = Query::new()
+    ///     .query(Bool::new().filter(vec![Range::new().gte(10)]))
+    ///     .build()
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(errors.0[0].path, "/query/bool/filter/0/range");
+    /// ```
+    pub fn build(self) -> Result<Self, ValidationErrors> {
+        self.validate()?;
+        Ok(self)
+    }
+
+    /// Body of plugin extensions, e.g. `search_pipeline` or `rerank`
+    pub fn ext<T: Into<Value> + Serialize>(self, ext: T) -> Self {
+        Self {
+            ext: Some(ext.into()),
+            ..self
+        }
+    }
+}
+
+
+impl Validate for Query {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        self.query.validate_at(&child(path, "query"), errors);
+        self.post_filter.validate_at(&child(path, "post_filter"), errors);
+
+        for (name, field) in self.script_fields.iter().flatten() {
+            field.validate_at(&child(&child(path, "script_fields"), name), errors);
+        }
+        for (name, field) in self.runtime_mappings.iter().flatten() {
+            field.validate_at(&child(&child(path, "derived"), name), errors);
+        }
+    }
+}
+
+/// Walks `query` and `post_filter`
+impl Walk for Query {
+    fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        if let Some(query) = &self.query {
+            visitor.visit_query(query);
+        }
+        if let Some(post_filter) = &self.post_filter {
+            visitor.visit_query(post_filter);
+        }
+    }
+
+    fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        if let Some(query) = &mut self.query {
+            visitor.visit_query_mut(query);
+        }
+        if let Some(post_filter) = &mut self.post_filter {
+            visitor.visit_query_mut(post_filter);
+        }
+    }
+}
+
+/// Names of runtime fields are the keys of the map
+fn deserialize_runtime_mappings<'de, D>(deserializer: D) -> Result<Option<HashMap<String, RuntimeField>>, D::Error>
+    where
+        D: Deserializer<'de>,
+{
+    let runtime_mappings = HashMap::<String, RuntimeField>::deserialize(deserializer)?
+        .into_iter()
+        .map(|(name, field)| (name.clone(), field.with_name(name)))
+        .collect();
+
+    Ok(Some(runtime_mappings))
+}
\ No newline at end of file
@@ -0,0 +1,78 @@
+use serde::{Deserialize, Serialize};
+
+/// Header line of a search in a multi-search request
+/// https://opensearch.org/docs/latest/api-reference/multi-search/#metadata-only-options
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct MultiSearchHeader {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    index: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    preference: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    routing: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    search_type: Option<SearchType>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    request_cache: Option<bool>,
+}
+
+impl MultiSearchHeader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Index, comma-separated indices or pattern, can be omitted when the request targets an index
+    pub fn index<T: Into<String>>(self, index: T) -> Self {
+        Self {
+            index: Some(index.into()),
+            ..self
+        }
+    }
+
+    /// Nodes or shards to run the search on, e.g. `_local` or a custom string for consistent routing
+    pub fn preference<T: Into<String>>(self, preference: T) -> Self {
+        Self {
+            preference: Some(preference.into()),
+            ..self
+        }
+    }
+
+    pub fn routing<T: Into<String>>(self, routing: T) -> Self {
+        Self {
+            routing: Some(routing.into()),
+            ..self
+        }
+    }
+
+    pub fn search_type<T: Into<SearchType>>(self, search_type: T) -> Self {
+        Self {
+            search_type: Some(search_type.into()),
+            ..self
+        }
+    }
+
+    pub fn request_cache(self, request_cache: bool) -> Self {
+        Self {
+            request_cache: Some(request_cache),
+            ..self
+        }
+    }
+}
+
+impl<T: Into<String>> From<T> for MultiSearchHeader {
+    fn from(index: T) -> Self {
+        Self::new().index(index)
+    }
+}
+
+/// https://opensearch.org/docs/latest/api-reference/search/#url-parameters
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchType {
+    QueryThenFetch,
+    DfsQueryThenFetch,
+}
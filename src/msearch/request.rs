@@ -0,0 +1,88 @@
+use serde::Serialize;
+use crate::model::Query;
+use crate::msearch::header::MultiSearchHeader;
+
+/// Body of the `_msearch` endpoint, responses come back in the order of the searches
+/// https://opensearch.org/docs/latest/api-reference/multi-search/
+///
+/// Examples
+/// ```
+/// use os_query_builder_rs::model::Query;
+/// use os_query_builder_rs::msearch::header::MultiSearchHeader;
+/// use os_query_builder_rs::msearch::request::MultiSearchRequest;
+/// use os_query_builder_rs::term::term::Term;
+///
+/// let msearch = MultiSearchRequest::new()
+///             .search("products", Query::new().query(Term::new("brand", "FIAT")))
+///             .search(MultiSearchHeader::new().index("products").request_cache(true), Query::new().size(0usize));
+///
+/// assert_eq!(msearch.to_ndjson(), concat!(
+///     r#"{"index":"products"}"#, "\n",
+///     r#"{"query":{"term":{"brand":{"value":"FIAT"}}}}"#, "\n",
+///     r#"{"index":"products","request_cache":true}"#, "\n",
+///     r#"{"size":0}"#, "\n",
+/// ));
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct MultiSearchRequest {
+    searches: Vec<(MultiSearchHeader, Query)>,
+}
+
+impl MultiSearchRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn search<H: Into<MultiSearchHeader>>(mut self, header: H, query: Query) -> Self {
+        self.push(header, query);
+        self
+    }
+
+    pub fn searches<H, F>(mut self, searches: F) -> Self
+        where H: Into<MultiSearchHeader>,
+              F: IntoIterator<Item = (H, Query)>
+    {
+        self.searches.extend(searches.into_iter().map(|(header, query)| (header.into(), query)));
+        self
+    }
+
+    pub fn push<H: Into<MultiSearchHeader>>(&mut self, header: H, query: Query) -> &mut Self {
+        self.searches.push((header.into(), query));
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.searches.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.searches.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &(MultiSearchHeader, Query)> {
+        self.searches.iter()
+    }
+
+    /// Newline-delimited body, ending with a newline as the endpoint requires
+    pub fn to_ndjson(&self) -> String {
+        let mut body = String::new();
+        for (header, query) in &self.searches {
+            body.push_str(&to_json(header));
+            body.push('\n');
+            body.push_str(&to_json(query));
+            body.push('\n');
+        }
+        body
+    }
+}
+
+impl<H: Into<MultiSearchHeader>> FromIterator<(H, Query)> for MultiSearchRequest {
+    fn from_iter<I: IntoIterator<Item = (H, Query)>>(iter: I) -> Self {
+        Self::new().searches(iter)
+    }
+}
+
+/// Headers and queries are built from strings, numbers and `Value`s, which always serialize
+fn to_json<T: Serialize>(value: &T) -> String {
+    serde_json::to_string(value).expect("msearch line serializes")
+}
@@ -0,0 +1,449 @@
+use std::collections::HashMap;
+use std::fmt;
+use serde::{Deserialize, Deserializer};
+use serde::de::{DeserializeOwned, DeserializeSeed, MapAccess, SeqAccess, Visitor};
+use serde_json::{Map, Number, Value};
+use crate::response::search::Hits;
+
+/// `aggregations` section of the search response.
+/// Results are read by the names used in the request and parsed on access.
+///
+/// Examples
+/// ```
+/// use serde_json::json;
+/// use os_query_builder_rs::response::aggregations::AggregationsResponse;
+///
+/// let aggregations: AggregationsResponse = serde_json::from_value(json!({
+///     "brands": {
+///         "buckets": [
+///             { "key": "FIAT", "doc_count": 3, "avg_price": { "value": 10.5 } }
+///         ]
+///     }
+/// })).unwrap();
+///
+/// let brands = aggregations.buckets("brands").unwrap();
+/// assert_eq!(brands.buckets[0].doc_count, 3);
+/// assert_eq!(brands.buckets[0].aggregations.value("avg_price").unwrap(), Some(10.5));
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct AggregationsResponse {
+    aggregations: HashMap<String, Value>,
+}
+
+#[derive(Debug)]
+pub enum AggregationError {
+    /// No aggregation with this name in the response
+    Missing(String),
+    /// Aggregation exists, but has another shape
+    Invalid(String, serde_json::Error),
+}
+
+impl fmt::Display for AggregationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AggregationError::Missing(name) => write!(f, "aggregation `{name}` is missing"),
+            AggregationError::Invalid(name, err) => write!(f, "aggregation `{name}` is invalid: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for AggregationError {}
+
+/// Result of single-value metric aggregations: `avg`, `sum`, `min`, `max`, `cardinality`, `value_count`
+#[derive(Debug, Clone, Deserialize)]
+pub struct MetricValue {
+    /// `null` when there are no documents
+    pub value: Option<f64>,
+    #[serde(default)]
+    pub value_as_string: Option<String>,
+}
+
+/// Result of `stats` and `extended_stats` aggregations
+#[derive(Debug, Clone, Deserialize)]
+pub struct Stats {
+    pub count: u64,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub avg: Option<f64>,
+    pub sum: f64,
+    #[serde(default)]
+    pub sum_of_squares: Option<f64>,
+    #[serde(default)]
+    pub variance: Option<f64>,
+    #[serde(default)]
+    pub std_deviation: Option<f64>,
+}
+
+/// Result of `percentiles` and `percentile_ranks` aggregations, both `keyed` layouts are supported
+#[derive(Debug, Clone)]
+pub struct Percentiles {
+    pub values: Vec<Percentile>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Percentile {
+    #[serde(rename = "key")]
+    pub percent: f64,
+    pub value: Option<f64>,
+}
+
+/// Result of multi-bucket aggregations: `terms`, `histogram`, `date_histogram`, `range`, `filters`, `composite`, etc.
+#[derive(Debug, Clone, Deserialize)]
+pub struct BucketAggregation {
+    #[serde(deserialize_with = "deserialize_buckets")]
+    pub buckets: Vec<Bucket>,
+    /// Key to pass to `after` of the next `composite` request
+    #[serde(default)]
+    pub after_key: Option<HashMap<String, Value>>,
+    #[serde(default)]
+    pub sum_other_doc_count: Option<u64>,
+    #[serde(default)]
+    pub doc_count_error_upper_bound: Option<i64>,
+}
+
+/// Bucket of multi-bucket aggregations or result of single-bucket aggregations
+/// (`filter`, `global`, `missing`, `nested`, `reverse_nested`)
+#[derive(Debug, Clone)]
+pub struct Bucket {
+    /// `Value::Null` for single-bucket aggregations, an object for `composite`
+    pub key: Value,
+    pub key_as_string: Option<String>,
+    pub doc_count: u64,
+    /// Bounds of `range` and `date_range` buckets
+    pub from: Option<f64>,
+    pub to: Option<f64>,
+    pub aggregations: AggregationsResponse,
+}
+
+/// Result of `top_hits` aggregation
+#[derive(Debug, Clone, Deserialize)]
+#[serde(bound = "T: DeserializeOwned")]
+pub struct TopHits<T = Value> {
+    pub hits: Hits<T>,
+}
+
+impl AggregationsResponse {
+
+    /// Raw value of the aggregation, `keyed` buckets are kept as an array in the order of the response
+    /// with the bucket name in `key`
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.aggregations.get(name)
+    }
+
+    pub fn names(&self) -> impl Iterator<Item=&str> {
+        self.aggregations.keys().map(|x| x.as_str())
+    }
+
+    /// Parse aggregation into any type
+    pub fn parse<T: DeserializeOwned>(&self, name: &str) -> Result<T, AggregationError> {
+        let value = self.get(name)
+            .ok_or_else(|| AggregationError::Missing(name.to_string()))?;
+
+        T::deserialize(value)
+            .map_err(|err| AggregationError::Invalid(name.to_string(), err))
+    }
+
+    pub fn metric(&self, name: &str) -> Result<MetricValue, AggregationError> {
+        self.parse(name)
+    }
+
+    /// Value of single-value metric aggregation
+    pub fn value(&self, name: &str) -> Result<Option<f64>, AggregationError> {
+        self.metric(name).map(|metric| metric.value)
+    }
+
+    pub fn stats(&self, name: &str) -> Result<Stats, AggregationError> {
+        self.parse(name)
+    }
+
+    pub fn percentiles(&self, name: &str) -> Result<Percentiles, AggregationError> {
+        self.parse(name)
+    }
+
+    pub fn buckets(&self, name: &str) -> Result<BucketAggregation, AggregationError> {
+        self.parse(name)
+    }
+
+    /// Result of single-bucket aggregation
+    pub fn bucket(&self, name: &str) -> Result<Bucket, AggregationError> {
+        self.parse(name)
+    }
+
+    pub fn top_hits<T: DeserializeOwned>(&self, name: &str) -> Result<TopHits<T>, AggregationError> {
+        self.parse(name)
+    }
+}
+
+impl Percentiles {
+
+    /// Value for the requested percent, e.g. `99.0`
+    pub fn get(&self, percent: f64) -> Option<f64> {
+        self.values
+            .iter()
+            .find(|x| x.percent == percent)
+            .and_then(|x| x.value)
+    }
+}
+
+impl<'de> Deserialize<'de> for Percentiles {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Values {
+            Keyed(HashMap<String, Value>),
+            List(Vec<Percentile>),
+        }
+
+        #[derive(Deserialize)]
+        struct Inner {
+            values: Values,
+        }
+
+        let values = match Inner::deserialize(deserializer)?.values {
+            Values::List(values) => values,
+            Values::Keyed(values) => {
+                // skip `"99.0_as_string"` entries added by `format`
+                let mut values: Vec<Percentile> = values
+                    .into_iter()
+                    .filter_map(|(key, value)| key.parse().ok().map(|percent| Percentile { percent, value: value.as_f64() }))
+                    .collect();
+                values.sort_by(|a, b| a.percent.total_cmp(&b.percent));
+                values
+            }
+        };
+
+        Ok(Self { values })
+    }
+}
+
+impl<'de> Deserialize<'de> for AggregationsResponse {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        let aggregations = HashMap::<String, AggregationValue>::deserialize(deserializer)?
+            .into_iter()
+            .map(|(name, value)| (name, value.0))
+            .collect();
+
+        Ok(Self { aggregations })
+    }
+}
+
+/// JSON value of an aggregation. `Map` sorts its keys, so `keyed` buckets are turned
+/// into an array while the order of the response is still known.
+struct AggregationValue(Value);
+
+impl<'de> Deserialize<'de> for AggregationValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(AggregationValueVisitor { level: Level::Body })
+    }
+}
+
+/// Part of the response being read. `buckets` and `hits` are only special in an aggregation body,
+/// entries of buckets and user data (`after_key`, `meta`, `scripted_metric` results) may use any names.
+#[derive(Clone, Copy)]
+enum Level {
+    /// Result of an aggregation, or a sub-aggregation of single-bucket aggregations
+    Body,
+    /// Value of `buckets`
+    Buckets,
+    /// Single entry of `buckets`
+    Bucket,
+}
+
+/// Entries of an aggregation body holding data instead of sub-aggregations, kept as they are
+const RAW_BODY_ENTRIES: [&str; 8] = ["hits", "value", "values", "after_key", "meta", "top", "bounds", "std_deviation_bounds"];
+
+struct AggregationValueVisitor {
+    level: Level,
+}
+
+impl<'de> DeserializeSeed<'de> for AggregationValueVisitor {
+    type Value = AggregationValue;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for AggregationValueVisitor {
+    type Value = AggregationValue;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a JSON value")
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E> {
+        Ok(AggregationValue(Value::Bool(v)))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(AggregationValue(Value::from(v)))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(AggregationValue(Value::from(v)))
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(AggregationValue(Number::from_f64(v).map_or(Value::Null, Value::Number)))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(AggregationValue(Value::String(v.to_string())))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E> {
+        Ok(AggregationValue(Value::String(v)))
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E> {
+        Ok(AggregationValue(Value::Null))
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E> {
+        Ok(AggregationValue(Value::Null))
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+    {
+        let level = match self.level {
+            Level::Buckets => Level::Bucket,
+            level => level,
+        };
+        let mut values = Vec::new();
+        while let Some(AggregationValue(value)) = seq.next_element_seed(AggregationValueVisitor { level })? {
+            values.push(value);
+        }
+        Ok(AggregationValue(Value::Array(values)))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+    {
+        if let Level::Buckets = self.level {
+            let mut buckets = Vec::new();
+            while let Some(key) = map.next_key::<String>()? {
+                let AggregationValue(mut bucket) = map.next_value_seed(AggregationValueVisitor { level: Level::Bucket })?;
+                if let Value::Object(bucket) = &mut bucket {
+                    bucket.entry("key").or_insert(Value::String(key));
+                }
+                buckets.push(bucket);
+            }
+            return Ok(AggregationValue(Value::Array(buckets)));
+        }
+
+        let mut values = Map::new();
+        while let Some(key) = map.next_key::<String>()? {
+            let value = match (self.level, key.as_str()) {
+                (Level::Body, "buckets") => map.next_value_seed(AggregationValueVisitor { level: Level::Buckets })?.0,
+                (Level::Body, key) if RAW_BODY_ENTRIES.contains(&key) => map.next_value()?,
+                // `composite` keys are user data
+                (Level::Bucket, "key") => map.next_value()?,
+                // other objects are sub-aggregations
+                _ => map.next_value_seed(AggregationValueVisitor { level: Level::Body })?.0,
+            };
+            values.insert(key, value);
+        }
+        Ok(AggregationValue(Value::Object(values)))
+    }
+}
+
+impl<'de> Deserialize<'de> for Bucket {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        let map = Map::<String, Value>::deserialize(deserializer)?;
+        Bucket::from_map(map, None).map_err(serde::de::Error::custom)
+    }
+}
+
+impl Bucket {
+
+    fn from_map(mut map: Map<String, Value>, keyed: Option<String>) -> Result<Self, serde_json::Error> {
+        let doc_count = u64::deserialize(map.remove("doc_count").unwrap_or_default())?;
+        let key = map.remove("key")
+            .or_else(|| keyed.map(Value::String))
+            .unwrap_or_default();
+        let key_as_string = Option::<String>::deserialize(map.remove("key_as_string").unwrap_or_default())?;
+        let from = Option::<f64>::deserialize(map.remove("from").unwrap_or_default())?;
+        let to = Option::<f64>::deserialize(map.remove("to").unwrap_or_default())?;
+
+        // sub-aggregations are always objects, other entries are bucket metadata
+        let aggregations = map
+            .into_iter()
+            .filter(|(_, value)| value.is_object())
+            .collect();
+
+        Ok(Self {
+            key,
+            key_as_string,
+            doc_count,
+            from,
+            to,
+            aggregations: AggregationsResponse { aggregations },
+        })
+    }
+}
+
+/// `buckets` is an array, or an object when the aggregation is `keyed`
+fn deserialize_buckets<'de, D>(deserializer: D) -> Result<Vec<Bucket>, D::Error>
+    where
+        D: Deserializer<'de>,
+{
+    struct BucketsVisitor;
+
+    impl<'de> Visitor<'de> for BucketsVisitor {
+        type Value = Vec<Bucket>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("buckets as an array or an object")
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+        {
+            let mut buckets = Vec::new();
+            while let Some(bucket) = seq.next_element()? {
+                buckets.push(bucket);
+            }
+            Ok(buckets)
+        }
+
+        /// Entries are read one by one to keep the order of the response
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+        {
+            let mut buckets = Vec::new();
+            while let Some((key, bucket)) = map.next_entry::<String, Map<String, Value>>()? {
+                buckets.push(Bucket::from_map(bucket, Some(key)).map_err(serde::de::Error::custom)?);
+            }
+            Ok(buckets)
+        }
+    }
+
+    deserializer.deserialize_any(BucketsVisitor)
+}
@@ -0,0 +1,112 @@
+use serde::{Deserialize, Deserializer};
+use serde::de::Error;
+use serde_json::Map;
+use crate::response::error::ErrorCause;
+
+/// Response of the `_bulk` endpoint
+/// https://opensearch.org/docs/latest/api-reference/document-apis/bulk/#response
+///
+/// Examples
+/// ```
+/// use serde_json::json;
+/// use os_query_builder_rs::response::bulk::BulkResponse;
+///
+/// let response: BulkResponse = serde_json::from_value(json!({
+///     "took": 11,
+///     "errors": true,
+///     "items": [
+///         { "index": { "_index": "products", "_id": "1", "_version": 1, "result": "created", "status": 201 } },
+///         { "create": {
+///             "_index": "products", "_id": "2", "status": 409,
+///             "error": { "type": "version_conflict_engine_exception", "reason": "document already exists" }
+///         } }
+///     ]
+/// })).unwrap();
+///
+/// assert_eq!(response.successes().count(), 1);
+/// assert_eq!(response.failures().next().unwrap().error.as_ref().unwrap().error_type, "version_conflict_engine_exception");
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+pub struct BulkResponse {
+    pub took: u64,
+    /// Whether any item failed
+    pub errors: bool,
+    pub items: Vec<BulkItem>,
+}
+
+impl BulkResponse {
+    pub fn successes(&self) -> impl Iterator<Item = &BulkItem> {
+        self.items.iter().filter(|x| x.is_success())
+    }
+
+    pub fn failures(&self) -> impl Iterator<Item = &BulkItem> {
+        self.items.iter().filter(|x| !x.is_success())
+    }
+}
+
+/// Result of one action, items are in the order of the actions of the request
+#[derive(Debug, Clone)]
+pub struct BulkItem {
+    /// `index`, `create`, `update` or `delete`
+    pub action: String,
+    pub index: String,
+    pub id: Option<String>,
+    pub status: u16,
+    /// `created`, `updated`, `deleted`, `not_found` or `noop`
+    pub result: Option<String>,
+    pub version: Option<u64>,
+    pub seq_no: Option<u64>,
+    pub primary_term: Option<u64>,
+    pub error: Option<ErrorCause>,
+}
+
+impl BulkItem {
+    pub fn is_success(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct BulkItemValue {
+    #[serde(rename = "_index")]
+    index: String,
+    #[serde(rename = "_id", default)]
+    id: Option<String>,
+    status: u16,
+    #[serde(default)]
+    result: Option<String>,
+    #[serde(rename = "_version", default)]
+    version: Option<u64>,
+    #[serde(rename = "_seq_no", default)]
+    seq_no: Option<u64>,
+    #[serde(rename = "_primary_term", default)]
+    primary_term: Option<u64>,
+    #[serde(default)]
+    error: Option<ErrorCause>,
+}
+
+impl<'de> Deserialize<'de> for BulkItem {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        let map = Map::deserialize(deserializer)?;
+        if map.len() != 1 {
+            return Err(D::Error::custom(format!("expected one action in a bulk item, found {}", map.len())));
+        }
+        let (action, value) = map.into_iter().next().expect("one action");
+        let value = BulkItemValue::deserialize(value).map_err(D::Error::custom)?;
+
+        Ok(Self {
+            action,
+            index: value.index,
+            id: value.id,
+            status: value.status,
+            result: value.result,
+            version: value.version,
+            seq_no: value.seq_no,
+            primary_term: value.primary_term,
+            error: value.error,
+        })
+    }
+}
@@ -0,0 +1,95 @@
+use serde::Deserialize;
+use serde_json::Value;
+use crate::response::error::ErrorCause;
+use crate::response::search::Shards;
+
+/// Response of the `_count` endpoint
+/// https://opensearch.org/docs/latest/api-reference/count/#response
+#[derive(Debug, Clone, Deserialize)]
+pub struct CountResponse {
+    pub count: u64,
+    #[serde(rename = "_shards")]
+    pub shards: Shards,
+}
+
+/// Response of `_delete_by_query`, `_update_by_query` and `_reindex` when waiting for completion,
+/// also the `status` of their task
+/// https://opensearch.org/docs/latest/api-reference/document-apis/update-by-query/#response
+///
+/// Examples
+/// ```
+/// use serde_json::json;
+/// use os_query_builder_rs::response::by_query::BulkByScrollResponse;
+///
+/// let response: BulkByScrollResponse = serde_json::from_value(json!({
+///     "took": 31, "timed_out": false, "total": 3, "deleted": 3, "batches": 1,
+///     "version_conflicts": 0, "noops": 0, "retries": { "bulk": 0, "search": 0 },
+///     "throttled_millis": 0, "requests_per_second": -1.0, "throttled_until_millis": 0, "failures": []
+/// })).unwrap();
+///
+/// assert_eq!(response.deleted, 3);
+/// assert!(response.failures.is_empty());
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct BulkByScrollResponse {
+    pub took: Option<u64>,
+    pub timed_out: Option<bool>,
+    pub total: u64,
+    pub created: u64,
+    pub updated: u64,
+    pub deleted: u64,
+    pub batches: u64,
+    pub version_conflicts: u64,
+    pub noops: u64,
+    pub retries: Retries,
+    pub throttled_millis: u64,
+    /// `-1` when unthrottled
+    pub requests_per_second: f64,
+    pub throttled_until_millis: u64,
+    /// Search and bulk failures that stopped the operation
+    pub failures: Vec<Value>,
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(default)]
+pub struct Retries {
+    pub bulk: u64,
+    pub search: u64,
+}
+
+/// Response of an operation started with `wait_for_completion=false`
+#[derive(Debug, Clone, Deserialize)]
+pub struct TaskResponse {
+    /// `<node>:<id>`, for the `_tasks` endpoint
+    pub task: String,
+}
+
+/// Response of `GET _tasks/<task>`
+/// https://opensearch.org/docs/latest/api-reference/tasks/#get-a-task
+#[derive(Debug, Clone, Deserialize)]
+pub struct TaskStatusResponse {
+    pub completed: bool,
+    pub task: TaskInfo,
+    /// Set once the task completed successfully
+    #[serde(default)]
+    pub response: Option<BulkByScrollResponse>,
+    #[serde(default)]
+    pub error: Option<ErrorCause>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TaskInfo {
+    pub node: String,
+    pub id: u64,
+    pub action: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Progress of the operation so far
+    #[serde(default)]
+    pub status: Option<BulkByScrollResponse>,
+    #[serde(default)]
+    pub running_time_in_nanos: Option<u64>,
+    #[serde(default)]
+    pub cancellable: Option<bool>,
+}
@@ -0,0 +1,14 @@
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Error object of a failed item or request
+/// https://opensearch.org/docs/latest/api-reference/document-apis/bulk/#response
+#[derive(Debug, Clone, Deserialize)]
+pub struct ErrorCause {
+    #[serde(rename = "type")]
+    pub error_type: String,
+    #[serde(default)]
+    pub reason: Option<String>,
+    #[serde(default)]
+    pub caused_by: Option<Value>,
+}
@@ -0,0 +1,102 @@
+use serde::{Deserialize, Deserializer};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use crate::response::error::ErrorCause;
+
+/// Response of the `_simulate` endpoint of ingest pipelines, `T` is the type of the processed `_source`
+/// https://opensearch.org/docs/latest/ingest-pipelines/simulate-ingest/#response
+///
+/// Examples
+/// ```
+/// use serde_json::json;
+/// use os_query_builder_rs::response::ingest::SimulateResponse;
+///
+/// let response: SimulateResponse = serde_json::from_value(json!({
+///     "docs": [
+///         { "doc": { "_index": "_index", "_id": "_id", "_source": { "brand": "fiat" }, "_ingest": { "timestamp": "2024-05-01T10:00:00Z" } } },
+///         { "error": { "type": "illegal_argument_exception", "reason": "field [brand] not present as part of path [brand]" } }
+///     ]
+/// })).unwrap();
+///
+/// assert_eq!(response.docs[0].doc.as_ref().unwrap().source["brand"], "fiat");
+/// assert_eq!(response.docs[1].error.as_ref().unwrap().error_type, "illegal_argument_exception");
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+#[serde(bound = "T: DeserializeOwned")]
+pub struct SimulateResponse<T = Value> {
+    /// Results in the order of the sample documents
+    #[serde(deserialize_with = "deserialize_results")]
+    pub docs: Vec<SimulateResult<T>>,
+}
+
+/// Result of a sample document, with `processor_results` instead of `doc` when `verbose` is set
+#[derive(Debug, Clone, Deserialize)]
+#[serde(bound = "T: DeserializeOwned")]
+pub struct SimulateResult<T = Value> {
+    #[serde(default)]
+    pub doc: Option<SimulatedDocument<T>>,
+    #[serde(default)]
+    pub error: Option<ErrorCause>,
+    #[serde(default)]
+    pub processor_results: Vec<ProcessorResult<T>>,
+}
+
+impl<T> Default for SimulateResult<T> {
+    fn default() -> Self {
+        Self {
+            doc: None,
+            error: None,
+            processor_results: Vec::new(),
+        }
+    }
+}
+
+impl<T> SimulateResult<T> {
+    /// The document was dropped by a `drop` processor
+    pub fn is_dropped(&self) -> bool {
+        self.doc.is_none() && self.error.is_none() && self.processor_results.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(bound = "T: DeserializeOwned")]
+pub struct SimulatedDocument<T = Value> {
+    #[serde(rename = "_index", default)]
+    pub index: Option<String>,
+    #[serde(rename = "_id", default)]
+    pub id: Option<String>,
+    #[serde(rename = "_source")]
+    pub source: T,
+    /// Ingest metadata, e.g. `timestamp`
+    #[serde(rename = "_ingest", default)]
+    pub ingest: Option<Value>,
+}
+
+/// Outcome of one processor in a verbose simulation
+#[derive(Debug, Clone, Deserialize)]
+#[serde(bound = "T: DeserializeOwned")]
+pub struct ProcessorResult<T = Value> {
+    #[serde(default)]
+    pub processor_type: Option<String>,
+    #[serde(default)]
+    pub tag: Option<String>,
+    /// `success`, `error`, `error_ignored`, `skipped` or `dropped`
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub doc: Option<SimulatedDocument<T>>,
+    #[serde(default)]
+    pub error: Option<ErrorCause>,
+    #[serde(default)]
+    pub ignored_error: Option<Value>,
+}
+
+/// Dropped documents come back as `null`
+fn deserialize_results<'de, D, T>(deserializer: D) -> Result<Vec<SimulateResult<T>>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: DeserializeOwned,
+{
+    let results = Vec::<Option<SimulateResult<T>>>::deserialize(deserializer)?;
+    Ok(results.into_iter().map(Option::unwrap_or_default).collect())
+}
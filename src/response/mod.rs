@@ -0,0 +1,9 @@
+pub mod search;
+pub mod aggregations;
+pub mod error;
+pub mod bulk;
+pub mod msearch;
+pub mod by_query;
+pub mod ingest;
+pub mod search_template;
+pub mod scroll;
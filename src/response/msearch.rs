@@ -0,0 +1,116 @@
+use serde::{Deserialize, Deserializer};
+use serde::de::{DeserializeOwned, IgnoredAny};
+use serde_json::Value;
+use serde_json::value::RawValue;
+use crate::response::error::ErrorCause;
+use crate::response::search::SearchResponse;
+
+/// Response of the `_msearch` endpoint, `T` is the type of the `_source` of documents
+/// https://opensearch.org/docs/latest/api-reference/multi-search/#example-response
+///
+/// Examples
+/// ```
+/// use serde_json::json;
+/// use os_query_builder_rs::response::msearch::MultiSearchResponse;
+///
+/// let response: MultiSearchResponse = serde_json::from_value(json!({
+///     "took": 5,
+///     "responses": [
+///         {
+///             "took": 3, "timed_out": false, "status": 200,
+///             "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+///             "hits": { "total": { "value": 0, "relation": "eq" }, "max_score": null, "hits": [] }
+///         },
+///         { "error": { "type": "index_not_found_exception", "reason": "no such index [cars]" }, "status": 404 }
+///     ]
+/// })).unwrap();
+///
+/// assert_eq!(response.responses[0].as_ref().unwrap().total(), Some(0));
+/// assert_eq!(response.responses[1].as_ref().unwrap_err().status(), Some(404));
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+#[serde(bound = "T: DeserializeOwned")]
+pub struct MultiSearchResponse<T = Value> {
+    #[serde(default)]
+    pub took: Option<u64>,
+    /// Result of each search, in the order of the request
+    #[serde(deserialize_with = "deserialize_responses")]
+    pub responses: Vec<Result<SearchResponse<T>, MultiSearchError>>,
+}
+
+impl<T> MultiSearchResponse<T> {
+    pub fn successes(&self) -> impl Iterator<Item = &SearchResponse<T>> {
+        self.responses.iter().filter_map(|x| x.as_ref().ok())
+    }
+
+    pub fn failures(&self) -> impl Iterator<Item = &MultiSearchError> {
+        self.responses.iter().filter_map(|x| x.as_ref().err())
+    }
+}
+
+/// Search of a multi-search request that failed
+#[derive(Debug, Clone)]
+pub enum MultiSearchError {
+    /// The cluster responded with an error
+    Search {
+        status: u16,
+        error: ErrorCause,
+    },
+    /// The response of the search could not be parsed, e.g. a `_source` does not match `T`
+    Parse(String),
+}
+
+impl MultiSearchError {
+    /// HTTP status of a failed search
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            MultiSearchError::Search { status, .. } => Some(*status),
+            MultiSearchError::Parse(_) => None,
+        }
+    }
+
+    pub fn error(&self) -> Option<&ErrorCause> {
+        match self {
+            MultiSearchError::Search { error, .. } => Some(error),
+            MultiSearchError::Parse(_) => None,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct SearchError {
+    status: u16,
+    error: ErrorCause,
+}
+
+/// Checks for the `error` entry without parsing the rest of the search
+#[derive(Deserialize)]
+struct ErrorProbe {
+    #[serde(default)]
+    error: Option<IgnoredAny>,
+}
+
+/// Each search is parsed on its own, so one invalid response does not fail the others.
+/// Searches are kept as raw JSON until then, `Value` would lose the order of `keyed` buckets.
+fn deserialize_responses<'de, D, T>(deserializer: D) -> Result<Vec<Result<SearchResponse<T>, MultiSearchError>>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: DeserializeOwned,
+{
+    let responses = Vec::<Box<RawValue>>::deserialize(deserializer)?
+        .into_iter()
+        .map(|raw| parse_response(raw.get()))
+        .collect();
+
+    Ok(responses)
+}
+
+fn parse_response<T: DeserializeOwned>(raw: &str) -> Result<SearchResponse<T>, MultiSearchError> {
+    let parse_error = |error: serde_json::Error| MultiSearchError::Parse(error.to_string());
+
+    if serde_json::from_str::<ErrorProbe>(raw).map_err(parse_error)?.error.is_some() {
+        let SearchError { status, error } = serde_json::from_str(raw).map_err(parse_error)?;
+        return Err(MultiSearchError::Search { status, error });
+    }
+    serde_json::from_str(raw).map_err(parse_error)
+}
@@ -0,0 +1,11 @@
+use serde::Deserialize;
+
+/// Response of `DELETE _search/scroll`, the pages of a scroll are
+/// [`SearchResponse`](crate::response::search::SearchResponse)s
+/// https://opensearch.org/docs/latest/api-reference/scroll/#clear-scroll
+#[derive(Debug, Clone, Deserialize)]
+pub struct ClearScrollResponse {
+    pub succeeded: bool,
+    /// Number of freed search contexts
+    pub num_freed: u64,
+}
@@ -0,0 +1,150 @@
+use std::collections::HashMap;
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use crate::response::aggregations::AggregationsResponse;
+
+/// Response of the `_search` endpoint, `T` is the type of the `_source` of documents
+/// https://opensearch.org/docs/latest/api-reference/search/#response-body
+///
+/// Examples
+/// ```
+/// use serde::Deserialize;
+/// use serde_json::json;
+/// use os_query_builder_rs::response::search::SearchResponse;
+///
+/// #[derive(Deserialize)]
+/// struct Product {
+///     brand: String
+/// }
+///
+/// let response: SearchResponse<Product> = serde_json::from_value(json!({
+///     "took": 3,
+///     "timed_out": false,
+///     "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+///     "hits": {
+///         "total": { "value": 1, "relation": "eq" },
+///         "max_score": 1.0,
+///         "hits": [
+///             { "_index": "products", "_id": "1", "_score": 1.0, "_source": { "brand": "FIAT" } }
+///         ]
+///     }
+/// })).unwrap();
+///
+/// assert_eq!(response.hits.hits[0].source.as_ref().unwrap().brand, "FIAT");
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+#[serde(bound = "T: DeserializeOwned")]
+pub struct SearchResponse<T = Value> {
+    pub took: u64,
+    pub timed_out: bool,
+    #[serde(rename = "_shards")]
+    pub shards: Shards,
+    pub hits: Hits<T>,
+    #[serde(default)]
+    pub aggregations: Option<AggregationsResponse>,
+    #[serde(rename = "_scroll_id", default)]
+    pub scroll_id: Option<String>,
+    #[serde(default)]
+    pub profile: Option<Value>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Shards {
+    pub total: u64,
+    pub successful: u64,
+    #[serde(default)]
+    pub skipped: u64,
+    pub failed: u64,
+    #[serde(default)]
+    pub failures: Vec<Value>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(bound = "T: DeserializeOwned")]
+pub struct Hits<T = Value> {
+    /// Missing when `track_total_hits` is `false`
+    #[serde(default)]
+    pub total: Option<TotalHits>,
+    #[serde(default)]
+    pub max_score: Option<f64>,
+    pub hits: Vec<Hit<T>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TotalHits {
+    pub value: u64,
+    pub relation: TotalHitsRelation,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TotalHitsRelation {
+    /// Exact count
+    Eq,
+    /// Lower bound, the count stopped at `track_total_hits`
+    Gte,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(bound = "T: DeserializeOwned")]
+pub struct Hit<T = Value> {
+    #[serde(rename = "_index")]
+    pub index: String,
+    #[serde(rename = "_id")]
+    pub id: String,
+    /// `null` when results are sorted by a field without `track_scores`
+    #[serde(rename = "_score", default)]
+    pub score: Option<f64>,
+    /// Missing when `_source` is disabled
+    #[serde(rename = "_source", default)]
+    pub source: Option<T>,
+    #[serde(rename = "_version", default)]
+    pub version: Option<i64>,
+    #[serde(rename = "_seq_no", default)]
+    pub seq_no: Option<i64>,
+    #[serde(rename = "_primary_term", default)]
+    pub primary_term: Option<i64>,
+    #[serde(rename = "_routing", default)]
+    pub routing: Option<String>,
+    #[serde(default)]
+    pub fields: Option<HashMap<String, Vec<Value>>>,
+    #[serde(default)]
+    pub highlight: Option<HashMap<String, Vec<String>>>,
+    #[serde(default)]
+    pub sort: Option<Vec<Value>>,
+    /// Inner hits keep untyped `_source`, it is a part of the document, not a document itself
+    #[serde(default)]
+    pub inner_hits: Option<HashMap<String, InnerHits>>,
+    #[serde(default)]
+    pub matched_queries: Option<Vec<String>>,
+    #[serde(rename = "_explanation", default)]
+    pub explanation: Option<Explanation>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct InnerHits {
+    pub hits: Hits<Value>,
+}
+
+/// https://opensearch.org/docs/latest/api-reference/explain/
+#[derive(Debug, Clone, Deserialize)]
+pub struct Explanation {
+    pub value: f64,
+    pub description: String,
+    #[serde(default)]
+    pub details: Vec<Explanation>,
+}
+
+impl<T> SearchResponse<T> {
+
+    /// `_source` of all hits, hits without a source are skipped
+    pub fn sources(&self) -> impl Iterator<Item=&T> {
+        self.hits.hits.iter().filter_map(|hit| hit.source.as_ref())
+    }
+
+    /// `hits.total.value`, `None` when total hits are not tracked
+    pub fn total(&self) -> Option<u64> {
+        self.hits.total.as_ref().map(|total| total.value)
+    }
+}
@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Response of `_render/template`, `_search/template` responds with a
+/// [`SearchResponse`](crate::response::search::SearchResponse)
+/// https://opensearch.org/docs/latest/api-reference/search-template/#render-the-template
+#[derive(Debug, Clone, Deserialize)]
+pub struct RenderTemplateResponse {
+    /// Search body produced by the template
+    pub template_output: Value,
+}
@@ -0,0 +1,221 @@
+use std::fmt;
+use std::marker::PhantomData;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use crate::model::Query;
+use crate::response::error::ErrorCause;
+use crate::response::search::{Hit, SearchResponse};
+use crate::scroll::request::{ClearScroll, ScrollRequest};
+
+/// Request the transport of a [`ScrollIterator`] sends
+#[derive(Debug, Clone, Copy)]
+pub enum ScrollCall<'a> {
+    /// `POST <index>/_search?scroll=<scroll>`, the first page
+    Search { index: &'a str, scroll: &'a str, query: &'a Query },
+    /// `POST _search/scroll`, the next pages
+    Scroll(&'a ScrollRequest),
+    /// `DELETE _search/scroll`, once the scroll is exhausted, failed or dropped
+    Clear(&'a ClearScroll),
+}
+
+impl ScrollCall<'_> {
+    pub fn method(&self) -> &'static str {
+        match self {
+            ScrollCall::Search { .. } | ScrollCall::Scroll(_) => "POST",
+            ScrollCall::Clear(_) => "DELETE",
+        }
+    }
+
+    pub fn path(&self) -> String {
+        match self {
+            ScrollCall::Search { index, scroll, .. } => format!("{index}/_search?scroll={scroll}"),
+            ScrollCall::Scroll(_) | ScrollCall::Clear(_) => "_search/scroll".into(),
+        }
+    }
+
+    pub fn body(&self) -> Value {
+        let body = match self {
+            ScrollCall::Search { query, .. } => serde_json::to_value(query),
+            ScrollCall::Scroll(request) => serde_json::to_value(request),
+            ScrollCall::Clear(request) => serde_json::to_value(request),
+        };
+        body.expect("scroll body serializes")
+    }
+}
+
+/// Pages through all hits of a query with the scroll API
+/// https://opensearch.org/docs/latest/api-reference/scroll/
+///
+/// The transport sends a [`ScrollCall`] and returns the response body. Each item is a page of hits,
+/// the page size is the `size` of the query. The iterator ends after the first empty page or error,
+/// the scroll is cleared then or when the iterator is dropped.
+///
+/// Examples
+/// ```
+/// use serde_json::{json, Value};
+/// use os_query_builder_rs::model::Query;
+/// use os_query_builder_rs::scroll::iterator::{ScrollCall, ScrollIterator};
+///
+/// let mut pages = vec![json!([]), json!([{ "_index": "products", "_id": "1", "_source": { "brand": "FIAT" } }])];
+/// let transport = |call: ScrollCall| -> Result<Value, String> {
+///     match call {
+///         ScrollCall::Clear(_) => Ok(json!({ "succeeded": true, "num_freed": 1 })),
+///         _ => Ok(json!({
+///             "_scroll_id": "scroll-1", "took": 1, "timed_out": false,
+///             "_shards": { "total": 1, "successful": 1, "failed": 0 },
+///             "hits": { "hits": pages.pop().unwrap() }
+///         })),
+///     }
+/// };
+///
+/// let hits = ScrollIterator::<Value, _, _>::new("products", Query::new().size(100usize), "1m", transport)
+///     .collect::<Result<Vec<_>, _>>()
+///     .unwrap();
+///
+/// assert_eq!(hits.len(), 1);
+/// assert_eq!(hits[0][0].source, Some(json!({ "brand": "FIAT" })));
+/// ```
+pub struct ScrollIterator<T, E, F>
+    where F: FnMut(ScrollCall<'_>) -> Result<Value, E>
+{
+    index: String,
+    query: Query,
+    scroll: String,
+    transport: F,
+    scroll_id: Option<String>,
+    started: bool,
+    done: bool,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T, E, F> ScrollIterator<T, E, F>
+    where F: FnMut(ScrollCall<'_>) -> Result<Value, E>
+{
+    /// `scroll` is how long the search context is kept between pages, e.g. `1m`
+    pub fn new<I: Into<String>, S: Into<String>>(index: I, query: Query, scroll: S, transport: F) -> Self {
+        Self {
+            index: index.into(),
+            query,
+            scroll: scroll.into(),
+            transport,
+            scroll_id: None,
+            started: false,
+            done: false,
+            marker: PhantomData,
+        }
+    }
+
+    /// Id of the open scroll, `None` before the first page and once it is cleared
+    pub fn scroll_id(&self) -> Option<&str> {
+        self.scroll_id.as_deref()
+    }
+
+    /// Clears the scroll now, the iterator yields no more pages
+    pub fn clear(&mut self) -> Result<(), E> {
+        self.done = true;
+        match self.scroll_id.take() {
+            Some(scroll_id) => (self.transport)(ScrollCall::Clear(&ClearScroll::from(scroll_id))).map(|_| ()),
+            None => Ok(()),
+        }
+    }
+
+    fn send(&mut self) -> Result<Value, E> {
+        if !self.started {
+            self.started = true;
+            return (self.transport)(ScrollCall::Search {
+                index: &self.index,
+                scroll: &self.scroll,
+                query: &self.query,
+            });
+        }
+
+        let scroll_id = self.scroll_id.clone().unwrap_or_default();
+        (self.transport)(ScrollCall::Scroll(&ScrollRequest::new(self.scroll.as_str(), scroll_id)))
+    }
+}
+
+impl<T, E, F> ScrollIterator<T, E, F>
+    where T: DeserializeOwned,
+          F: FnMut(ScrollCall<'_>) -> Result<Value, E>
+{
+    fn next_page(&mut self) -> Result<Vec<Hit<T>>, ScrollError<E>> {
+        let mut body = self.send().map_err(ScrollError::Transport)?;
+
+        // stored before parsing, so the context is cleared even when the page is invalid
+        if let Some(scroll_id) = body.get("_scroll_id").and_then(Value::as_str) {
+            self.scroll_id = Some(scroll_id.to_string());
+        }
+
+        if let Some(error) = body.get_mut("error") {
+            let error = serde_json::from_value(error.take()).map_err(ScrollError::Response)?;
+            return Err(ScrollError::Server(error));
+        }
+
+        let response: SearchResponse<T> = serde_json::from_value(body).map_err(ScrollError::Response)?;
+        if self.scroll_id.is_none() {
+            return Err(ScrollError::MissingScrollId);
+        }
+        Ok(response.hits.hits)
+    }
+}
+
+impl<T, E, F> Iterator for ScrollIterator<T, E, F>
+    where T: DeserializeOwned,
+          F: FnMut(ScrollCall<'_>) -> Result<Value, E>
+{
+    type Item = Result<Vec<Hit<T>>, ScrollError<E>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        match self.next_page() {
+            Ok(hits) if hits.is_empty() => {
+                let _ = self.clear();
+                None
+            }
+            Ok(hits) => Some(Ok(hits)),
+            Err(error) => {
+                let _ = self.clear();
+                Some(Err(error))
+            }
+        }
+    }
+}
+
+impl<T, E, F> Drop for ScrollIterator<T, E, F>
+    where F: FnMut(ScrollCall<'_>) -> Result<Value, E>
+{
+    fn drop(&mut self) {
+        let _ = self.clear();
+    }
+}
+
+#[derive(Debug)]
+pub enum ScrollError<E> {
+    /// The transport failed to send a request
+    Transport(E),
+    /// The cluster responded with an error
+    Server(ErrorCause),
+    /// The response is not a search response
+    Response(serde_json::Error),
+    /// The first page has no `_scroll_id`
+    MissingScrollId,
+}
+
+impl<E: fmt::Display> fmt::Display for ScrollError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScrollError::Transport(error) => write!(f, "scroll request failed: {error}"),
+            ScrollError::Server(error) => match &error.reason {
+                Some(reason) => write!(f, "scroll failed with {}: {reason}", error.error_type),
+                None => write!(f, "scroll failed with {}", error.error_type),
+            },
+            ScrollError::Response(error) => write!(f, "invalid scroll response: {error}"),
+            ScrollError::MissingScrollId => write!(f, "search response has no scroll id"),
+        }
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> std::error::Error for ScrollError<E> {}
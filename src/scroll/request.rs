@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+
+/// Body of `POST _search/scroll`, fetches the next page of a scroll
+/// https://opensearch.org/docs/latest/api-reference/scroll/
+///
+/// Examples
+/// ```
+/// use serde_json::json;
+/// use os_query_builder_rs::scroll::request::ScrollRequest;
+///
+/// let request = ScrollRequest::new("1m", "DXF1ZXJ5QW5kRmV0Y2gBAAAAAAAAAAUWdmpUZDhnRFBUcWFtV21nMmFwUGJEQQ==");
+///
+/// assert_eq!(serde_json::to_value(request).unwrap(), json!({
+///     "scroll": "1m",
+///     "scroll_id": "DXF1ZXJ5QW5kRmV0Y2gBAAAAAAAAAAUWdmpUZDhnRFBUcWFtV21nMmFwUGJEQQ=="
+/// }));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScrollRequest {
+    scroll: String,
+    scroll_id: String,
+}
+
+impl ScrollRequest {
+    /// `scroll` is how long the search context is kept, e.g. `1m`
+    pub fn new<T: Into<String>, F: Into<String>>(scroll: T, scroll_id: F) -> Self {
+        Self {
+            scroll: scroll.into(),
+            scroll_id: scroll_id.into(),
+        }
+    }
+
+    pub fn get_scroll(&self) -> &str {
+        &self.scroll
+    }
+
+    pub fn get_scroll_id(&self) -> &str {
+        &self.scroll_id
+    }
+}
+
+/// Body of `DELETE _search/scroll`, frees the search contexts of scrolls
+/// https://opensearch.org/docs/latest/api-reference/scroll/#clear-scroll
+///
+/// Examples
+/// ```
+/// use serde_json::json;
+/// use os_query_builder_rs::scroll::request::ClearScroll;
+///
+/// let request = ClearScroll::new(["id-1", "id-2"]);
+///
+/// assert_eq!(serde_json::to_value(request).unwrap(), json!({ "scroll_id": ["id-1", "id-2"] }));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClearScroll {
+    scroll_id: Vec<String>,
+}
+
+impl ClearScroll {
+    pub fn new<F, T>(scroll_ids: F) -> Self
+        where T: Into<String>,
+              F: IntoIterator<Item = T>
+    {
+        Self {
+            scroll_id: scroll_ids.into_iter().map(|x| x.into()).collect(),
+        }
+    }
+
+    pub fn get_scroll_ids(&self) -> &[String] {
+        &self.scroll_id
+    }
+}
+
+impl From<String> for ClearScroll {
+    fn from(scroll_id: String) -> Self {
+        Self::new([scroll_id])
+    }
+}
+
+impl From<&str> for ClearScroll {
+    fn from(scroll_id: &str) -> Self {
+        Self::new([scroll_id])
+    }
+}
@@ -0,0 +1,3 @@
+pub mod source;
+pub mod request;
+pub mod mustache;
@@ -0,0 +1,277 @@
+use std::fmt;
+use serde_json::Value;
+
+/// Renders a mustache template like the `mustache` script engine of OpenSearch
+/// https://opensearch.org/docs/latest/api-reference/search-template/
+///
+/// Supported tags:
+/// - `{{name}}`, with JSON string escaping, and `{{{name}}}` or `{{&name}}` without escaping
+/// - dotted names such as `{{range.gte}}`, array indices such as `{{tags.0}}` and `{{.}}`
+/// - sections `{{#name}}...{{/name}}` iterating arrays, and inverted sections `{{^name}}...{{/name}}`,
+///   `null`, `false`, empty strings and empty arrays are falsy
+/// - comments `{{! ... }}`
+/// - the functions `{{#toJson}}name{{/toJson}}`, `{{#join}}name{{/join}}`,
+///   `{{#join delimiter='|'}}name{{/join delimiter='|'}}` and `{{#url}}...{{/url}}`
+///
+/// Examples
+/// ```
+/// use serde_json::json;
+/// use os_query_builder_rs::search_template::mustache::render;
+///
+/// let template = r#"{"query": {"term": {"brand": "{{brand}}"}}{{#size}}, "size": {{size}}{{/size}}}"#;
+///
+/// assert_eq!(render(template, &json!({ "brand": "FIAT", "size": 5 })).unwrap(),
+///            r#"{"query": {"term": {"brand": "FIAT"}}, "size": 5}"#);
+/// assert_eq!(render(template, &json!({ "brand": "say \"hi\"" })).unwrap(),
+///            r#"{"query": {"term": {"brand": "say \"hi\""}}}"#);
+/// ```
+pub fn render(template: &str, context: &Value) -> Result<String, MustacheError> {
+    let nodes = Parser { template, position: 0 }.parse(None)?.0;
+    let mut out = String::new();
+    render_nodes(&nodes, &mut vec![context], &mut out);
+    Ok(out)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MustacheError {
+    position: usize,
+    message: String,
+}
+
+impl MustacheError {
+    fn new<T: Into<String>>(position: usize, message: T) -> Self {
+        Self {
+            position,
+            message: message.into(),
+        }
+    }
+
+    /// Byte offset of the error in the template
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for MustacheError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}", self.message, self.position)
+    }
+}
+
+impl std::error::Error for MustacheError {}
+
+#[derive(Debug)]
+enum Node<'a> {
+    Text(&'a str),
+    Variable { name: &'a str, escape: bool },
+    Section { name: &'a str, inverted: bool, children: Vec<Node<'a>>, body: &'a str },
+}
+
+struct Parser<'a> {
+    template: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    /// Parses nodes until the closing tag of `section`, returns them with the raw body
+    fn parse(&mut self, section: Option<(&'a str, usize)>) -> Result<(Vec<Node<'a>>, &'a str), MustacheError> {
+        let start = self.position;
+        let mut nodes = Vec::new();
+
+        loop {
+            let rest = &self.template[self.position..];
+            let Some(offset) = rest.find("{{") else {
+                if !rest.is_empty() {
+                    nodes.push(Node::Text(rest));
+                }
+                self.position = self.template.len();
+                return match section {
+                    Some((name, position)) => Err(MustacheError::new(position, format!("section `{name}` is not closed"))),
+                    None => Ok((nodes, &self.template[start..])),
+                };
+            };
+
+            if offset > 0 {
+                nodes.push(Node::Text(&rest[..offset]));
+            }
+            let tag_start = self.position + offset;
+            let (tag, end) = self.tag(tag_start)?;
+            self.position = end;
+
+            match tag.as_bytes().first() {
+                Some(b'!') => {}
+                Some(b'=') => return Err(MustacheError::new(tag_start, "changing delimiters is not supported")),
+                Some(b'#') | Some(b'^') => {
+                    let name = tag[1..].trim();
+                    let (children, body) = self.parse(Some((name, tag_start)))?;
+                    nodes.push(Node::Section { name, inverted: tag.starts_with('^'), children, body });
+                }
+                Some(b'/') => {
+                    let name = tag[1..].trim();
+                    return match section {
+                        Some((open, _)) if open == name => Ok((nodes, &self.template[start..tag_start])),
+                        Some((open, _)) => Err(MustacheError::new(tag_start, format!("expected closing tag of `{open}`, found `{name}`"))),
+                        None => Err(MustacheError::new(tag_start, format!("unexpected closing tag `{name}`"))),
+                    };
+                }
+                Some(b'&') => nodes.push(Node::Variable { name: tag[1..].trim(), escape: false }),
+                Some(b'{') => nodes.push(Node::Variable { name: tag[1..].trim(), escape: false }),
+                _ => nodes.push(Node::Variable { name: tag.trim(), escape: true }),
+            }
+        }
+    }
+
+    /// Content of the tag starting at `start` and the position after it
+    fn tag(&self, start: usize) -> Result<(&'a str, usize), MustacheError> {
+        let content_start = start + 2;
+        let triple = self.template[content_start..].starts_with('{');
+        let close = if triple { "}}}" } else { "}}" };
+
+        let Some(offset) = self.template[content_start..].find(close) else {
+            return Err(MustacheError::new(start, "tag is not closed"));
+        };
+        let tag = &self.template[content_start..content_start + offset];
+        if tag.trim_start_matches(['{', '#', '^', '/', '&', '!']).trim().is_empty() && !tag.starts_with('!') {
+            return Err(MustacheError::new(start, "tag is empty"));
+        }
+        Ok((tag, content_start + offset + close.len()))
+    }
+}
+
+fn render_nodes(nodes: &[Node<'_>], stack: &mut Vec<&Value>, out: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(text),
+            Node::Variable { name, escape } => {
+                if let Some(value) = lookup(name, stack) {
+                    let text = to_text(value);
+                    if *escape {
+                        escape_json(&text, out);
+                    } else {
+                        out.push_str(&text);
+                    }
+                }
+            }
+            Node::Section { name: "toJson", inverted: false, body, .. } => {
+                let value = lookup(body.trim(), stack).unwrap_or(&Value::Null);
+                out.push_str(&value.to_string());
+            }
+            Node::Section { name, inverted: false, body, .. } if is_join(name) => {
+                let delimiter = join_delimiter(name);
+                let text = match lookup(body.trim(), stack) {
+                    Some(Value::Array(values)) => values.iter().map(to_text).collect::<Vec<_>>().join(delimiter),
+                    Some(value) => to_text(value),
+                    None => String::new(),
+                };
+                escape_json(&text, out);
+            }
+            Node::Section { name: "url", inverted: false, children, .. } => {
+                let mut text = String::new();
+                render_nodes(children, stack, &mut text);
+                encode_url(&text, out);
+            }
+            Node::Section { name, inverted, children, .. } => {
+                let value = lookup(name, stack);
+                let truthy = value.is_some_and(is_truthy);
+
+                if *inverted {
+                    if !truthy {
+                        render_nodes(children, stack, out);
+                    }
+                } else if let Some(Value::Array(values)) = value {
+                    for value in values {
+                        stack.push(value);
+                        render_nodes(children, stack, out);
+                        stack.pop();
+                    }
+                } else if let Some(value) = value.filter(|x| is_truthy(x)) {
+                    stack.push(value);
+                    render_nodes(children, stack, out);
+                    stack.pop();
+                }
+            }
+        }
+    }
+}
+
+/// Resolves the first segment from the innermost context, then the rest from it
+fn lookup<'a>(name: &str, stack: &[&'a Value]) -> Option<&'a Value> {
+    if name == "." {
+        return stack.last().copied();
+    }
+
+    let mut segments = name.split('.');
+    let first = segments.next()?;
+    let mut value = stack.iter().rev().find_map(|context| child(context, first))?;
+    for segment in segments {
+        value = child(value, segment)?;
+    }
+    Some(value)
+}
+
+fn child<'a>(value: &'a Value, segment: &str) -> Option<&'a Value> {
+    match value {
+        Value::Object(map) => map.get(segment),
+        Value::Array(values) => segment.parse::<usize>().ok().and_then(|i| values.get(i)),
+        _ => None,
+    }
+}
+
+fn is_truthy(value: &Value) -> bool {
+    match value {
+        Value::Null => false,
+        Value::Bool(value) => *value,
+        Value::String(value) => !value.is_empty(),
+        Value::Array(values) => !values.is_empty(),
+        _ => true,
+    }
+}
+
+fn to_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(value) => value.clone(),
+        value => value.to_string(),
+    }
+}
+
+fn is_join(name: &str) -> bool {
+    name == "join" || name.starts_with("join delimiter=")
+}
+
+/// `join delimiter='x'` or `join delimiter="x"`, `,` by default
+fn join_delimiter(name: &str) -> &str {
+    name.strip_prefix("join delimiter=")
+        .map(|x| x.trim_matches(['\'', '"']))
+        .unwrap_or(",")
+}
+
+fn escape_json(text: &str, out: &mut String) {
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{8}' => out.push_str("\\b"),
+            '\u{c}' => out.push_str("\\f"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+}
+
+fn encode_url(text: &str, out: &mut String) {
+    for byte in text.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'*' => out.push(byte as char),
+            b' ' => out.push('+'),
+            byte => out.push_str(&format!("%{byte:02X}")),
+        }
+    }
+}
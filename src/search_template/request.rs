@@ -0,0 +1,91 @@
+use std::collections::BTreeMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::search_template::source::{TemplateError, TemplateSource};
+
+/// Body of `_search/template` and `_render/template`, runs a stored template by `id` or an inline `source`
+/// https://opensearch.org/docs/latest/api-reference/search-template/
+///
+/// Examples
+/// ```
+/// use serde_json::json;
+/// use os_query_builder_rs::search_template::request::SearchTemplateRequest;
+///
+/// let request = SearchTemplateRequest::stored("product-search")
+///             .param("brand", "FIAT")
+///             .param("size", 10);
+///
+/// assert_eq!(serde_json::to_value(request).unwrap(), json!({
+///     "id": "product-search",
+///     "params": { "brand": "FIAT", "size": 10 }
+/// }));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchTemplateRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<TemplateSource>,
+
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    params: BTreeMap<String, Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    explain: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    profile: Option<bool>,
+}
+
+impl SearchTemplateRequest {
+    /// Runs the template stored with `PUT _scripts/<id>`
+    pub fn stored<T: Into<String>>(id: T) -> Self {
+        Self {
+            id: Some(id.into()),
+            source: None,
+            params: BTreeMap::new(),
+            explain: None,
+            profile: None,
+        }
+    }
+
+    pub fn inline<T: Into<TemplateSource>>(source: T) -> Self {
+        Self {
+            id: None,
+            source: Some(source.into()),
+            params: BTreeMap::new(),
+            explain: None,
+            profile: None,
+        }
+    }
+
+    /// Adds a value of the template parameters
+    pub fn param<K: Into<String>, V: Into<Value>>(mut self, name: K, value: V) -> Self {
+        self.params.insert(name.into(), value.into());
+        self
+    }
+
+    pub fn explain(self, explain: bool) -> Self {
+        Self {
+            explain: Some(explain),
+            ..self
+        }
+    }
+
+    pub fn profile(self, profile: bool) -> Self {
+        Self {
+            profile: Some(profile),
+            ..self
+        }
+    }
+
+    pub fn params(&self) -> &BTreeMap<String, Value> {
+        &self.params
+    }
+
+    /// Renders the inline source with the parameters, `None` for stored templates
+    pub fn render(&self) -> Option<Result<Value, TemplateError>> {
+        self.source.as_ref().map(|source| source.render(&self.params))
+    }
+}
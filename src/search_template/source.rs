@@ -0,0 +1,177 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::model::Query;
+use crate::search_template::mustache::{render, MustacheError};
+
+/// `{{name}}`, a string placeholder for query values, e.g. `Term::new("brand", placeholder("brand"))`
+pub fn placeholder(name: &str) -> String {
+    format!("{{{{{name}}}}}")
+}
+
+/// `{{#toJson}}name{{/toJson}}`, a placeholder replaced by the JSON of the parameter,
+/// its quotes are removed by [`TemplateSource::from_query`]
+pub fn json_placeholder(name: &str) -> String {
+    format!("{{{{#toJson}}}}{name}{{{{/toJson}}}}")
+}
+
+/// Mustache source of a search template, a JSON object or a string when placeholders
+/// stand for numbers, booleans or whole JSON values
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum TemplateSource {
+    Text(String),
+    Json(Value),
+}
+
+impl TemplateSource {
+    /// Source from a query holding [`placeholder`] and [`json_placeholder`] values
+    ///
+    /// Examples
+    /// ```
+    /// use os_query_builder_rs::model::Query;
+    /// use os_query_builder_rs::search_template::source::{placeholder, TemplateSource};
+    /// use os_query_builder_rs::term::{range::Range, term::Term};
+    ///
+    /// let query = Query::new().query(Range::new().field("price").gte(placeholder("min_price")));
+    /// let source = TemplateSource::from_query(&query).unquote("min_price");
+    ///
+    /// assert_eq!(source, TemplateSource::Text(r#"{"query":{"range":{"price":{"gte":{{min_price}}}}}}"#.into()));
+    /// ```
+    pub fn from_query(query: &Query) -> Self {
+        let text = serde_json::to_string(query).expect("query serializes");
+        TemplateSource::Text(unquote_json_placeholders(&text))
+    }
+
+    /// Removes the quotes around `"{{name}}"`, for parameters that are numbers or booleans
+    pub fn unquote(self, name: &str) -> Self {
+        let quoted = Value::from(placeholder(name)).to_string();
+        TemplateSource::Text(self.to_text().replace(&quoted, &placeholder(name)))
+    }
+
+    /// Template as sent in the `source`
+    pub fn to_text(&self) -> String {
+        match self {
+            TemplateSource::Text(text) => text.clone(),
+            TemplateSource::Json(value) => value.to_string(),
+        }
+    }
+
+    /// Renders the template locally and parses the result, to compare it with a `Query` without a cluster
+    pub fn render(&self, params: &BTreeMap<String, Value>) -> Result<Value, TemplateError> {
+        let context = Value::Object(params.clone().into_iter().collect());
+        let text = render(&self.to_text(), &context).map_err(TemplateError::Mustache)?;
+        serde_json::from_str(&text).map_err(TemplateError::Json)
+    }
+}
+
+/// `"{{#toJson}}name{{/toJson}}"` to `{{#toJson}}name{{/toJson}}`
+fn unquote_json_placeholders(text: &str) -> String {
+    const START: &str = "\"{{#toJson}}";
+    const END: &str = "{{/toJson}}\"";
+
+    let mut out = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find(START) {
+        let Some(end) = rest[start..].find(END).map(|x| start + x) else {
+            break;
+        };
+        out.push_str(&rest[..start]);
+        out.push_str(&rest[start + 1..end + END.len() - 1]);
+        rest = &rest[end + END.len()..];
+    }
+    out.push_str(rest);
+    out
+}
+
+impl From<String> for TemplateSource {
+    fn from(text: String) -> Self {
+        TemplateSource::Text(text)
+    }
+}
+
+impl From<&str> for TemplateSource {
+    fn from(text: &str) -> Self {
+        TemplateSource::Text(text.into())
+    }
+}
+
+impl From<Value> for TemplateSource {
+    fn from(value: Value) -> Self {
+        TemplateSource::Json(value)
+    }
+}
+
+impl From<&Query> for TemplateSource {
+    fn from(query: &Query) -> Self {
+        TemplateSource::from_query(query)
+    }
+}
+
+/// Body of `PUT _scripts/<id>`, a search template stored in the cluster
+/// https://opensearch.org/docs/latest/api-reference/search-template/#save-and-execute-search-templates
+///
+/// Examples
+/// ```
+/// use serde_json::json;
+/// use os_query_builder_rs::model::Query;
+/// use os_query_builder_rs::search_template::source::{placeholder, StoredTemplate};
+/// use os_query_builder_rs::term::term::Term;
+///
+/// let template = StoredTemplate::new(&Query::new().query(Term::new("brand", placeholder("brand"))));
+///
+/// assert_eq!(serde_json::to_value(template).unwrap(), json!({
+///     "script": { "lang": "mustache", "source": r#"{"query":{"term":{"brand":{"value":"{{brand}}"}}}}"# }
+/// }));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoredTemplate {
+    script: StoredScript,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StoredScript {
+    lang: String,
+    source: TemplateSource,
+}
+
+impl StoredTemplate {
+    pub fn new<T: Into<TemplateSource>>(source: T) -> Self {
+        Self {
+            script: StoredScript {
+                lang: "mustache".into(),
+                source: source.into(),
+            },
+        }
+    }
+
+    pub fn source(&self) -> &TemplateSource {
+        &self.script.source
+    }
+}
+
+#[derive(Debug)]
+pub enum TemplateError {
+    Mustache(MustacheError),
+    /// The rendered template is not valid JSON
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TemplateError::Mustache(error) => write!(f, "invalid template: {error}"),
+            TemplateError::Json(error) => write!(f, "rendered template is not JSON: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for TemplateError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TemplateError::Mustache(error) => Some(error),
+            TemplateError::Json(error) => Some(error),
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::mapping::index_mapping::IndexMapping;
+use crate::misc::query_field::QueryField;
+use crate::misc::validate::{child, ValidationError, ValidationErrors};
+use crate::settings::{
+    analyzer::{Analyzer, Normalizer},
+    char_filter::CharFilter,
+    token_filter::TokenFilter,
+    tokenizer::Tokenizer
+};
+
+/// Analyzers available without configuration
+/// https://opensearch.org/docs/latest/analyzers/supported-analyzers/index/
+pub const BUILT_IN_ANALYZERS: &[&str] = &[
+    "standard", "simple", "whitespace", "stop", "keyword", "pattern", "fingerprint",
+    "arabic", "armenian", "basque", "bengali", "brazilian", "bulgarian", "catalan", "cjk", "czech",
+    "danish", "dutch", "english", "estonian", "finnish", "french", "galician", "german", "greek",
+    "hindi", "hungarian", "indonesian", "irish", "italian", "latvian", "lithuanian", "norwegian",
+    "persian", "portuguese", "romanian", "russian", "sorani", "spanish", "swedish", "thai", "turkish",
+];
+
+/// Normalizers available without configuration
+pub const BUILT_IN_NORMALIZERS: &[&str] = &["lowercase"];
+
+/// `analysis` section of the index settings, components are referenced by name
+/// https://opensearch.org/docs/latest/analyzers/index/
+///
+/// Names of analyzers are checked with [`Analysis::analyzer_name`] when building queries,
+/// or afterwards with [`Analysis::validate_query`]
+///
+/// Examples
+/// ```
+/// use os_query_builder_rs::full_text::r#match::Match;
+/// use os_query_builder_rs::settings::analysis::Analysis;
+/// use os_query_builder_rs::settings::analyzer::CustomAnalyzer;
+/// use os_query_builder_rs::settings::token_filter::{SynonymFilter, TokenFilter};
+///
+/// let analysis = Analysis::new()
+///             .filter("product_synonyms", TokenFilter::SynonymGraph(SynonymFilter::inline(["tv, television"])))
+///             .analyzer("product_search", CustomAnalyzer::new("standard").filter(["lowercase", "product_synonyms"]));
+///
+/// let query = Match::new().field("title").value("tv").analyzer(analysis.analyzer_name("product_search").unwrap());
+///
+/// assert!(analysis.analyzer_name("product_serach").is_err());
+/// assert!(analysis.validate_query(&query.into()).is_ok());
+/// ```
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Analysis {
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    analyzer: BTreeMap<String, Analyzer>,
+
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    tokenizer: BTreeMap<String, Tokenizer>,
+
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    filter: BTreeMap<String, TokenFilter>,
+
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    char_filter: BTreeMap<String, CharFilter>,
+
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    normalizer: BTreeMap<String, Normalizer>,
+}
+
+impl Analysis {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn analyzer<K: Into<String>, T: Into<Analyzer>>(mut self, name: K, analyzer: T) -> Self {
+        self.analyzer.insert(name.into(), analyzer.into());
+        self
+    }
+
+    pub fn tokenizer<K: Into<String>, T: Into<Tokenizer>>(mut self, name: K, tokenizer: T) -> Self {
+        self.tokenizer.insert(name.into(), tokenizer.into());
+        self
+    }
+
+    pub fn filter<K: Into<String>, T: Into<TokenFilter>>(mut self, name: K, filter: T) -> Self {
+        self.filter.insert(name.into(), filter.into());
+        self
+    }
+
+    pub fn char_filter<K: Into<String>, T: Into<CharFilter>>(mut self, name: K, char_filter: T) -> Self {
+        self.char_filter.insert(name.into(), char_filter.into());
+        self
+    }
+
+    pub fn normalizer<K: Into<String>, T: Into<Normalizer>>(mut self, name: K, normalizer: T) -> Self {
+        self.normalizer.insert(name.into(), normalizer.into());
+        self
+    }
+
+    /// Whether the analyzer is configured here or built in
+    pub fn has_analyzer(&self, name: &str) -> bool {
+        self.analyzer.contains_key(name) || BUILT_IN_ANALYZERS.contains(&name)
+    }
+
+    /// Whether the normalizer is configured here or built in
+    pub fn has_normalizer(&self, name: &str) -> bool {
+        self.normalizer.contains_key(name) || BUILT_IN_NORMALIZERS.contains(&name)
+    }
+
+    /// Name of a known analyzer, for the `analyzer` of queries and mappings
+    pub fn analyzer_name(&self, name: &str) -> Result<AnalyzerName, UnknownAnalyzer> {
+        if self.has_analyzer(name) {
+            Ok(AnalyzerName(name.to_string()))
+        } else {
+            Err(UnknownAnalyzer(name.to_string()))
+        }
+    }
+
+    /// Checks that analyzers of the query, e.g. `analyzer` of `match` or `quote_analyzer` of `query_string`, are known
+    ///
+    /// Examples
+    /// ```
+    /// use os_query_builder_rs::full_text::query_string::QueryString;
+    /// use os_query_builder_rs::settings::analysis::Analysis;
+    ///
+    /// let query = QueryString::new().query("tv").analyzer("product_serach");
+    /// let errors = Analysis::new().validate_query(&query.into()).unwrap_err();
+    ///
+    /// assert_eq!(errors.to_string(), "/query_string/analyzer: unknown analyzer `product_serach`");
+    /// ```
+    pub fn validate_query(&self, query: &QueryField) -> Result<(), ValidationErrors> {
+        let value = serde_json::to_value(query).expect("query serializes");
+        self.validate_value(&value)
+    }
+
+    /// Checks that analyzers and normalizers of the fields are known
+    pub fn validate_mapping(&self, mapping: &IndexMapping) -> Result<(), ValidationErrors> {
+        let value = serde_json::to_value(mapping).expect("mapping serializes");
+        self.validate_value(&value)
+    }
+
+    fn validate_value(&self, value: &Value) -> Result<(), ValidationErrors> {
+        let mut errors = Vec::new();
+        self.check_references("", value, &mut errors);
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationErrors(errors))
+        }
+    }
+
+    fn check_references(&self, path: &str, value: &Value, errors: &mut Vec<ValidationError>) {
+        match value {
+            Value::Object(map) => for (key, value) in map {
+                let path = child(path, key);
+                match (key.as_str(), value) {
+                    ("analyzer" | "search_analyzer" | "search_quote_analyzer" | "quote_analyzer", Value::String(name)) => {
+                        if !self.has_analyzer(name) {
+                            errors.push(ValidationError::new(path, UnknownAnalyzer(name.clone()).to_string()));
+                        }
+                    }
+                    ("normalizer", Value::String(name)) => {
+                        if !self.has_normalizer(name) {
+                            errors.push(ValidationError::new(path, format!("unknown normalizer `{name}`")));
+                        }
+                    }
+                    _ => self.check_references(&path, value, errors),
+                }
+            },
+            Value::Array(values) => for (i, value) in values.iter().enumerate() {
+                self.check_references(&child(path, i), value, errors);
+            },
+            _ => {}
+        }
+    }
+}
+
+/// Name of an analyzer known to an [`Analysis`], converts into the `String` taken by `analyzer` builders
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnalyzerName(String);
+
+impl AnalyzerName {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<AnalyzerName> for String {
+    fn from(name: AnalyzerName) -> Self {
+        name.0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownAnalyzer(pub String);
+
+impl fmt::Display for UnknownAnalyzer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown analyzer `{}`", self.0)
+    }
+}
+
+impl std::error::Error for UnknownAnalyzer {}
@@ -0,0 +1,173 @@
+use serde::{Deserialize, Serialize};
+use crate::settings::token_filter::Stopwords;
+
+/// Analyzer of the analysis settings, serialized with its `type`
+/// https://opensearch.org/docs/latest/analyzers/index/
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Analyzer {
+    Custom(CustomAnalyzer),
+    /// Configured built-in `standard` analyzer
+    Standard(StandardAnalyzer),
+}
+
+from_variant! {
+    Analyzer:
+    (CustomAnalyzer, Custom),
+    (StandardAnalyzer, Standard)
+}
+
+/// Char filters, then the tokenizer, then token filters, all by name
+/// https://opensearch.org/docs/latest/analyzers/custom-analyzer/
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomAnalyzer {
+    tokenizer: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    char_filter: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filter: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    position_increment_gap: Option<u32>,
+}
+
+impl CustomAnalyzer {
+    /// Built-in tokenizer such as `standard` or the name of a configured one
+    pub fn new<T: Into<String>>(tokenizer: T) -> Self {
+        Self {
+            tokenizer: tokenizer.into(),
+            char_filter: None,
+            filter: None,
+            position_increment_gap: None,
+        }
+    }
+
+    /// Names of character filters applied before the tokenizer
+    pub fn char_filter<F, T>(self, char_filter: F) -> Self
+        where
+            F: IntoIterator<Item=T>,
+            T: Into<String>
+    {
+        Self {
+            char_filter: Some(char_filter.into_iter().map(|x| x.into()).collect()),
+            ..self
+        }
+    }
+
+    /// Names of token filters applied in order
+    pub fn filter<F, T>(self, filter: F) -> Self
+        where
+            F: IntoIterator<Item=T>,
+            T: Into<String>
+    {
+        Self {
+            filter: Some(filter.into_iter().map(|x| x.into()).collect()),
+            ..self
+        }
+    }
+
+    /// Position gap between values of an array
+    pub fn position_increment_gap<T: Into<u32>>(self, position_increment_gap: T) -> Self {
+        Self {
+            position_increment_gap: Some(position_increment_gap.into()),
+            ..self
+        }
+    }
+}
+
+/// https://opensearch.org/docs/latest/analyzers/supported-analyzers/standard/
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct StandardAnalyzer {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_token_length: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stopwords: Option<Stopwords>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stopwords_path: Option<String>,
+}
+
+impl StandardAnalyzer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn max_token_length<T: Into<u32>>(self, max_token_length: T) -> Self {
+        Self {
+            max_token_length: Some(max_token_length.into()),
+            ..self
+        }
+    }
+
+    /// Predefined list such as `_english_` or custom words
+    pub fn stopwords<T: Into<Stopwords>>(self, stopwords: T) -> Self {
+        Self {
+            stopwords: Some(stopwords.into()),
+            ..self
+        }
+    }
+
+    /// File of stop words, relative to the config directory
+    pub fn stopwords_path<T: Into<String>>(self, stopwords_path: T) -> Self {
+        Self {
+            stopwords_path: Some(stopwords_path.into()),
+            ..self
+        }
+    }
+}
+
+/// Normalizer of `keyword` fields, like an analyzer without a tokenizer
+/// https://opensearch.org/docs/latest/analyzers/normalizers/
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Normalizer {
+    Custom(CustomNormalizer),
+}
+
+from_variant! {
+    Normalizer:
+    (CustomNormalizer, Custom)
+}
+
+/// Only filters working on single characters, such as `lowercase` or `asciifolding`, are allowed
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CustomNormalizer {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    char_filter: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filter: Option<Vec<String>>,
+}
+
+impl CustomNormalizer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Names of character filters
+    pub fn char_filter<F, T>(self, char_filter: F) -> Self
+        where
+            F: IntoIterator<Item=T>,
+            T: Into<String>
+    {
+        Self {
+            char_filter: Some(char_filter.into_iter().map(|x| x.into()).collect()),
+            ..self
+        }
+    }
+
+    /// Names of token filters
+    pub fn filter<F, T>(self, filter: F) -> Self
+        where
+            F: IntoIterator<Item=T>,
+            T: Into<String>
+    {
+        Self {
+            filter: Some(filter.into_iter().map(|x| x.into()).collect()),
+            ..self
+        }
+    }
+}
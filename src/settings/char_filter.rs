@@ -0,0 +1,118 @@
+use serde::{Deserialize, Serialize};
+
+/// Character filter of a custom analyzer or normalizer, serialized with its `type`
+/// https://opensearch.org/docs/latest/analyzers/character-filters/index/
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CharFilter {
+    HtmlStrip(HtmlStripCharFilter),
+    Mapping(MappingCharFilter),
+    PatternReplace(PatternReplaceCharFilter),
+}
+
+from_variant! {
+    CharFilter:
+    (HtmlStripCharFilter, HtmlStrip),
+    (MappingCharFilter, Mapping),
+    (PatternReplaceCharFilter, PatternReplace)
+}
+
+/// https://opensearch.org/docs/latest/analyzers/character-filters/html-character-filter/
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct HtmlStripCharFilter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    escaped_tags: Option<Vec<String>>,
+}
+
+impl HtmlStripCharFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tags that are kept
+    pub fn escaped_tags<F, T>(self, escaped_tags: F) -> Self
+        where
+            F: IntoIterator<Item=T>,
+            T: Into<String>
+    {
+        Self {
+            escaped_tags: Some(escaped_tags.into_iter().map(|x| x.into()).collect()),
+        }
+    }
+}
+
+/// Replaces strings, e.g. `ph => f`
+/// https://opensearch.org/docs/latest/analyzers/character-filters/mapping-character-filter/
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct MappingCharFilter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mappings: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mappings_path: Option<String>,
+}
+
+impl MappingCharFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inline rules as `key => value`
+    pub fn mappings<F, T>(self, mappings: F) -> Self
+        where
+            F: IntoIterator<Item=T>,
+            T: Into<String>
+    {
+        Self {
+            mappings: Some(mappings.into_iter().map(|x| x.into()).collect()),
+            ..self
+        }
+    }
+
+    /// File of rules, relative to the config directory
+    pub fn mappings_path<T: Into<String>>(self, mappings_path: T) -> Self {
+        Self {
+            mappings_path: Some(mappings_path.into()),
+            ..self
+        }
+    }
+}
+
+/// Replaces matches of a Java regular expression
+/// https://opensearch.org/docs/latest/analyzers/character-filters/pattern-replace-character-filter/
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PatternReplaceCharFilter {
+    pattern: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    replacement: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    flags: Option<String>,
+}
+
+impl PatternReplaceCharFilter {
+    pub fn new<T: Into<String>>(pattern: T) -> Self {
+        Self {
+            pattern: pattern.into(),
+            replacement: None,
+            flags: None,
+        }
+    }
+
+    /// Replacement, `$1` refers to capture groups
+    pub fn replacement<T: Into<String>>(self, replacement: T) -> Self {
+        Self {
+            replacement: Some(replacement.into()),
+            ..self
+        }
+    }
+
+    /// Java regex flags separated by `|`
+    pub fn flags<T: Into<String>>(self, flags: T) -> Self {
+        Self {
+            flags: Some(flags.into()),
+            ..self
+        }
+    }
+}
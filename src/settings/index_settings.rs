@@ -0,0 +1,100 @@
+use std::collections::BTreeMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::settings::analysis::Analysis;
+
+/// Settings of an index, the `settings` of the create-index body, names are relative to `index.`
+/// https://opensearch.org/docs/latest/install-and-configure/configuring-opensearch/index-settings/
+///
+/// Examples
+/// ```
+/// use serde_json::json;
+/// use os_query_builder_rs::settings::index_settings::IndexSettings;
+///
+/// let settings = IndexSettings::new()
+///             .number_of_shards(3u32)
+///             .number_of_replicas(1u32)
+///             .refresh_interval("30s")
+///             .setting("max_result_window", 50000);
+///
+/// assert_eq!(serde_json::to_value(settings).unwrap(), json!({
+///     "number_of_shards": 3,
+///     "number_of_replicas": 1,
+///     "refresh_interval": "30s",
+///     "max_result_window": 50000
+/// }));
+/// ```
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct IndexSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    number_of_shards: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    number_of_replicas: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    refresh_interval: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    knn: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    analysis: Option<Analysis>,
+
+    #[serde(flatten)]
+    other: BTreeMap<String, Value>,
+}
+
+impl IndexSettings {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fixed at index creation
+    pub fn number_of_shards<T: Into<u32>>(self, number_of_shards: T) -> Self {
+        Self {
+            number_of_shards: Some(number_of_shards.into()),
+            ..self
+        }
+    }
+
+    pub fn number_of_replicas<T: Into<u32>>(self, number_of_replicas: T) -> Self {
+        Self {
+            number_of_replicas: Some(number_of_replicas.into()),
+            ..self
+        }
+    }
+
+    /// How often new documents become searchable, e.g. `1s`, `-1` disables refreshes
+    pub fn refresh_interval<T: Into<String>>(self, refresh_interval: T) -> Self {
+        Self {
+            refresh_interval: Some(refresh_interval.into()),
+            ..self
+        }
+    }
+
+    /// Enables `knn_vector` fields with approximate search
+    pub fn knn(self, knn: bool) -> Self {
+        Self {
+            knn: Some(knn),
+            ..self
+        }
+    }
+
+    pub fn analysis<T: Into<Analysis>>(self, analysis: T) -> Self {
+        Self {
+            analysis: Some(analysis.into()),
+            ..self
+        }
+    }
+
+    /// Any other setting, e.g. `max_result_window`
+    pub fn setting<K: Into<String>, V: Into<Value>>(mut self, name: K, value: V) -> Self {
+        self.other.insert(name.into(), value.into());
+        self
+    }
+
+    pub fn get_analysis(&self) -> Option<&Analysis> {
+        self.analysis.as_ref()
+    }
+}
@@ -0,0 +1,19 @@
+/// `From` of the options structs for the variants of a tagged enum
+macro_rules! from_variant {
+    ($enum:ident: $(($struct:ident, $variant:ident)),*) => {
+        $(
+            impl From<$struct> for $enum {
+                fn from(value: $struct) -> Self {
+                    $enum::$variant(value)
+                }
+            }
+        )*
+    }
+}
+
+pub mod index_settings;
+pub mod analysis;
+pub mod analyzer;
+pub mod tokenizer;
+pub mod token_filter;
+pub mod char_filter;
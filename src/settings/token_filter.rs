@@ -0,0 +1,407 @@
+use serde::{Deserialize, Serialize};
+
+/// Token filter of a custom analyzer or normalizer, serialized with its `type`
+/// https://opensearch.org/docs/latest/analyzers/token-filters/index/
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TokenFilter {
+    Lowercase(LowercaseFilter),
+    Synonym(SynonymFilter),
+    SynonymGraph(SynonymFilter),
+    Stop(StopFilter),
+    Stemmer(StemmerFilter),
+    Shingle(ShingleFilter),
+    WordDelimiterGraph(WordDelimiterGraphFilter),
+    #[serde(rename = "asciifolding")]
+    AsciiFolding(AsciiFoldingFilter),
+}
+
+from_variant! {
+    TokenFilter:
+    (LowercaseFilter, Lowercase),
+    (StopFilter, Stop),
+    (StemmerFilter, Stemmer),
+    (ShingleFilter, Shingle),
+    (WordDelimiterGraphFilter, WordDelimiterGraph),
+    (AsciiFoldingFilter, AsciiFolding)
+}
+
+/// https://opensearch.org/docs/latest/analyzers/token-filters/lowercase/
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct LowercaseFilter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+}
+
+impl LowercaseFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `greek`, `irish` or `turkish` for language-specific rules
+    pub fn language<T: Into<String>>(self, language: T) -> Self {
+        Self {
+            language: Some(language.into()),
+        }
+    }
+}
+
+/// Options of `synonym` and `synonym_graph` filters, the type is the [`TokenFilter`] variant,
+/// `synonym_graph` handles multi-word synonyms at search time
+/// https://opensearch.org/docs/latest/analyzers/token-filters/synonym-graph/
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct SynonymFilter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    synonyms: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    synonyms_path: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    format: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expand: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lenient: Option<bool>,
+}
+
+impl SynonymFilter {
+    /// Inline rules in Solr format, e.g. `car, automobile` or `i-pod => ipod`
+    pub fn inline<F, T>(synonyms: F) -> Self
+        where
+            F: IntoIterator<Item=T>,
+            T: Into<String>
+    {
+        Self {
+            synonyms: Some(synonyms.into_iter().map(|x| x.into()).collect()),
+            ..Self::default()
+        }
+    }
+
+    /// Rules read from a file, relative to the config directory
+    pub fn path<T: Into<String>>(synonyms_path: T) -> Self {
+        Self {
+            synonyms_path: Some(synonyms_path.into()),
+            ..Self::default()
+        }
+    }
+
+    /// File of synonyms, relative to the config directory
+    pub fn synonyms_path<T: Into<String>>(self, synonyms_path: T) -> Self {
+        Self {
+            synonyms_path: Some(synonyms_path.into()),
+            ..self
+        }
+    }
+
+    /// `solr` or `wordnet`
+    pub fn format<T: Into<String>>(self, format: T) -> Self {
+        Self {
+            format: Some(format.into()),
+            ..self
+        }
+    }
+
+    /// Maps equivalent synonyms to all of them instead of the first one
+    pub fn expand(self, expand: bool) -> Self {
+        Self {
+            expand: Some(expand),
+            ..self
+        }
+    }
+
+    /// Skips invalid rules instead of failing
+    pub fn lenient(self, lenient: bool) -> Self {
+        Self {
+            lenient: Some(lenient),
+            ..self
+        }
+    }
+}
+
+/// https://opensearch.org/docs/latest/analyzers/token-filters/stop/
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct StopFilter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stopwords: Option<Stopwords>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stopwords_path: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ignore_case: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    remove_trailing: Option<bool>,
+}
+
+impl StopFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Predefined list such as `_english_` or custom words
+    pub fn stopwords<T: Into<Stopwords>>(self, stopwords: T) -> Self {
+        Self {
+            stopwords: Some(stopwords.into()),
+            ..self
+        }
+    }
+
+    /// File of stop words, relative to the config directory
+    pub fn stopwords_path<T: Into<String>>(self, stopwords_path: T) -> Self {
+        Self {
+            stopwords_path: Some(stopwords_path.into()),
+            ..self
+        }
+    }
+
+    pub fn ignore_case(self, ignore_case: bool) -> Self {
+        Self {
+            ignore_case: Some(ignore_case),
+            ..self
+        }
+    }
+
+    /// Keeps a trailing stop word, useful for completion
+    pub fn remove_trailing(self, remove_trailing: bool) -> Self {
+        Self {
+            remove_trailing: Some(remove_trailing),
+            ..self
+        }
+    }
+}
+
+/// Stop words of a filter or an analyzer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Stopwords {
+    /// Predefined list such as `_english_` or `_none_`
+    Predefined(String),
+    Words(Vec<String>),
+}
+
+impl From<&str> for Stopwords {
+    fn from(value: &str) -> Self {
+        Stopwords::Predefined(value.into())
+    }
+}
+
+impl From<String> for Stopwords {
+    fn from(value: String) -> Self {
+        Stopwords::Predefined(value)
+    }
+}
+
+impl<T: Into<String>> From<Vec<T>> for Stopwords {
+    fn from(value: Vec<T>) -> Self {
+        Stopwords::Words(value.into_iter().map(|x| x.into()).collect())
+    }
+}
+
+/// https://opensearch.org/docs/latest/analyzers/token-filters/stemmer/
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StemmerFilter {
+    language: String,
+}
+
+impl StemmerFilter {
+    /// e.g. `english`, `light_german` or `minimal_portuguese`
+    pub fn new<T: Into<String>>(language: T) -> Self {
+        Self {
+            language: language.into(),
+        }
+    }
+}
+
+/// Joins adjacent tokens into word n-grams
+/// https://opensearch.org/docs/latest/analyzers/token-filters/shingle/
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ShingleFilter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_shingle_size: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_shingle_size: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output_unigrams: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    token_separator: Option<String>,
+}
+
+impl ShingleFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn min_shingle_size<T: Into<u32>>(self, min_shingle_size: T) -> Self {
+        Self {
+            min_shingle_size: Some(min_shingle_size.into()),
+            ..self
+        }
+    }
+
+    pub fn max_shingle_size<T: Into<u32>>(self, max_shingle_size: T) -> Self {
+        Self {
+            max_shingle_size: Some(max_shingle_size.into()),
+            ..self
+        }
+    }
+
+    /// Also emits the single tokens
+    pub fn output_unigrams(self, output_unigrams: bool) -> Self {
+        Self {
+            output_unigrams: Some(output_unigrams),
+            ..self
+        }
+    }
+
+    pub fn token_separator<T: Into<String>>(self, token_separator: T) -> Self {
+        Self {
+            token_separator: Some(token_separator.into()),
+            ..self
+        }
+    }
+}
+
+/// Splits tokens on case changes, letter-number transitions and punctuation, e.g. `Wi-Fi` into `Wi` and `Fi`
+/// https://opensearch.org/docs/latest/analyzers/token-filters/word-delimiter-graph/
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct WordDelimiterGraphFilter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    preserve_original: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    generate_word_parts: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    generate_number_parts: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    split_on_case_change: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    split_on_numerics: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    catenate_words: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    catenate_numbers: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    catenate_all: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stem_english_possessive: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    protected_words: Option<Vec<String>>,
+}
+
+impl WordDelimiterGraphFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also emits the original token
+    pub fn preserve_original(self, preserve_original: bool) -> Self {
+        Self {
+            preserve_original: Some(preserve_original),
+            ..self
+        }
+    }
+
+    pub fn generate_word_parts(self, generate_word_parts: bool) -> Self {
+        Self {
+            generate_word_parts: Some(generate_word_parts),
+            ..self
+        }
+    }
+
+    pub fn generate_number_parts(self, generate_number_parts: bool) -> Self {
+        Self {
+            generate_number_parts: Some(generate_number_parts),
+            ..self
+        }
+    }
+
+    pub fn split_on_case_change(self, split_on_case_change: bool) -> Self {
+        Self {
+            split_on_case_change: Some(split_on_case_change),
+            ..self
+        }
+    }
+
+    pub fn split_on_numerics(self, split_on_numerics: bool) -> Self {
+        Self {
+            split_on_numerics: Some(split_on_numerics),
+            ..self
+        }
+    }
+
+    pub fn catenate_words(self, catenate_words: bool) -> Self {
+        Self {
+            catenate_words: Some(catenate_words),
+            ..self
+        }
+    }
+
+    pub fn catenate_numbers(self, catenate_numbers: bool) -> Self {
+        Self {
+            catenate_numbers: Some(catenate_numbers),
+            ..self
+        }
+    }
+
+    pub fn catenate_all(self, catenate_all: bool) -> Self {
+        Self {
+            catenate_all: Some(catenate_all),
+            ..self
+        }
+    }
+
+    pub fn stem_english_possessive(self, stem_english_possessive: bool) -> Self {
+        Self {
+            stem_english_possessive: Some(stem_english_possessive),
+            ..self
+        }
+    }
+
+    /// Tokens that are not split
+    pub fn protected_words<F, T>(self, protected_words: F) -> Self
+        where
+            F: IntoIterator<Item=T>,
+            T: Into<String>
+    {
+        Self {
+            protected_words: Some(protected_words.into_iter().map(|x| x.into()).collect()),
+            ..self
+        }
+    }
+}
+
+/// Converts characters to their ASCII equivalent, e.g. `é` to `e`
+/// https://opensearch.org/docs/latest/analyzers/token-filters/asciifolding/
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct AsciiFoldingFilter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    preserve_original: Option<bool>,
+}
+
+impl AsciiFoldingFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also emits the original token
+    pub fn preserve_original(self, preserve_original: bool) -> Self {
+        Self {
+            preserve_original: Some(preserve_original),
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use serde::{Deserialize, Serialize};
+
+/// Tokenizer of a custom analyzer, serialized with its `type`
+/// https://opensearch.org/docs/latest/analyzers/tokenizers/index/
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Tokenizer {
+    Standard(StandardTokenizer),
+    Ngram(NgramTokenizer),
+    EdgeNgram(NgramTokenizer),
+    Pattern(PatternTokenizer),
+    Keyword(KeywordTokenizer),
+}
+
+from_variant! {
+    Tokenizer:
+    (StandardTokenizer, Standard),
+    (PatternTokenizer, Pattern),
+    (KeywordTokenizer, Keyword)
+}
+
+/// https://opensearch.org/docs/latest/analyzers/tokenizers/standard/
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct StandardTokenizer {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_token_length: Option<u32>,
+}
+
+impl StandardTokenizer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Longer tokens are split
+    pub fn max_token_length<T: Into<u32>>(self, max_token_length: T) -> Self {
+        Self {
+            max_token_length: Some(max_token_length.into()),
+        }
+    }
+}
+
+/// Options of `ngram` and `edge_ngram` tokenizers, the type is the [`Tokenizer`] variant
+/// https://opensearch.org/docs/latest/analyzers/tokenizers/edge-n-gram/
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct NgramTokenizer {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    token_chars: Option<Vec<TokenChars>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_gram: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_gram: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    custom_token_chars: Option<String>,
+}
+
+impl NgramTokenizer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn min_gram<T: Into<u32>>(self, min_gram: T) -> Self {
+        Self {
+            min_gram: Some(min_gram.into()),
+            ..self
+        }
+    }
+
+    pub fn max_gram<T: Into<u32>>(self, max_gram: T) -> Self {
+        Self {
+            max_gram: Some(max_gram.into()),
+            ..self
+        }
+    }
+
+    /// Characters kept in tokens with [`TokenChars::Custom`]
+    pub fn custom_token_chars<T: Into<String>>(self, custom_token_chars: T) -> Self {
+        Self {
+            custom_token_chars: Some(custom_token_chars.into()),
+            ..self
+        }
+    }
+
+    /// Character classes kept in tokens, others split tokens
+    pub fn token_chars<F: IntoIterator<Item=TokenChars>>(self, token_chars: F) -> Self {
+        Self {
+            token_chars: Some(token_chars.into_iter().collect()),
+            ..self
+        }
+    }
+}
+
+/// https://opensearch.org/docs/latest/analyzers/tokenizers/edge-n-gram/#parameters
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenChars {
+    Letter,
+    Digit,
+    Whitespace,
+    Punctuation,
+    Symbol,
+    Custom,
+}
+
+/// Splits text on a Java regular expression
+/// https://opensearch.org/docs/latest/analyzers/tokenizers/pattern/
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PatternTokenizer {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pattern: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    flags: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<i32>,
+}
+
+impl PatternTokenizer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Separator pattern, `\\W+` by default
+    pub fn pattern<T: Into<String>>(self, pattern: T) -> Self {
+        Self {
+            pattern: Some(pattern.into()),
+            ..self
+        }
+    }
+
+    /// Java regex flags separated by `|`
+    pub fn flags<T: Into<String>>(self, flags: T) -> Self {
+        Self {
+            flags: Some(flags.into()),
+            ..self
+        }
+    }
+
+    /// Capture group emitted as token, `-1` splits on the pattern instead
+    pub fn group<T: Into<i32>>(self, group: T) -> Self {
+        Self {
+            group: Some(group.into()),
+            ..self
+        }
+    }
+}
+
+/// Emits the whole input as one token
+/// https://opensearch.org/docs/latest/analyzers/tokenizers/keyword/
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct KeywordTokenizer {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    buffer_size: Option<u32>,
+}
+
+impl KeywordTokenizer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn buffer_size<T: Into<u32>>(self, buffer_size: T) -> Self {
+        Self {
+            buffer_size: Some(buffer_size.into()),
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::template::index_template::Template;
+
+/// Body of the `_component_template` endpoint, a building block of index templates
+/// https://opensearch.org/docs/latest/im-plugin/index-templates/#composable-index-templates
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComponentTemplate {
+    template: Template,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<u64>,
+
+    #[serde(rename = "_meta", skip_serializing_if = "Option::is_none")]
+    meta: Option<Value>,
+}
+
+impl ComponentTemplate {
+    pub fn new<T: Into<Template>>(template: T) -> Self {
+        Self {
+            template: template.into(),
+            version: None,
+            meta: None,
+        }
+    }
+
+    pub fn version<T: Into<u64>>(self, version: T) -> Self {
+        Self {
+            version: Some(version.into()),
+            ..self
+        }
+    }
+
+    /// Arbitrary metadata, not used by OpenSearch
+    pub fn meta<T: Into<Value>>(self, meta: T) -> Self {
+        Self {
+            meta: Some(meta.into()),
+            ..self
+        }
+    }
+}
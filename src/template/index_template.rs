@@ -0,0 +1,183 @@
+use std::collections::BTreeMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::alias::definition::Alias;
+use crate::mapping::index_mapping::IndexMapping;
+use crate::settings::index_settings::IndexSettings;
+
+/// Body of the `_index_template` endpoint, applied to new indices matching the patterns
+/// https://opensearch.org/docs/latest/im-plugin/index-templates/
+///
+/// Examples
+/// ```
+/// use serde_json::json;
+/// use os_query_builder_rs::mapping::index_mapping::IndexMapping;
+/// use os_query_builder_rs::mapping::text::KeywordField;
+/// use os_query_builder_rs::template::index_template::{IndexTemplate, Template};
+///
+/// let template = IndexTemplate::new(["logs-*"])
+///             .priority(100u64)
+///             .composed_of(["logs-settings"])
+///             .template(Template::new().mappings(IndexMapping::new().property("service", KeywordField::new())));
+///
+/// assert_eq!(serde_json::to_value(template).unwrap(), json!({
+///     "index_patterns": ["logs-*"],
+///     "template": { "mappings": { "properties": { "service": { "type": "keyword" } } } },
+///     "composed_of": ["logs-settings"],
+///     "priority": 100
+/// }));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexTemplate {
+    index_patterns: Vec<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    template: Option<Template>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    composed_of: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    priority: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<u64>,
+
+    #[serde(rename = "_meta", skip_serializing_if = "Option::is_none")]
+    meta: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data_stream: Option<DataStream>,
+}
+
+impl IndexTemplate {
+    pub fn new<F, T>(index_patterns: F) -> Self
+        where
+            F: IntoIterator<Item=T>,
+            T: Into<String>
+    {
+        Self {
+            index_patterns: index_patterns.into_iter().map(|x| x.into()).collect(),
+            template: None,
+            composed_of: None,
+            priority: None,
+            version: None,
+            meta: None,
+            data_stream: None,
+        }
+    }
+
+    /// Settings, mappings and aliases, merged over the ones of `composed_of`
+    pub fn template<T: Into<Template>>(self, template: T) -> Self {
+        Self {
+            template: Some(template.into()),
+            ..self
+        }
+    }
+
+    /// Component templates merged in order
+    pub fn composed_of<F, T>(self, composed_of: F) -> Self
+        where
+            F: IntoIterator<Item=T>,
+            T: Into<String>
+    {
+        Self {
+            composed_of: Some(composed_of.into_iter().map(|x| x.into()).collect()),
+            ..self
+        }
+    }
+
+    /// The template with the highest priority applies when several match
+    pub fn priority<T: Into<u64>>(self, priority: T) -> Self {
+        Self {
+            priority: Some(priority.into()),
+            ..self
+        }
+    }
+
+    pub fn version<T: Into<u64>>(self, version: T) -> Self {
+        Self {
+            version: Some(version.into()),
+            ..self
+        }
+    }
+
+    /// Arbitrary metadata, not used by OpenSearch
+    pub fn meta<T: Into<Value>>(self, meta: T) -> Self {
+        Self {
+            meta: Some(meta.into()),
+            ..self
+        }
+    }
+
+    /// Matching names create data streams instead of indices
+    pub fn data_stream<T: Into<DataStream>>(self, data_stream: T) -> Self {
+        Self {
+            data_stream: Some(data_stream.into()),
+            ..self
+        }
+    }
+}
+
+/// Settings, mappings and aliases of an index or component template
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Template {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    settings: Option<IndexSettings>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mappings: Option<IndexMapping>,
+
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    aliases: BTreeMap<String, Alias>,
+}
+
+impl Template {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn settings<T: Into<IndexSettings>>(self, settings: T) -> Self {
+        Self {
+            settings: Some(settings.into()),
+            ..self
+        }
+    }
+
+    pub fn mappings<T: Into<IndexMapping>>(self, mappings: T) -> Self {
+        Self {
+            mappings: Some(mappings.into()),
+            ..self
+        }
+    }
+
+    pub fn alias<K: Into<String>, T: Into<Alias>>(mut self, name: K, alias: T) -> Self {
+        self.aliases.insert(name.into(), alias.into());
+        self
+    }
+}
+
+/// https://opensearch.org/docs/latest/im-plugin/data-streams/
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct DataStream {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timestamp_field: Option<TimestampField>,
+}
+
+impl DataStream {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Date field of the documents, `@timestamp` by default
+    pub fn timestamp_field<T: Into<String>>(self, name: T) -> Self {
+        Self {
+            timestamp_field: Some(TimestampField { name: name.into() }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TimestampField {
+    name: String,
+}
@@ -0,0 +1,2 @@
+pub mod index_template;
+pub mod component_template;
@@ -1,6 +1,9 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use crate::misc::validate::{check_field, Validate, ValidationError};
+use crate::misc::visit::{Visitor, VisitorMut, Walk};
 
-#[derive(Debug, Default, Clone, Serialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct Exists {
     field: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -24,4 +27,24 @@ impl Exists {
             ..self
         }
     }
+}
+
+impl Validate for Exists {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        check_field(Some(&self.field), path, errors);
+    }
+}
+
+impl Walk for Exists {
+    fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        visitor.visit_field(&self.field);
+        if let Some(boost) = self.boost {
+            visitor.visit_boost(boost);
+        }
+    }
+
+    fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        visitor.visit_field_mut(&mut self.field);
+        visitor.visit_boost_mut(&mut self.boost);
+    }
 }
\ No newline at end of file
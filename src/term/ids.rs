@@ -1,7 +1,10 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use crate::term::term_type::TermType;
+use crate::misc::validate::{Validate, ValidationError};
+use crate::misc::visit::{Visitor, VisitorMut, Walk};
 
-#[derive(Debug, Default, Clone, Serialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct IDs {
     values: Vec<TermType>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -30,4 +33,20 @@ impl IDs {
         }
     }
 
+}
+
+impl Validate for IDs {
+    fn validate_at(&self, _path: &str, _errors: &mut Vec<ValidationError>) {}
+}
+
+impl Walk for IDs {
+    fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        if let Some(boost) = self.boost {
+            visitor.visit_boost(boost);
+        }
+    }
+
+    fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        visitor.visit_boost_mut(&mut self.boost);
+    }
 }
\ No newline at end of file
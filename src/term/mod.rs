@@ -1,6 +1,7 @@
 pub mod terms;
+#[allow(clippy::module_inception)]
 pub mod term;
-mod term_type;
+pub(crate) mod term_type;
 pub mod terms_set;
 pub mod prefix;
 pub mod ids;
@@ -1,10 +1,15 @@
 use serde::{
+    Deserialize,
+    Deserializer,
     Serialize,
     Serializer,
     ser::SerializeMap
 };
 use crate::misc::relation::Relation;
 use crate::term::term_type::TermType;
+use crate::misc::field_entry::{deserialize_field_entry, FieldValue};
+use crate::misc::validate::{check_field, Validate, ValidationError};
+use crate::misc::visit::{Visitor, VisitorMut, Walk};
 
 #[derive(Debug, Default, Clone)]
 pub struct Range {
@@ -12,7 +17,8 @@ pub struct Range {
     value: RangeValue
 }
 
-#[derive(Debug, Default, Clone, Serialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 struct RangeValue {
     #[serde(skip_serializing_if = "Option::is_none")]
     gt: Option<TermType>,
@@ -44,6 +50,21 @@ impl Serialize for Range {
     }
 }
 
+impl<'de> Deserialize<'de> for Range {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        let (field, value) = deserialize_field_entry(deserializer)?;
+        Ok(Self {
+            field: Some(field),
+            value
+        })
+    }
+}
+
+impl FieldValue for RangeValue {}
+
 impl Range {
     pub fn new() -> Self {
         Self::default()
@@ -134,3 +155,27 @@ impl Range {
         }
     }
 }
+
+impl Validate for Range {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        check_field(self.field.as_deref(), path, errors);
+    }
+}
+
+impl Walk for Range {
+    fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        if let Some(field) = &self.field {
+            visitor.visit_field(field);
+        }
+        if let Some(boost) = self.value.boost {
+            visitor.visit_boost(boost);
+        }
+    }
+
+    fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        if let Some(field) = &mut self.field {
+            visitor.visit_field_mut(field);
+        }
+        visitor.visit_boost_mut(&mut self.value.boost);
+    }
+}
\ No newline at end of file
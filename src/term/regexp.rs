@@ -1,4 +1,6 @@
 use serde::{
+    Deserialize,
+    Deserializer,
     Serialize,
     Serializer,
     ser::SerializeMap
@@ -7,6 +9,10 @@ use crate::misc::{
     rewrite::Rewrite,
     regexp_flag::RegexpFlag
 };
+use serde_json::Value;
+use crate::misc::field_entry::{deserialize_field_entry, FieldValue};
+use crate::misc::validate::{check_field, child, Validate, ValidationError};
+use crate::misc::visit::{Visitor, VisitorMut, Walk};
 
 #[derive(Debug, Default, Clone)]
 pub struct Regexp {
@@ -14,7 +20,8 @@ pub struct Regexp {
     value: RegexpValue
 }
 
-#[derive(Debug, Default, Clone, Serialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 struct RegexpValue {
     value: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -40,6 +47,28 @@ impl Serialize for Regexp {
     }
 }
 
+impl<'de> Deserialize<'de> for Regexp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        let (field, value) = deserialize_field_entry(deserializer)?;
+        Ok(Self {
+            field: Some(field),
+            value
+        })
+    }
+}
+
+impl FieldValue for RegexpValue {
+    fn from_short(value: Value) -> Option<Self> {
+        value.as_str().map(|value| Self {
+            value: value.into(),
+            ..Self::default()
+        })
+    }
+}
+
 
 impl Regexp {
     pub fn new() -> Self {
@@ -112,4 +141,32 @@ impl Regexp {
             ..self
         }
     }
+}
+
+impl Validate for Regexp {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        check_field(self.field.as_deref(), path, errors);
+        if self.value.value.is_empty() {
+            let path = child(path, self.field.as_deref().unwrap_or_default());
+            errors.push(ValidationError::new(child(&path, "value"), "value is missing"));
+        }
+    }
+}
+
+impl Walk for Regexp {
+    fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        if let Some(field) = &self.field {
+            visitor.visit_field(field);
+        }
+        if let Some(boost) = self.value.boost {
+            visitor.visit_boost(boost);
+        }
+    }
+
+    fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        if let Some(field) = &mut self.field {
+            visitor.visit_field_mut(field);
+        }
+        visitor.visit_boost_mut(&mut self.value.boost);
+    }
 }
\ No newline at end of file
@@ -1,5 +1,9 @@
-use serde::{Serialize, Serializer, ser::SerializeMap};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, ser::SerializeMap};
 use super::term_type::TermType;
+use serde_json::Value;
+use crate::misc::field_entry::{deserialize_field_entry, FieldValue};
+use crate::misc::validate::{check_field, Validate, ValidationError};
+use crate::misc::visit::{Visitor, VisitorMut, Walk};
 
 #[derive(Debug, Clone)]
 pub struct Term {   
@@ -7,7 +11,8 @@ pub struct Term {
     value: TermInner,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 struct TermInner {   
     value: TermType,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -53,6 +58,17 @@ impl Term {
     }
 }
 
+impl Term {
+
+    /// Field and value of the term without `boost` and `case_insensitive`
+    pub(crate) fn as_plain(&self) -> Option<(&str, &TermType)> {
+        match self.value {
+            TermInner { boost: None, case_insensitive: None, .. } => Some((&self.field, &self.value.value)),
+            _ => None,
+        }
+    }
+}
+
 impl Serialize for Term {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
         where
@@ -62,4 +78,47 @@ impl Serialize for Term {
         state.serialize_entry(&self.field, &self.value)?;
         state.end()
     }
+}
+
+impl<'de> Deserialize<'de> for Term {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        let (field, value) = deserialize_field_entry(deserializer)?;
+        Ok(Self {
+            field,
+            value
+        })
+    }
+}
+
+impl FieldValue for TermInner {
+    fn from_short(value: Value) -> Option<Self> {
+        TermType::deserialize(value).ok().map(|value| Self {
+            value,
+            boost: None,
+            case_insensitive: None
+        })
+    }
+}
+
+impl Validate for Term {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        check_field(Some(&self.field), path, errors);
+    }
+}
+
+impl Walk for Term {
+    fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        visitor.visit_field(&self.field);
+        if let Some(boost) = self.value.boost {
+            visitor.visit_boost(boost);
+        }
+    }
+
+    fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        visitor.visit_field_mut(&mut self.field);
+        visitor.visit_boost_mut(&mut self.value.boost);
+    }
 }
\ No newline at end of file
@@ -1,4 +1,6 @@
-use serde::Serialize;
+use std::fmt;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde::de::{Error, Visitor};
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(untagged)]
@@ -6,7 +8,8 @@ pub enum TermType {
     String(String),
     Int(i128),
     UInt(u128),
-    Float(f64)
+    Float(f64),
+    Bool(bool)
 }
 
 macro_rules! from_types {
@@ -41,5 +44,59 @@ from_types! {
     (usize, UInt),
     
     (f32, Float),
-    (f64, Float)
+    (f64, Float),
+
+    (bool, Bool)
+}
+
+impl<'de> Deserialize<'de> for TermType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        struct TermTypeVisitor;
+
+        impl Visitor<'_> for TermTypeVisitor {
+            type Value = TermType;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a string, a number or a boolean")
+            }
+
+            fn visit_bool<E: Error>(self, v: bool) -> Result<Self::Value, E> {
+                Ok(TermType::Bool(v))
+            }
+
+            fn visit_i64<E: Error>(self, v: i64) -> Result<Self::Value, E> {
+                Ok(TermType::Int(v.into()))
+            }
+
+            fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+                // serialized integers are read back as `Int`, like `TermType::from(1)`
+                Ok(TermType::Int(v.into()))
+            }
+
+            fn visit_i128<E: Error>(self, v: i128) -> Result<Self::Value, E> {
+                Ok(TermType::Int(v))
+            }
+
+            fn visit_u128<E: Error>(self, v: u128) -> Result<Self::Value, E> {
+                Ok(TermType::UInt(v))
+            }
+
+            fn visit_f64<E: Error>(self, v: f64) -> Result<Self::Value, E> {
+                Ok(TermType::Float(v))
+            }
+
+            fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(TermType::String(v.into()))
+            }
+
+            fn visit_string<E: Error>(self, v: String) -> Result<Self::Value, E> {
+                Ok(TermType::String(v))
+            }
+        }
+
+        deserializer.deserialize_any(TermTypeVisitor)
+    }
 }
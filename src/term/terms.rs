@@ -1,6 +1,10 @@
 use std::collections::HashMap;
-use serde::Serialize;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde::de::Error;
+use serde_json::{Map, Value};
 use super::term_type::TermType;
+use crate::misc::validate::{check_field, Validate, ValidationError};
+use crate::misc::visit::{Visitor, VisitorMut, Walk};
 
 #[derive(Debug, Default, Clone, Serialize)]
 pub struct Terms {
@@ -17,7 +21,8 @@ pub struct Terms {
     boost: Option<f64>
 }
 
-#[derive(Debug, Default, Clone, Serialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct TermsLookup {
     index: String,
     id: String,
@@ -50,7 +55,7 @@ impl Terms {
     {
 
         let mut terms = HashMap::with_capacity(1);
-        terms.insert(field_name.into(), values.into());
+        terms.insert(field_name.into(), values);
 
         Self {
             terms_query: None,
@@ -68,6 +73,37 @@ impl Terms {
 }
 
 
+impl<'de> Deserialize<'de> for Terms {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        let mut map = Map::<String, Value>::deserialize(deserializer)?;
+        let boost = map.remove("boost")
+            .map(f64::deserialize)
+            .transpose()
+            .map_err(D::Error::custom)?;
+
+        if map.len() != 1 {
+            return Err(D::Error::custom(format!("expected a single field, found {}", map.len())));
+        }
+
+        let (field, value) = map.into_iter().next().expect("map has one entry");
+        let terms = match value {
+            Value::Object(_) => Self::new_with_terms_lookup(
+                field,
+                TermsLookup::deserialize(value).map_err(D::Error::custom)?
+            ),
+            _ => Self::new_with_terms_query(
+                field,
+                Vec::<TermType>::deserialize(value).map_err(D::Error::custom)?
+            ),
+        };
+
+        Ok(Self { boost, ..terms })
+    }
+}
+
 impl TermsLookup {
 
     pub fn new<T,F,X>(index: T, id: F, path: X) -> Self 
@@ -89,4 +125,48 @@ impl TermsLookup {
             ..self
         }
     }
+}
+
+impl Validate for Terms {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        let fields = self.terms_query.iter().flat_map(|x| x.keys())
+            .chain(self.terms_lookup.iter().flat_map(|x| x.keys()));
+
+        for field in fields {
+            check_field(Some(field), path, errors);
+        }
+    }
+}
+
+impl Walk for Terms {
+    fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        let fields = self.terms_query.iter().flat_map(|x| x.keys())
+            .chain(self.terms_lookup.iter().flat_map(|x| x.keys()));
+
+        for field in fields {
+            visitor.visit_field(field);
+        }
+        if let Some(boost) = self.boost {
+            visitor.visit_boost(boost);
+        }
+    }
+
+    fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        if let Some(terms) = self.terms_query.take() {
+            self.terms_query = Some(rename_keys(terms, visitor));
+        }
+        if let Some(terms) = self.terms_lookup.take() {
+            self.terms_lookup = Some(rename_keys(terms, visitor));
+        }
+        visitor.visit_boost_mut(&mut self.boost);
+    }
+}
+
+fn rename_keys<T, V: VisitorMut + ?Sized>(map: HashMap<String, T>, visitor: &mut V) -> HashMap<String, T> {
+    map.into_iter()
+        .map(|(mut field, value)| {
+            visitor.visit_field_mut(&mut field);
+            (field, value)
+        })
+        .collect()
 }
\ No newline at end of file
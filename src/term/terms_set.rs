@@ -1,13 +1,17 @@
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde::ser::SerializeMap;
 use crate::misc::script::Script;
+use crate::misc::field_entry::{deserialize_field_entry, FieldValue};
+use crate::misc::validate::{check_field, child, Validate, ValidationError};
+use crate::misc::visit::{Visitor, VisitorMut, Walk};
 
 #[derive(Debug, Default, Clone)]
 pub struct TermsSet {
     field: Option<String>,
     value: TermsSetValue
 }
-#[derive(Debug, Default, Clone, Serialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct TermsSetValue {
 
     terms: Vec<String>,
@@ -90,4 +94,57 @@ impl Serialize for TermsSet {
         state.serialize_entry(self.field.as_deref().unwrap_or_default(), &self.value)?;
         state.end()
     }
+}
+
+impl<'de> Deserialize<'de> for TermsSet {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        let (field, value) = deserialize_field_entry(deserializer)?;
+        Ok(Self {
+            field: Some(field),
+            value
+        })
+    }
+}
+
+impl FieldValue for TermsSetValue {}
+
+impl Validate for TermsSet {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        check_field(self.field.as_deref(), path, errors);
+
+        let path = child(path, self.field.as_deref().unwrap_or_default());
+        if self.value.terms.is_empty() {
+            errors.push(ValidationError::new(child(&path, "terms"), "terms are empty"));
+        }
+        if self.value.minimum_should_match_field.is_none() && self.value.minimum_should_match_script.is_none() {
+            errors.push(ValidationError::new(path, "minimum_should_match_field or minimum_should_match_script is missing"));
+        }
+    }
+}
+
+impl Walk for TermsSet {
+    fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        if let Some(field) = &self.field {
+            visitor.visit_field(field);
+        }
+        if let Some(field) = &self.value.minimum_should_match_field {
+            visitor.visit_field(field);
+        }
+        if let Some(boost) = self.value.boost {
+            visitor.visit_boost(boost);
+        }
+    }
+
+    fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        if let Some(field) = &mut self.field {
+            visitor.visit_field_mut(field);
+        }
+        if let Some(field) = &mut self.value.minimum_should_match_field {
+            visitor.visit_field_mut(field);
+        }
+        visitor.visit_boost_mut(&mut self.value.boost);
+    }
 }
\ No newline at end of file
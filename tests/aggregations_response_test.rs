@@ -0,0 +1,278 @@
+use serde::Deserialize;
+use serde_json::json;
+use os_query_builder_rs::response::aggregations::{AggregationError, AggregationsResponse, BucketAggregation};
+use os_query_builder_rs::response::search::SearchResponse;
+
+#[derive(Debug, Deserialize)]
+struct Product {
+    name: String,
+}
+
+#[test]
+fn metric_aggregations_test() {
+    let aggregations: AggregationsResponse = serde_json::from_value(json!({
+        "avg_price": {
+            "value": 12.5
+        },
+        "max_price": {
+            "value": null
+        },
+        "price_stats": {
+            "count": 4,
+            "min": 1.0,
+            "max": 30.0,
+            "avg": 12.5,
+            "sum": 50.0
+        },
+        "load_time": {
+            "values": {
+                "50.0": 120.0,
+                "99.0": 840.5,
+                "99.0_as_string": "840.5"
+            }
+        },
+        "load_time_list": {
+            "values": [
+                { "key": 50.0, "value": 120.0 },
+                { "key": 99.0, "value": 840.5 }
+            ]
+        }
+    })).unwrap();
+
+    assert_eq!(aggregations.value("avg_price").unwrap(), Some(12.5));
+    assert_eq!(aggregations.value("max_price").unwrap(), None);
+
+    let stats = aggregations.stats("price_stats").unwrap();
+    assert_eq!(stats.count, 4);
+    assert_eq!(stats.max, Some(30.0));
+    assert_eq!(stats.sum, 50.0);
+
+    let percentiles = aggregations.percentiles("load_time").unwrap();
+    assert_eq!(percentiles.values.len(), 2);
+    assert_eq!(percentiles.get(99.0), Some(840.5));
+
+    let percentiles = aggregations.percentiles("load_time_list").unwrap();
+    assert_eq!(percentiles.get(50.0), Some(120.0));
+}
+
+#[test]
+fn bucket_aggregations_with_sub_aggregations_test() {
+    let aggregations: AggregationsResponse = serde_json::from_value(json!({
+        "brands": {
+            "doc_count_error_upper_bound": 0,
+            "sum_other_doc_count": 5,
+            "buckets": [
+                {
+                    "key": "FIAT",
+                    "doc_count": 10,
+                    "avg_price": { "value": 7.0 },
+                    "per_month": {
+                        "buckets": [
+                            {
+                                "key_as_string": "2024-01-01",
+                                "key": 1704067200000_i64,
+                                "doc_count": 4
+                            }
+                        ]
+                    }
+                },
+                {
+                    "key": "LADA",
+                    "doc_count": 3,
+                    "avg_price": { "value": 2.0 },
+                    "per_month": { "buckets": [] }
+                }
+            ]
+        },
+        "prices": {
+            "buckets": {
+                "cheap": { "to": 10.0, "doc_count": 8 },
+                "expensive": { "from": 10.0, "doc_count": 5 }
+            }
+        },
+        "in_stock": {
+            "doc_count": 42,
+            "avg_price": { "value": 3.5 }
+        }
+    })).unwrap();
+
+    let brands = aggregations.buckets("brands").unwrap();
+    assert_eq!(brands.sum_other_doc_count, Some(5));
+    assert_eq!(brands.buckets.len(), 2);
+    assert_eq!(brands.buckets[0].key, json!("FIAT"));
+    assert_eq!(brands.buckets[0].doc_count, 10);
+    assert_eq!(brands.buckets[0].aggregations.value("avg_price").unwrap(), Some(7.0));
+
+    let per_month = brands.buckets[0].aggregations.buckets("per_month").unwrap();
+    assert_eq!(per_month.buckets[0].key_as_string.as_deref(), Some("2024-01-01"));
+    assert_eq!(per_month.buckets[0].doc_count, 4);
+
+    let mut prices = aggregations.buckets("prices").unwrap().buckets;
+    prices.sort_by_key(|x| x.doc_count);
+    assert_eq!(prices[0].key, json!("expensive"));
+    assert_eq!(prices[0].from, Some(10.0));
+    assert_eq!(prices[1].to, Some(10.0));
+
+    let in_stock = aggregations.bucket("in_stock").unwrap();
+    assert_eq!(in_stock.doc_count, 42);
+    assert_eq!(in_stock.aggregations.value("avg_price").unwrap(), Some(3.5));
+}
+
+#[test]
+fn composite_aggregation_test() {
+    let aggregations: AggregationsResponse = serde_json::from_value(json!({
+        "pairs": {
+            "after_key": {
+                "brand": "LADA",
+                "year": 2020
+            },
+            "buckets": [
+                {
+                    "key": { "brand": "FIAT", "year": 2019 },
+                    "doc_count": 1
+                },
+                {
+                    "key": { "brand": "LADA", "year": 2020 },
+                    "doc_count": 2
+                }
+            ]
+        }
+    })).unwrap();
+
+    let pairs = aggregations.buckets("pairs").unwrap();
+    let after_key = pairs.after_key.unwrap();
+    assert_eq!(after_key["brand"], json!("LADA"));
+    assert_eq!(after_key["year"], json!(2020));
+    assert_eq!(pairs.buckets[1].key["brand"], json!("LADA"));
+    assert!(pairs.buckets[1].aggregations.get("key").is_none());
+}
+
+#[test]
+fn top_hits_inside_search_response_test() {
+    let response: SearchResponse = serde_json::from_value(json!({
+        "took": 2,
+        "timed_out": false,
+        "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+        "hits": {
+            "total": { "value": 2, "relation": "eq" },
+            "max_score": null,
+            "hits": []
+        },
+        "aggregations": {
+            "brands": {
+                "buckets": [
+                    {
+                        "key": "FIAT",
+                        "doc_count": 2,
+                        "top": {
+                            "hits": {
+                                "total": { "value": 2, "relation": "eq" },
+                                "max_score": 1.0,
+                                "hits": [
+                                    {
+                                        "_index": "products",
+                                        "_id": "1",
+                                        "_score": 1.0,
+                                        "_source": { "name": "Panda" }
+                                    }
+                                ]
+                            }
+                        }
+                    }
+                ]
+            }
+        }
+    })).unwrap();
+
+    let aggregations = response.aggregations.unwrap();
+    let brands = aggregations.buckets("brands").unwrap();
+    let top = brands.buckets[0].aggregations.top_hits::<Product>("top").unwrap();
+    assert_eq!(top.hits.hits[0].source.as_ref().unwrap().name, "Panda");
+}
+
+#[test]
+fn missing_and_invalid_aggregation_test() {
+    let aggregations: AggregationsResponse = serde_json::from_value(json!({
+        "avg_price": { "value": 1.0 }
+    })).unwrap();
+
+    assert!(matches!(aggregations.value("unknown"), Err(AggregationError::Missing(_))));
+    assert!(matches!(aggregations.buckets("avg_price"), Err(AggregationError::Invalid(_, _))));
+}
+
+#[test]
+fn keyed_buckets_keep_response_order_test() {
+    let aggregations: AggregationsResponse = serde_json::from_str(r#"{
+        "levels": {
+            "buckets": {
+                "warnings": { "doc_count": 2 },
+                "errors": {
+                    "doc_count": 1,
+                    "prices": {
+                        "buckets": {
+                            "100.0-*": { "from": 100.0, "doc_count": 1 },
+                            "*-100.0": { "to": 100.0, "doc_count": 0 }
+                        }
+                    }
+                },
+                "info": { "doc_count": 5 }
+            }
+        }
+    }"#).unwrap();
+
+    let levels = aggregations.buckets("levels").unwrap();
+    let keys: Vec<_> = levels.buckets.iter().map(|x| x.key.as_str().unwrap()).collect();
+    assert_eq!(keys, ["warnings", "errors", "info"]);
+
+    let prices = levels.buckets[1].aggregations.buckets("prices").unwrap();
+    assert_eq!(prices.buckets[0].key, "100.0-*");
+    assert_eq!(prices.buckets[0].from, Some(100.0));
+    assert_eq!(prices.buckets[1].key, "*-100.0");
+
+    let levels: BucketAggregation = serde_json::from_str(r#"{
+        "buckets": { "warnings": { "doc_count": 2 }, "errors": { "doc_count": 1 } }
+    }"#).unwrap();
+    assert_eq!(levels.buckets[0].key, "warnings");
+    assert_eq!(levels.buckets[1].doc_count, 1);
+}
+
+#[test]
+fn reserved_names_outside_aggregation_body_test() {
+    let aggregations: AggregationsResponse = serde_json::from_str(r#"{
+        "script": {
+            "value": { "buckets": { "b": 1, "a": 2 }, "hits": 3 }
+        },
+        "pairs": {
+            "after_key": { "buckets": "x" },
+            "buckets": [
+                { "key": { "buckets": "x" }, "doc_count": 1 }
+            ]
+        },
+        "levels": {
+            "buckets": {
+                "errors": {
+                    "doc_count": 1,
+                    "hits": {
+                        "buckets": {
+                            "second": { "doc_count": 1 },
+                            "first": { "doc_count": 0 }
+                        }
+                    }
+                }
+            }
+        }
+    }"#).unwrap();
+
+    assert_eq!(aggregations.get("script").unwrap(), &json!({
+        "value": { "buckets": { "a": 2, "b": 1 }, "hits": 3 }
+    }));
+
+    let pairs = aggregations.buckets("pairs").unwrap();
+    assert_eq!(pairs.after_key.unwrap()["buckets"], json!("x"));
+    assert_eq!(pairs.buckets[0].key, json!({ "buckets": "x" }));
+
+    let levels = aggregations.buckets("levels").unwrap();
+    let hits = levels.buckets[0].aggregations.buckets("hits").unwrap();
+    let keys: Vec<_> = hits.buckets.iter().map(|x| x.key.as_str().unwrap()).collect();
+    assert_eq!(keys, ["second", "first"]);
+}
@@ -0,0 +1,138 @@
+use serde_json::json;
+use os_query_builder_rs::bulk::{
+    action::{CreateAction, DeleteAction, IndexAction, UpdateAction},
+    metadata::{BulkMetadata, VersionType},
+    request::BulkRequest
+};
+use os_query_builder_rs::misc::script::Script;
+use os_query_builder_rs::response::bulk::BulkResponse;
+
+#[test]
+fn bulk_actions_test() {
+    let bulk = BulkRequest::new()
+        .action(IndexAction::new(json!({ "brand": "FIAT" }))
+            .index("products")
+            .id("1")
+            .routing("eu")
+            .pipeline("enrich"))
+        .action(CreateAction::new(json!({ "brand": "LADA" }))
+            .metadata(BulkMetadata::new().index("products").id("2")))
+        .action(UpdateAction::new("3")
+            .index("products")
+            .if_seq_no(7u64)
+            .if_primary_term(1u64)
+            .retry_on_conflict(3u32)
+            .script(Script::new().source("ctx._source.price += params.step").lang("painless"))
+            .upsert(json!({ "price": 0 }))
+            .scripted_upsert(true))
+        .action(DeleteAction::new("4")
+            .index("products")
+            .version(5u64)
+            .version_type(VersionType::ExternalGte));
+
+    let expected = [
+        r#"{"index":{"_index":"products","_id":"1","routing":"eu","pipeline":"enrich"}}"#,
+        r#"{"brand":"FIAT"}"#,
+        r#"{"create":{"_index":"products","_id":"2"}}"#,
+        r#"{"brand":"LADA"}"#,
+        r#"{"update":{"_index":"products","_id":"3","if_seq_no":7,"if_primary_term":1,"retry_on_conflict":3}}"#,
+        r#"{"upsert":{"price":0},"script":{"source":"ctx._source.price += params.step","lang":"painless"},"scripted_upsert":true}"#,
+        r#"{"delete":{"_index":"products","_id":"4","version":5,"version_type":"external_gte"}}"#,
+    ];
+
+    assert_eq!(bulk.len(), 4);
+    assert_eq!(bulk.to_ndjson(), expected.map(|x| format!("{x}\n")).concat());
+}
+
+#[test]
+fn bulk_update_doc_as_upsert_test() {
+    let bulk: BulkRequest = vec![
+        UpdateAction::new("1").doc(json!({ "price": 10 })).doc_as_upsert(true).detect_noop(false)
+    ].into_iter().collect();
+
+    assert_eq!(bulk.to_ndjson(), concat!(
+        r#"{"update":{"_id":"1"}}"#, "\n",
+        r#"{"doc":{"price":10},"doc_as_upsert":true,"detect_noop":false}"#, "\n"
+    ));
+}
+
+#[test]
+fn bulk_chunks_test() {
+    let mut bulk = BulkRequest::new();
+    for id in 0..5 {
+        bulk.push(DeleteAction::new(id.to_string()).index("products"));
+    }
+    let line = r#"{"delete":{"_index":"products","_id":"0"}}"#.len() + 1;
+
+    let chunks = bulk.chunks_by_actions(2);
+    assert_eq!(chunks.len(), 3);
+    assert_eq!(chunks[2], "{\"delete\":{\"_index\":\"products\",\"_id\":\"4\"}}\n");
+
+    let chunks = bulk.chunks_by_bytes(line * 3);
+    assert_eq!(chunks.iter().map(|x| x.lines().count()).collect::<Vec<_>>(), vec![3, 2]);
+
+    let chunks = bulk.chunks(4, line * 3 - 1);
+    assert_eq!(chunks.iter().map(|x| x.lines().count()).collect::<Vec<_>>(), vec![2, 2, 1]);
+
+    // an action larger than the limit is sent alone
+    let chunks = bulk.chunks_by_bytes(1);
+    assert_eq!(chunks.len(), 5);
+    assert_eq!(chunks.concat(), bulk.to_ndjson());
+
+    assert!(BulkRequest::new().chunks(10, 10).is_empty());
+}
+
+#[test]
+fn bulk_response_test() {
+    let response: BulkResponse = serde_json::from_value(json!({
+        "took": 30,
+        "errors": true,
+        "items": [
+            {
+                "index": {
+                    "_index": "products", "_id": "1", "_version": 1, "result": "created",
+                    "_shards": { "total": 2, "successful": 1, "failed": 0 },
+                    "_seq_no": 0, "_primary_term": 1, "status": 201
+                }
+            },
+            {
+                "update": {
+                    "_index": "products", "_id": "3", "status": 404,
+                    "error": {
+                        "type": "document_missing_exception",
+                        "reason": "[3]: document missing",
+                        "index": "products"
+                    }
+                }
+            },
+            {
+                "delete": {
+                    "_index": "products", "_id": "4", "_version": 2, "result": "not_found",
+                    "_seq_no": 3, "_primary_term": 1, "status": 404
+                }
+            }
+        ]
+    })).unwrap();
+
+    assert!(response.errors);
+    assert_eq!(response.items.len(), 3);
+
+    let successes: Vec<_> = response.successes().map(|x| (x.action.as_str(), x.result.as_deref())).collect();
+    assert_eq!(successes, vec![("index", Some("created")), ("delete", Some("not_found"))]);
+
+    let failure = response.failures().next().unwrap();
+    assert_eq!(failure.action, "update");
+    assert_eq!(failure.id.as_deref(), Some("3"));
+    assert_eq!(failure.status, 404);
+    let error = failure.error.as_ref().unwrap();
+    assert_eq!(error.error_type, "document_missing_exception");
+    assert_eq!(error.reason.as_deref(), Some("[3]: document missing"));
+
+    assert_eq!(response.items[0].seq_no, Some(0));
+    assert_eq!(response.items[0].version, Some(1));
+
+    let invalid = serde_json::from_value::<BulkResponse>(json!({
+        "took": 1, "errors": false, "items": [{ "index": { "_index": "a", "status": 201 }, "create": {} }]
+    }));
+    assert!(invalid.is_err());
+}
@@ -0,0 +1,109 @@
+use serde_json::json;
+use os_query_builder_rs::by_query::{
+    conflicts::Conflicts,
+    count::CountRequest,
+    delete_by_query::DeleteByQuery,
+    params::ByQueryParams,
+    slice::Slice,
+    update_by_query::UpdateByQuery
+};
+use os_query_builder_rs::compound_query::bool::Bool;
+use os_query_builder_rs::misc::script::Script;
+use os_query_builder_rs::response::by_query::{BulkByScrollResponse, CountResponse, TaskResponse, TaskStatusResponse};
+use os_query_builder_rs::term::{range::Range, term::Term};
+
+#[test]
+fn count_request_test() {
+    assert_eq!(serde_json::to_value(CountRequest::new()).unwrap(), json!({}));
+    assert_eq!(
+        serde_json::to_value(CountRequest::new().query(Bool::new().filter(vec![Term::new("brand", "FIAT")]))).unwrap(),
+        json!({ "query": { "bool": { "filter": [{ "term": { "brand": { "value": "FIAT" } } }] } } })
+    );
+}
+
+#[test]
+fn delete_by_query_test() {
+    let delete = DeleteByQuery::new()
+        .query(Range::new().field("created").lt("now-1y"))
+        .max_docs(100u64)
+        .conflicts(Conflicts::Abort)
+        .slice(Slice::new(0, 2));
+
+    assert_eq!(serde_json::to_value(delete).unwrap(), json!({
+        "query": { "range": { "created": { "lt": "now-1y" } } },
+        "max_docs": 100,
+        "conflicts": "abort",
+        "slice": { "id": 0, "max": 2 }
+    }));
+}
+
+#[test]
+fn update_by_query_test() {
+    let filter = Term::new("status", "draft");
+    let update = UpdateByQuery::new()
+        .query(filter)
+        .script(Script::new().source("ctx._source.status = 'archived'"))
+        .conflicts(Conflicts::Proceed)
+        .max_docs(10u64);
+
+    assert_eq!(serde_json::to_value(&update).unwrap(), json!({
+        "query": { "term": { "status": { "value": "draft" } } },
+        "script": { "source": "ctx._source.status = 'archived'" },
+        "max_docs": 10,
+        "conflicts": "proceed"
+    }));
+
+    let update: UpdateByQuery = serde_json::from_value(json!({ "conflicts": "proceed", "slice": { "id": 1, "max": 4 } })).unwrap();
+    assert_eq!(serde_json::to_value(update).unwrap(), json!({ "conflicts": "proceed", "slice": { "id": 1, "max": 4 } }));
+}
+
+#[test]
+fn by_query_params_test() {
+    let params = ByQueryParams::new()
+        .refresh(true)
+        .wait_for_completion(false)
+        .requests_per_second(-1)
+        .scroll_size(500u64);
+
+    assert_eq!(params.to_string(), "refresh=true&wait_for_completion=false&requests_per_second=-1&scroll_size=500");
+    assert_eq!(params.pairs()[0], ("refresh", "true".to_string()));
+    assert_eq!(serde_json::to_value(&params).unwrap(), json!({
+        "refresh": true, "wait_for_completion": false, "requests_per_second": -1.0, "scroll_size": 500
+    }));
+    assert_eq!(ByQueryParams::new().requests_per_second(12.5).to_string(), "requests_per_second=12.5");
+    assert_eq!(ByQueryParams::new().to_string(), "");
+}
+
+#[test]
+fn by_query_responses_test() {
+    let count: CountResponse = serde_json::from_value(json!({
+        "count": 42, "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 }
+    })).unwrap();
+    assert_eq!(count.count, 42);
+
+    let response: BulkByScrollResponse = serde_json::from_value(json!({
+        "took": 147, "timed_out": false, "total": 120, "updated": 118, "deleted": 0, "batches": 2,
+        "version_conflicts": 2, "noops": 0, "retries": { "bulk": 1, "search": 0 },
+        "throttled_millis": 0, "requests_per_second": -1.0, "throttled_until_millis": 0, "failures": []
+    })).unwrap();
+    assert_eq!((response.total, response.updated, response.version_conflicts), (120, 118, 2));
+    assert_eq!(response.retries.bulk, 1);
+
+    let task: TaskResponse = serde_json::from_value(json!({ "task": "oTUltX4IQMOUUVeiohTt8A:12345" })).unwrap();
+    assert_eq!(task.task, "oTUltX4IQMOUUVeiohTt8A:12345");
+
+    let status: TaskStatusResponse = serde_json::from_value(json!({
+        "completed": false,
+        "task": {
+            "node": "oTUltX4IQMOUUVeiohTt8A", "id": 12345, "type": "transport",
+            "action": "indices:data/write/delete/byquery",
+            "status": { "total": 6154, "updated": 0, "created": 0, "deleted": 3500, "batches": 36,
+                        "version_conflicts": 0, "noops": 0, "retries": { "bulk": 0, "search": 0 },
+                        "throttled_millis": 0, "requests_per_second": -1, "throttled_until_millis": 0 },
+            "running_time_in_nanos": 2000000000, "cancellable": true
+        }
+    })).unwrap();
+    assert!(!status.completed);
+    assert_eq!(status.task.status.unwrap().deleted, 3500);
+    assert!(status.response.is_none());
+}
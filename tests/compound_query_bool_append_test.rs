@@ -0,0 +1,94 @@
+use serde_json::json;
+use os_query_builder_rs::compound_query::bool::Bool;
+use os_query_builder_rs::full_text::r#match::Match;
+use os_query_builder_rs::term::{
+    range::Range,
+    term::Term,
+    terms::Terms
+};
+
+#[test]
+fn add_and_extend_clauses_test() {
+    let boolean = Bool::new()
+        .must(vec![Match::new().field("title").value("wind")])
+        .add_must(Match::new().field("body").value("rain"))
+        .add_filter(Term::new("lang", "en"))
+        .extend_filter(vec![Range::new().field("price").gte(10)])
+        .extend_should(vec![Term::new("brand", "FIAT"), Term::new("brand", "LADA")])
+        .add_must_not(Term::new("status", "deleted"));
+
+    let json_expected = json!({
+        "bool": {
+            "must": [
+                { "match": { "title": { "query": "wind" } } },
+                { "match": { "body": { "query": "rain" } } }
+            ],
+            "filter": [
+                { "term": { "lang": { "value": "en" } } },
+                { "range": { "price": { "gte": 10 } } }
+            ],
+            "should": [
+                { "term": { "brand": { "value": "FIAT" } } },
+                { "term": { "brand": { "value": "LADA" } } }
+            ],
+            "must_not": [{ "term": { "status": { "value": "deleted" } } }]
+        }
+    });
+
+    assert_eq!(json_expected, json!({ "bool": boolean }));
+}
+
+#[test]
+fn push_clauses_in_place_test() {
+    let mut boolean = Bool::new();
+    for brand in ["FIAT", "LADA"] {
+        boolean.push_should(Term::new("brand", brand));
+    }
+    boolean
+        .push_filter(Term::new("lang", "en"))
+        .append_must_not(vec![Term::new("status", "deleted"), Term::new("status", "draft")]);
+
+    let json_expected = json!({
+        "bool": {
+            "should": [
+                { "term": { "brand": { "value": "FIAT" } } },
+                { "term": { "brand": { "value": "LADA" } } }
+            ],
+            "filter": [{ "term": { "lang": { "value": "en" } } }],
+            "must_not": [
+                { "term": { "status": { "value": "deleted" } } },
+                { "term": { "status": { "value": "draft" } } }
+            ]
+        }
+    });
+
+    assert_eq!(json_expected, json!({ "bool": boolean }));
+}
+
+#[test]
+fn conditional_clauses_test() {
+    let in_stock_only = true;
+    let show_deleted = true;
+    let brands: Option<Vec<&str>> = Some(vec!["FIAT", "LADA"]);
+    let max_price: Option<u32> = None;
+
+    let boolean = Bool::new()
+        .filter_if(in_stock_only, Range::new().field("stock").gt(0))
+        .must_not_if(!show_deleted, Term::new("status", "deleted"))
+        .filter_opt(brands.map(|x| Terms::new_with_terms_query("brand", x)))
+        .filter_opt(max_price.map(|x| Range::new().field("price").lte(x)))
+        .must_opt(Some(Match::new().field("title").value("wind")))
+        .should_opt(None::<Term>);
+
+    let json_expected = json!({
+        "bool": {
+            "must": [{ "match": { "title": { "query": "wind" } } }],
+            "filter": [
+                { "range": { "stock": { "gt": 0 } } },
+                { "terms": { "brand": ["FIAT", "LADA"] } }
+            ]
+        }
+    });
+
+    assert_eq!(json_expected, json!({ "bool": boolean }));
+}
@@ -1,4 +1,5 @@
 use serde_json::json;
+use os_query_builder_rs::misc::minimum_should_match::MinimumShouldMatch;
 use os_query_builder_rs::term::term::Term;
 use os_query_builder_rs::term::terms::{Terms, TermsLookup};
 use os_query_builder_rs::{
@@ -2853,7 +2854,7 @@ fn using_all_conditions_test() {
         .operator(Operator::And)
         .query_type(Type::BestFields)
         .boost(2)
-        .minimum_should_match("90%");
+        .minimum_should_match(MinimumShouldMatch::percentage(90).unwrap());
 
 
     let match_phrase_prefix = MatchPhrasePrefix::new()
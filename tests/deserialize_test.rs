@@ -0,0 +1,175 @@
+use serde_json::{json, Value};
+use os_query_builder_rs::compound_query::bool::Bool;
+use os_query_builder_rs::misc::query_field::QueryField;
+use os_query_builder_rs::model::Query;
+use os_query_builder_rs::term::term::Term;
+
+fn round_trip<T>(value: Value)
+    where T: serde::de::DeserializeOwned + serde::Serialize
+{
+    let parsed: T = serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(value, json!(parsed));
+}
+
+#[test]
+fn term_level_queries_round_trip_test() {
+    round_trip::<QueryField>(json!({ "term": { "brand": { "value": "FIAT", "boost": 0.6, "case_insensitive": true } } }));
+    round_trip::<QueryField>(json!({ "term": { "brand_id": { "value": 12 } } }));
+    round_trip::<QueryField>(json!({ "term": { "price": { "value": 1.5 } } }));
+    round_trip::<QueryField>(json!({ "term": { "active": { "value": true } } }));
+    round_trip::<QueryField>(json!({ "terms": { "active": [true, false] } }));
+    round_trip::<QueryField>(json!({ "terms": { "brand": ["FIAT", "LADA"], "boost": 2.0 } }));
+    round_trip::<QueryField>(json!({ "terms": { "brand": { "index": "brands", "id": "1", "path": "list", "routing": "r" } } }));
+    round_trip::<QueryField>(json!({ "terms_set": { "tags": { "terms": ["a", "b"], "minimum_should_match_field": "count" } } }));
+    round_trip::<QueryField>(json!({ "ids": { "values": ["1", "2"] } }));
+    round_trip::<QueryField>(json!({ "exists": { "field": "brand", "boost": 1.0 } }));
+    round_trip::<QueryField>(json!({ "prefix": { "brand": { "value": "FI", "rewrite": "top_terms_boost_10" } } }));
+    round_trip::<QueryField>(json!({ "wildcard": { "brand": { "value": "F*T", "case_insensitive": true } } }));
+    round_trip::<QueryField>(json!({ "regexp": { "brand": { "value": "F.*", "flags": "ALL", "rewrite": "constant_score" } } }));
+    round_trip::<QueryField>(json!({ "fuzzy": { "brand": { "value": "FAIT", "fuzziness": "AUTO", "transpositions": true } } }));
+    round_trip::<QueryField>(json!({ "range": { "created": { "gte": "2019/01/01", "lt": "2020/01/01", "format": "yyyy/MM/dd", "relation": "WITHIN" } } }));
+}
+
+#[test]
+fn full_text_queries_round_trip_test() {
+    round_trip::<QueryField>(json!({ "match": { "title": { "query": "wind", "operator": "and", "fuzziness": 2, "zero_terms_query": "all" } } }));
+    round_trip::<QueryField>(json!({ "match_phrase": { "title": { "query": "the wind", "slop": 2 } } }));
+    round_trip::<QueryField>(json!({ "match_phrase_prefix": { "title": { "query": "the wi", "max_expansions": 10 } } }));
+    round_trip::<QueryField>(json!({ "match_bool_prefix": { "title": { "query": "the wi", "fuzzy_rewrite": "scoring_boolean" } } }));
+    round_trip::<QueryField>(json!({ "multi_match": { "query": "wind", "fields": ["title^2", "body"], "type": "best_fields", "tie_breaker": 0.3 } }));
+    round_trip::<QueryField>(json!({ "query_string": { "query": "title:wind", "default_operator": "and" } }));
+    round_trip::<QueryField>(json!({ "simple_query_string": { "query": "wind", "fields": ["title"], "flags": "AND|OR" } }));
+    round_trip::<QueryField>(json!({
+        "intervals": {
+            "title": {
+                "all_of": {
+                    "ordered": true,
+                    "intervals": [
+                        { "match": { "query": "key", "max_gaps": 0 } },
+                        { "any_of": { "intervals": [{ "prefix": { "prefix": "ch" } }, { "wildcard": { "pattern": "h*" } }] } }
+                    ],
+                    "filter": { "not_containing": { "fuzzy": { "term": "cheese", "fuzziness": "AUTO" } } }
+                }
+            }
+        }
+    }));
+}
+
+#[test]
+fn compound_queries_round_trip_test() {
+    round_trip::<Query>(json!({
+        "from": 10,
+        "size": 20,
+        "_source": { "excludes": ["embedding"] },
+        "track_total_hits": 1000,
+        "query": {
+            "bool": {
+                "must": [
+                    { "match": { "title": { "query": "wind" } } },
+                    {
+                        "dis_max": {
+                            "queries": [
+                                { "term": { "brand": { "value": "FIAT" } } },
+                                { "constant_score": { "filter": { "exists": { "field": "brand" } }, "boost": 1.2 } }
+                            ],
+                            "tie_breaker": 0.7
+                        }
+                    }
+                ],
+                "should": [
+                    {
+                        "boosting": {
+                            "positive": { "term": { "brand": { "value": "FIAT" } } },
+                            "negative": { "term": { "brand": { "value": "LADA" } } },
+                            "negative_boost": 0.5
+                        }
+                    }
+                ],
+                "filter": [{ "range": { "price": { "gte": 10 } } }],
+                "must_not": [{ "ids": { "values": [1, 2] } }],
+                "minimum_should_match": 1
+            }
+        },
+        "post_filter": { "term": { "color": { "value": "red" } } },
+        "docvalue_fields": [{ "field": "created", "format": "epoch_millis" }],
+        "derived": {
+            "method": {
+                "type": "keyword",
+                "script": { "source": "emit(doc['request'].value)", "lang": "painless" }
+            }
+        }
+    }));
+}
+
+#[test]
+fn short_forms_test() {
+    let query: QueryField = serde_json::from_value(json!({
+        "bool": {
+            "filter": [
+                { "term": { "brand": "FIAT" } },
+                { "match": { "title": "wind" } },
+                { "match_phrase": { "title": "the wind" } },
+                { "prefix": { "brand": "FI" } },
+                { "wildcard": { "brand": "F*" } }
+            ]
+        }
+    })).unwrap();
+
+    let json_expected = json!({
+        "bool": {
+            "filter": [
+                { "term": { "brand": { "value": "FIAT" } } },
+                { "match": { "title": { "query": "wind" } } },
+                { "match_phrase": { "title": { "query": "the wind" } } },
+                { "prefix": { "brand": { "value": "FI" } } },
+                { "wildcard": { "brand": { "value": "F*" } } }
+            ]
+        }
+    });
+
+    assert_eq!(json_expected, json!(query));
+}
+
+#[test]
+fn loaded_query_can_be_changed_test() {
+    let saved = json!({ "bool": { "must": [{ "term": { "brand": { "value": "FIAT" } } }] } });
+    let must: QueryField = serde_json::from_value(saved).unwrap();
+
+    let query = Query::new()
+        .size(10usize)
+        .query(Bool::new().must(vec![must]).filter(vec![Term::new("tenant", "acme")]));
+
+    let json_expected = json!({
+        "size": 10,
+        "query": {
+            "bool": {
+                "must": [{ "bool": { "must": [{ "term": { "brand": { "value": "FIAT" } } }] } }],
+                "filter": [{ "term": { "tenant": { "value": "acme" } } }]
+            }
+        }
+    });
+
+    assert_eq!(json_expected, json!(query));
+}
+
+#[test]
+fn invalid_queries_test() {
+    assert!(serde_json::from_value::<QueryField>(json!({ "unknown": {} })).is_err());
+    assert!(serde_json::from_value::<QueryField>(json!({ "term": { "a": "x", "b": "y" } })).is_err());
+    assert!(serde_json::from_value::<QueryField>(json!({ "range": { "price": 10 } })).is_err());
+    assert!(serde_json::from_value::<QueryField>(json!({ "fuzzy": { "brand": { "value": "x", "fuzziness": "many" } } })).is_err());
+}
+
+#[test]
+fn unknown_options_are_rejected_test() {
+    let err = serde_json::from_value::<QueryField>(json!({ "term": { "brand": { "value": "FIAT", "_name": "q1" } } })).unwrap_err();
+    assert!(err.to_string().contains("_name"), "{err}");
+
+    let err = serde_json::from_value::<QueryField>(json!({ "match": { "title": { "query": "wind", "boots": 2 } } })).unwrap_err();
+    assert!(err.to_string().contains("boots"), "{err}");
+
+    assert!(serde_json::from_value::<QueryField>(json!({ "bool": { "must": [], "boots": 2 } })).is_err());
+    assert!(serde_json::from_value::<QueryField>(json!({
+        "intervals": { "title": { "all_of": { "intervals": [{ "match": { "query": "wind" } }], "orderd": true } } }
+    })).is_err());
+}
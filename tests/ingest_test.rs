@@ -0,0 +1,248 @@
+use serde_json::json;
+use os_query_builder_rs::ingest::{
+    pipeline::Pipeline,
+    processors::{
+        ConvertProcessor, ConvertType, DateProcessor, DissectProcessor, DropProcessor, FailProcessor,
+        ForeachProcessor, GrokProcessor, GsubProcessor, JoinProcessor, JsonProcessor, LowercaseProcessor, OtherProcessor,
+        PipelineProcessor, Processor, RemoveProcessor, RenameProcessor, ScriptProcessor, SetProcessor,
+        SparseEncodingProcessor, SplitProcessor, TextEmbeddingProcessor
+    },
+    simulate::{SimulateDocument, SimulateRequest}
+};
+use os_query_builder_rs::misc::script::Script;
+use os_query_builder_rs::response::ingest::SimulateResponse;
+
+fn to_json<T: Into<Processor>>(processor: T) -> serde_json::Value {
+    serde_json::to_value(processor.into()).unwrap()
+}
+
+#[test]
+fn field_processors_test() {
+    assert_eq!(to_json(SetProcessor::new("status", "active").override_existing(false).ignore_empty_value(true)),
+               json!({ "set": { "field": "status", "value": "active", "override": false, "ignore_empty_value": true } }));
+    assert_eq!(to_json(RemoveProcessor::new(["tmp", "debug"]).ignore_missing(true)),
+               json!({ "remove": { "field": ["tmp", "debug"], "ignore_missing": true } }));
+    assert_eq!(to_json(RenameProcessor::new("host", "host.name")),
+               json!({ "rename": { "field": "host", "target_field": "host.name" } }));
+    assert_eq!(to_json(ConvertProcessor::new("price", ConvertType::Double).target_field("price_value")),
+               json!({ "convert": { "field": "price", "type": "double", "target_field": "price_value" } }));
+    assert_eq!(to_json(DateProcessor::new("created", ["dd/MM/yyyy", "ISO8601"]).timezone("Europe/Rome").output_format("yyyy-MM-dd")),
+               json!({ "date": { "field": "created", "formats": ["dd/MM/yyyy", "ISO8601"], "timezone": "Europe/Rome", "output_format": "yyyy-MM-dd" } }));
+    assert_eq!(to_json(LowercaseProcessor::new("brand").target_field("brand_lower").ignore_missing(true)),
+               json!({ "lowercase": { "field": "brand", "target_field": "brand_lower", "ignore_missing": true } }));
+    assert_eq!(to_json(GsubProcessor::new("sku", "-", "")),
+               json!({ "gsub": { "field": "sku", "pattern": "-", "replacement": "" } }));
+    assert_eq!(to_json(JsonProcessor::new("payload").add_to_root(true)),
+               json!({ "json": { "field": "payload", "add_to_root": true } }));
+}
+
+#[test]
+fn parsing_processors_test() {
+    let grok = GrokProcessor::new("message", ["%{IP:client} %{WORD:method} %{SKU:sku}"])
+        .pattern_definition("SKU", "[A-Z]{2}-[0-9]+")
+        .trace_match(true);
+    assert_eq!(to_json(grok), json!({
+        "grok": {
+            "field": "message",
+            "patterns": ["%{IP:client} %{WORD:method} %{SKU:sku}"],
+            "pattern_definitions": { "SKU": "[A-Z]{2}-[0-9]+" },
+            "trace_match": true
+        }
+    }));
+
+    assert_eq!(to_json(DissectProcessor::new("message", "%{client} - %{+ts} %{+ts}").append_separator(" ")),
+               json!({ "dissect": { "field": "message", "pattern": "%{client} - %{+ts} %{+ts}", "append_separator": " " } }));
+    assert_eq!(to_json(SplitProcessor::new("tags", ",").preserve_trailing(true)),
+               json!({ "split": { "field": "tags", "separator": ",", "preserve_trailing": true } }));
+    assert_eq!(to_json(JoinProcessor::new("tags", "|").target_field("tags_joined")),
+               json!({ "join": { "field": "tags", "separator": "|", "target_field": "tags_joined" } }));
+}
+
+#[test]
+fn flow_processors_test() {
+    let script = ScriptProcessor::new(Script::new().source("ctx.total = ctx.price * params.rate").lang("painless"))
+        .params(json!({ "rate": 1.2 }));
+    assert_eq!(to_json(script), json!({
+        "script": { "source": "ctx.total = ctx.price * params.rate", "lang": "painless", "params": { "rate": 1.2 } }
+    }));
+
+    assert_eq!(to_json(PipelineProcessor::new("geoip-pipeline").ignore_missing_pipeline(true)),
+               json!({ "pipeline": { "name": "geoip-pipeline", "ignore_missing_pipeline": true } }));
+    assert_eq!(to_json(ForeachProcessor::new("tags", LowercaseProcessor::new("_ingest._value"))),
+               json!({ "foreach": { "field": "tags", "processor": { "lowercase": { "field": "_ingest._value" } } } }));
+    assert_eq!(to_json(DropProcessor::new().condition("ctx.status == 'deleted'")),
+               json!({ "drop": { "if": "ctx.status == 'deleted'" } }));
+    assert_eq!(to_json(FailProcessor::new("missing sku").condition("ctx.sku == null")),
+               json!({ "fail": { "message": "missing sku", "if": "ctx.sku == null" } }));
+}
+
+#[test]
+fn ml_processors_test() {
+    assert_eq!(to_json(TextEmbeddingProcessor::new("bQ1J8ooBpBj3wMXfh9n1").field_map("title", "title_embedding")),
+               json!({ "text_embedding": { "model_id": "bQ1J8ooBpBj3wMXfh9n1", "field_map": { "title": "title_embedding" } } }));
+    assert_eq!(to_json(SparseEncodingProcessor::new("aP2Q8ooBpBj3wMXfSn_b").field_map("body", "body_sparse").tag("sparse")),
+               json!({ "sparse_encoding": { "model_id": "aP2Q8ooBpBj3wMXfSn_b", "field_map": { "body": "body_sparse" }, "tag": "sparse" } }));
+}
+
+#[test]
+fn common_options_test() {
+    let convert = ConvertProcessor::new("year", ConvertType::Integer)
+        .condition("ctx.year != null")
+        .tag("convert-year")
+        .description("year as a number")
+        .ignore_failure(false)
+        .on_failure([SetProcessor::new("year_error", "{{{_ingest.on_failure_message}}}")]);
+
+    assert_eq!(to_json(convert), json!({
+        "convert": {
+            "field": "year",
+            "type": "integer",
+            "if": "ctx.year != null",
+            "tag": "convert-year",
+            "description": "year as a number",
+            "ignore_failure": false,
+            "on_failure": [{ "set": { "field": "year_error", "value": "{{{_ingest.on_failure_message}}}" } }]
+        }
+    }));
+}
+
+#[test]
+fn pipeline_test() {
+    let pipeline = Pipeline::new()
+        .description("products")
+        .version(2u64)
+        .meta(json!({ "owner": "catalog" }))
+        .processor(TextEmbeddingProcessor::new("model").field_map("title", "title_embedding"))
+        .processors(vec![
+            Processor::from(LowercaseProcessor::new("brand")),
+            DropProcessor::new().condition("ctx.hidden == true").into()
+        ])
+        .on_failure([SetProcessor::new("_index", "failed-{{{_index}}}")]);
+
+    let value = serde_json::to_value(&pipeline).unwrap();
+    assert_eq!(value, json!({
+        "description": "products",
+        "processors": [
+            { "text_embedding": { "model_id": "model", "field_map": { "title": "title_embedding" } } },
+            { "lowercase": { "field": "brand" } },
+            { "drop": { "if": "ctx.hidden == true" } }
+        ],
+        "on_failure": [{ "set": { "field": "_index", "value": "failed-{{{_index}}}" } }],
+        "version": 2,
+        "_meta": { "owner": "catalog" }
+    }));
+
+    let parsed: Pipeline = serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(serde_json::to_value(parsed).unwrap(), value);
+}
+
+#[test]
+fn remove_field_as_string_test() {
+    let pipeline: Pipeline = serde_json::from_value(json!({
+        "processors": [
+            { "remove": { "field": "tmp" } },
+            { "remove": { "field": ["a", "b"], "ignore_missing": true } }
+        ]
+    })).unwrap();
+
+    assert_eq!(serde_json::to_value(pipeline).unwrap(), json!({
+        "processors": [
+            { "remove": { "field": ["tmp"] } },
+            { "remove": { "field": ["a", "b"], "ignore_missing": true } }
+        ]
+    }));
+}
+
+#[test]
+fn other_processors_test() {
+    let value = json!({
+        "processors": [
+            { "uppercase": { "field": "brand" } },
+            { "append": { "field": "tags", "value": ["new"] } },
+            { "set": { "field": "a", "value": 1, "on_failure": [{ "trim": { "field": "a" } }] } },
+            { "lowercase": { "field": "brand" } }
+        ]
+    });
+
+    let pipeline: Pipeline = serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&pipeline).unwrap(), value);
+
+    let processor: Processor = serde_json::from_value(json!({ "uppercase": { "field": "brand" } })).unwrap();
+    let Processor::Other(other) = processor else {
+        panic!("expected an other processor");
+    };
+    assert_eq!(other.processor_type(), "uppercase");
+    assert_eq!(other.options(), &json!({ "field": "brand" }));
+
+    assert_eq!(to_json(OtherProcessor::new("trim", json!({ "field": "title" }))), json!({ "trim": { "field": "title" } }));
+    assert!(serde_json::from_value::<Processor>(json!({ "uppercase": {}, "trim": {} })).is_err());
+}
+
+#[test]
+fn processor_with_unknown_options_test() {
+    let value = json!({ "set": { "field": "a", "value": 1, "media_type": "text/plain" } });
+
+    let processor: Processor = serde_json::from_value(value.clone()).unwrap();
+    let Processor::Other(other) = &processor else {
+        panic!("expected an other processor");
+    };
+    assert_eq!(other.processor_type(), "set");
+    assert_eq!(serde_json::to_value(&processor).unwrap(), value);
+
+    let processor: Processor = serde_json::from_value(json!({ "set": { "field": "a", "value": 1, "tag": "t" } })).unwrap();
+    assert!(matches!(processor, Processor::Set(_)));
+}
+
+#[test]
+fn simulate_request_test() {
+    let simulate = SimulateRequest::new()
+        .doc(SimulateDocument::new(json!({ "brand": "FIAT" })).index("products").id("1"))
+        .docs(vec![json!({ "brand": "LADA" })]);
+
+    assert_eq!(serde_json::to_value(simulate).unwrap(), json!({
+        "docs": [
+            { "_index": "products", "_id": "1", "_source": { "brand": "FIAT" } },
+            { "_source": { "brand": "LADA" } }
+        ]
+    }));
+}
+
+#[test]
+fn simulate_response_test() {
+    #[derive(Debug, serde::Deserialize)]
+    struct Product {
+        brand: String,
+    }
+
+    let response: SimulateResponse<Product> = serde_json::from_value(json!({
+        "docs": [
+            { "doc": { "_index": "products", "_id": "1", "_source": { "brand": "fiat" }, "_ingest": { "timestamp": "2024-05-01T10:00:00Z" } } },
+            null,
+            { "error": { "root_cause": [], "type": "illegal_argument_exception", "reason": "field [brand] not present" } }
+        ]
+    })).unwrap();
+
+    assert_eq!(response.docs[0].doc.as_ref().unwrap().source.brand, "fiat");
+    assert_eq!(response.docs[0].doc.as_ref().unwrap().id.as_deref(), Some("1"));
+    assert!(response.docs[1].is_dropped());
+    assert_eq!(response.docs[2].error.as_ref().unwrap().reason.as_deref(), Some("field [brand] not present"));
+    assert!(!response.docs[2].is_dropped());
+
+    let verbose: SimulateResponse = serde_json::from_value(json!({
+        "docs": [{
+            "processor_results": [
+                { "processor_type": "lowercase", "status": "success", "doc": { "_index": "_index", "_id": "_id", "_source": { "brand": "fiat" } } },
+                { "processor_type": "convert", "tag": "convert-year", "status": "error_ignored",
+                  "ignored_error": { "error": { "type": "illegal_argument_exception" } },
+                  "doc": { "_source": { "brand": "fiat" } } },
+                { "processor_type": "drop", "status": "dropped" }
+            ]
+        }]
+    })).unwrap();
+
+    let results = &verbose.docs[0].processor_results;
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[1].tag.as_deref(), Some("convert-year"));
+    assert!(results[1].ignored_error.is_some());
+    assert_eq!(results[2].status.as_deref(), Some("dropped"));
+}
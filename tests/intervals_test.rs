@@ -44,7 +44,7 @@ fn first_example_from_docs_test() {
           "title": {
             "all_of": {
               "ordered": true,
-              "interval": [
+              "intervals": [
                 {
                   "match": {
                     "query": "key-value pairs",
@@ -54,7 +54,7 @@ fn first_example_from_docs_test() {
                 },
                 {
                   "any_of": {
-                    "interval": [
+                    "intervals": [
                       {
                         "match": {
                           "query": "hash table"
@@ -217,10 +217,10 @@ fn interval_with_all_of_and_nested_any_of_with_other_rules_docs_test() {
         "intervals" : {
           "my_text" : {
             "all_of" : {
-              "interval": [
+              "intervals": [
                 {
                   "any_of": {
-                    "interval": [
+                    "intervals": [
                       {
                         "match": {
                           "query": "d"
@@ -0,0 +1,264 @@
+use serde_json::json;
+use os_query_builder_rs::lucene::{
+    ast::{Bound, Clause, Node, Occur},
+    convert::{ConvertError, Converter},
+    parser::{parse, Parser}
+};
+use os_query_builder_rs::misc::operator::Operator;
+
+fn term(field: &str, value: &str) -> Node {
+    Node::Term { field: Some(field.into()), value: value.into() }
+}
+
+fn word(value: &str) -> Node {
+    Node::Term { field: None, value: value.into() }
+}
+
+#[test]
+fn parse_operators_precedence_test() {
+    let node = parse("a OR b AND c").unwrap();
+
+    assert_eq!(node, Node::Bool(vec![
+        Clause::new(Occur::Should, word("a")),
+        Clause::new(Occur::Should, Node::Bool(vec![
+            Clause::new(Occur::Must, word("b")),
+            Clause::new(Occur::Must, word("c")),
+        ])),
+    ]));
+
+    assert_eq!(parse("a || b && c").unwrap(), node);
+}
+
+#[test]
+fn parse_modifiers_test() {
+    let node = parse("+title:wind -status:draft NOT status:deleted !lang:ru rain").unwrap();
+
+    assert_eq!(node, Node::Bool(vec![
+        Clause::new(Occur::Must, term("title", "wind")),
+        Clause::new(Occur::MustNot, term("status", "draft")),
+        Clause::new(Occur::MustNot, term("status", "deleted")),
+        Clause::new(Occur::MustNot, term("lang", "ru")),
+        Clause::new(Occur::Should, word("rain")),
+    ]));
+
+    let node = parse("wind AND NOT rain").unwrap();
+    assert_eq!(node, Node::Bool(vec![
+        Clause::new(Occur::Must, word("wind")),
+        Clause::new(Occur::MustNot, word("rain")),
+    ]));
+}
+
+#[test]
+fn parse_default_operator_test() {
+    let node = Parser::new()
+        .default_operator(Operator::And)
+        .parse("wind rain")
+        .unwrap();
+
+    assert_eq!(node, Node::Bool(vec![
+        Clause::new(Occur::Must, word("wind")),
+        Clause::new(Occur::Must, word("rain")),
+    ]));
+}
+
+#[test]
+fn parse_field_group_test() {
+    let node = parse("title:(quick brown) AND (lang:en OR lang:de)").unwrap();
+
+    assert_eq!(node, Node::Bool(vec![
+        Clause::new(Occur::Must, Node::Bool(vec![
+            Clause::new(Occur::Should, term("title", "quick")),
+            Clause::new(Occur::Should, term("title", "brown")),
+        ])),
+        Clause::new(Occur::Must, Node::Bool(vec![
+            Clause::new(Occur::Should, term("lang", "en")),
+            Clause::new(Occur::Should, term("lang", "de")),
+        ])),
+    ]));
+}
+
+#[test]
+fn parse_values_test() {
+    assert_eq!(parse("title:\"quick \\\"brown\\\" fox\"~3").unwrap(), Node::Phrase {
+        field: Some("title".into()),
+        text: "quick \"brown\" fox".into(),
+        slop: Some(3),
+    });
+    assert_eq!(parse("\"quick fox\"").unwrap(), Node::Phrase { field: None, text: "quick fox".into(), slop: None });
+
+    assert_eq!(parse("name:jonh~1").unwrap(), Node::Fuzzy { field: Some("name".into()), value: "jonh".into(), distance: Some(1) });
+    assert_eq!(parse("name:jonh~").unwrap(), Node::Fuzzy { field: Some("name".into()), value: "jonh".into(), distance: None });
+
+    assert_eq!(parse("name:jo?n*").unwrap(), Node::Wildcard { field: Some("name".into()), pattern: "jo?n*".into() });
+    assert_eq!(parse("name:jo\\*n*").unwrap(), Node::Wildcard { field: Some("name".into()), pattern: "jo\\*n*".into() });
+    assert_eq!(parse("name:c\\+\\+").unwrap(), term("name", "c++"));
+    assert_eq!(parse("url:http\\://example.com").unwrap(), term("url", "http://example.com"));
+
+    assert_eq!(parse("name:/jo.*n\\/x/").unwrap(), Node::Regexp { field: Some("name".into()), pattern: "jo.*n/x".into() });
+
+    assert_eq!(parse("_exists_:title").unwrap(), Node::Exists { field: "title".into() });
+    assert_eq!(parse("title:*").unwrap(), Node::Exists { field: "title".into() });
+    assert_eq!(parse("*:*").unwrap(), Node::MatchAll);
+}
+
+#[test]
+fn parse_ranges_test() {
+    assert_eq!(parse("price:[10 TO 20}").unwrap(), Node::Range {
+        field: Some("price".into()),
+        lower: Bound::Inclusive("10".into()),
+        upper: Bound::Exclusive("20".into()),
+    });
+    assert_eq!(parse("date:{* TO \"2024-01-01 10:00\"]").unwrap(), Node::Range {
+        field: Some("date".into()),
+        lower: Bound::Unbounded,
+        upper: Bound::Inclusive("2024-01-01 10:00".into()),
+    });
+    assert_eq!(parse("price:>=10").unwrap(), Node::Range {
+        field: Some("price".into()),
+        lower: Bound::Inclusive("10".into()),
+        upper: Bound::Unbounded,
+    });
+    assert_eq!(parse("price:<20").unwrap(), Node::Range {
+        field: Some("price".into()),
+        lower: Bound::Unbounded,
+        upper: Bound::Exclusive("20".into()),
+    });
+}
+
+#[test]
+fn parse_boost_test() {
+    assert_eq!(parse("title:wind^2").unwrap(), Node::Boost(Box::new(term("title", "wind")), 2.0));
+    assert_eq!(parse("(wind rain)^1.5").unwrap(), Node::Boost(Box::new(Node::Bool(vec![
+        Clause::new(Occur::Should, word("wind")),
+        Clause::new(Occur::Should, word("rain")),
+    ])), 1.5));
+}
+
+#[test]
+fn parse_errors_test() {
+    for (query, position) in [
+        ("", 0),
+        ("title:(wind", 11),
+        ("title:\"wind", 6),
+        ("wind AND", 8),
+        ("OR wind", 0),
+        ("price:[10 20]", 10),
+        ("wind)", 4),
+        ("title:wind^x", 11),
+        ("()", 2),
+    ] {
+        let error = parse(query).unwrap_err();
+        assert_eq!(error.position(), position, "{query}: {error}");
+    }
+}
+
+#[test]
+fn parse_depth_limit_test() {
+    let query = format!("{}wind{}", "(".repeat(256), ")".repeat(256));
+    assert_eq!(parse(&query).unwrap(), word("wind"));
+
+    let error = parse(&format!("{}wind{}", "(".repeat(257), ")".repeat(257))).unwrap_err();
+    assert_eq!(error.position(), 257);
+    assert_eq!(error.message(), "query is nested deeper than 256 levels");
+
+    assert!(parse(&"(".repeat(100_000)).is_err());
+    assert!(parse(&"a:".repeat(100_000)).is_err());
+}
+
+#[test]
+fn convert_query_test() {
+    let node = parse("title:\"quick fox\"~2^2 AND status:active AND -status:draft* AND price:[10 TO 20.5} AND (name:jonh~1 OR name:/jo.*/ OR _exists_:nick) AND rain").unwrap();
+
+    let query = Converter::new()
+        .default_field("body")
+        .keyword_fields(["status"])
+        .convert(&node)
+        .unwrap();
+
+    let json_expected = json!({
+        "bool": {
+            "must": [
+                { "match_phrase": { "title": { "query": "quick fox", "slop": 2, "boost": 2.0 } } },
+                { "term": { "status": { "value": "active" } } },
+                { "range": { "price": { "gte": "10", "lt": "20.5" } } },
+                {
+                    "bool": {
+                        "should": [
+                            { "fuzzy": { "name": { "value": "jonh", "fuzziness": 1 } } },
+                            { "regexp": { "name": { "value": "jo.*" } } },
+                            { "exists": { "field": "nick" } }
+                        ]
+                    }
+                },
+                { "match": { "body": { "query": "rain" } } }
+            ],
+            "must_not": [
+                { "wildcard": { "status": { "value": "draft*" } } }
+            ]
+        }
+    });
+
+    assert_eq!(json_expected, json!(query));
+}
+
+#[test]
+fn convert_boosted_group_test() {
+    let node = parse("(wind rain)^2 *:*").unwrap();
+    let query = Converter::new().default_field("title").convert(&node).unwrap();
+
+    let json_expected = json!({
+        "bool": {
+            "should": [
+                {
+                    "bool": {
+                        "should": [
+                            { "match": { "title": { "query": "wind" } } },
+                            { "match": { "title": { "query": "rain" } } }
+                        ],
+                        "boost": 2.0
+                    }
+                },
+                { "bool": {} }
+            ]
+        }
+    });
+
+    assert_eq!(json_expected, json!(query));
+}
+
+#[test]
+fn convert_range_bounds_test() {
+    let node = parse("date:[2020 TO 2021] code:{007 TO 010]").unwrap();
+    let query = Converter::new().convert(&node).unwrap();
+
+    let json_expected = json!({
+        "bool": {
+            "should": [
+                { "range": { "date": { "gte": "2020", "lte": "2021" } } },
+                { "range": { "code": { "gt": "007", "lte": "010" } } }
+            ]
+        }
+    });
+
+    assert_eq!(json_expected, json!(query));
+}
+
+#[test]
+fn convert_fields_test() {
+    let node = parse("title:wind OR secret:rain").unwrap();
+
+    let error = Converter::new()
+        .allowed_fields(["title"])
+        .convert(&node)
+        .unwrap_err();
+    assert_eq!(error, ConvertError::FieldNotAllowed("secret".into()));
+
+    let error = Converter::new().convert(&parse("wind").unwrap()).unwrap_err();
+    assert_eq!(error, ConvertError::MissingField);
+
+    let error = Converter::new()
+        .allowed_fields(["title"])
+        .convert(&parse("_exists_:secret").unwrap())
+        .unwrap_err();
+    assert_eq!(error, ConvertError::FieldNotAllowed("secret".into()));
+}
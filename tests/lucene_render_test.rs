@@ -0,0 +1,139 @@
+use os_query_builder_rs::compound_query::{bool::Bool, boosting::Boosting};
+use os_query_builder_rs::full_text::{
+    match_phrase::MatchPhrase,
+    multi_match::MultiMatch,
+    r#match::Match
+};
+use os_query_builder_rs::lucene::{
+    convert::Converter,
+    parser::parse,
+    render::RenderError
+};
+use os_query_builder_rs::misc::{
+    fuzziness::Fuzziness,
+    minimum_should_match::MinimumShouldMatch,
+    operator::Operator,
+    query_field::QueryField
+};
+use os_query_builder_rs::term::{
+    exists::Exists,
+    fuzzy::Fuzzy,
+    prefix::Prefix,
+    range::Range,
+    regexp::Regexp,
+    term::Term,
+    terms::Terms,
+    wildcard::Wildcard
+};
+
+fn render<T: Into<QueryField>>(query: T) -> String {
+    query.into().to_query_string().unwrap()
+}
+
+#[test]
+fn render_term_level_queries_test() {
+    assert_eq!(render(Term::new("status", "active")), "status:active");
+    assert_eq!(render(Term::new("price", 10).boost(2)), "price:10^2.0");
+    assert_eq!(render(Term::new("title", "c++ (beta)")), r#"title:"c++ (beta)""#);
+    assert_eq!(render(Term::new("url", "http://a.b")), r"url:http\:\/\/a.b");
+    assert_eq!(render(Terms::new_with_terms_query("brand", vec!["FIAT", "LADA"])), "brand:(FIAT OR LADA)");
+    assert_eq!(render(Prefix::new().field("name").value("jo*")), r"name:jo\**");
+    assert_eq!(render(Wildcard::new().field("name").value(r"jo?n*\*")), r"name:jo?n*\*");
+    assert_eq!(render(Regexp::new().field("path").value("a/b.*")), r"path:/a\/b.*/");
+    assert_eq!(render(Fuzzy::new().field("name").value("jonh").fuzziness(1u64)), "name:jonh~1");
+    assert_eq!(render(Fuzzy::new().field("name").value("jonh").fuzziness(Fuzziness::Auto)), "name:jonh~");
+    assert_eq!(render(Exists::new().field("title")), "_exists_:title");
+    assert_eq!(render(Range::new().field("price").gt(10)), "price:{10 TO *]");
+    assert_eq!(render(Range::new().field("date").gte("2024-01-01").lte("2024-12-31")), "date:[2024\\-01\\-01 TO 2024\\-12\\-31]");
+}
+
+#[test]
+fn render_full_text_queries_test() {
+    assert_eq!(render(Match::new().field("title").value("wind")), "title:wind");
+    assert_eq!(render(Match::new().field("title").value("quick brown fox")), "title:(quick OR brown OR fox)");
+    assert_eq!(render(Match::new().field("title").value("quick fox").operator(Operator::And).boost(2)), "title:(quick AND fox)^2.0");
+    assert_eq!(render(Match::new().field("title").value("jonh").fuzziness(Fuzziness::Auto)), "title:jonh~");
+    assert_eq!(render(MatchPhrase::new().field("title").value("say \"hi\"").slop(1u64)), r#"title:"say \"hi\""~1"#);
+}
+
+#[test]
+fn render_bool_test() {
+    let query = Bool::new()
+        .must(vec![Match::new().field("title").value("wind")])
+        .filter(vec![Bool::new().should(vec![Term::new("lang", "en"), Term::new("lang", "de")])])
+        .must_not(vec![Bool::new().must(vec![Term::new("status", "draft")])])
+        .should(vec![Term::new("brand", "FIAT")]);
+
+    assert_eq!(render(query), "+title:wind +(lang:en OR lang:de) -(+status:draft) brand:FIAT");
+
+    let query = Bool::new().should(vec![
+        QueryField::from(Term::new("brand", "FIAT")),
+        Bool::new().must(vec![Term::new("brand", "LADA"), Term::new("year", 2020)]).boost(2).into()
+    ]);
+    assert_eq!(render(query), "brand:FIAT OR (+brand:LADA +year:2020)^2.0");
+
+    assert_eq!(render(Bool::new().must_not(vec![Term::new("status", "draft")])), "*:* -status:draft");
+    assert_eq!(render(Bool::new()), "*:*");
+    assert_eq!(render(Bool::new().should(vec![Term::new("brand", "FIAT")])), "brand:FIAT");
+}
+
+#[test]
+fn render_round_trip_test() {
+    let query = QueryField::from(Bool::new()
+        .must(vec![QueryField::from(MatchPhrase::new().field("title").value("quick fox").slop(2u64).boost(2)),
+                   Range::new().field("price").gte("10").lt("20.5").into()])
+        .must_not(vec![Term::new("status", "draft")])
+        .should(vec![QueryField::from(Wildcard::new().field("name").value("jo*")), Exists::new().field("nick").into()]));
+
+    let rendered = query.to_query_string().unwrap();
+    let converted = Converter::new()
+        .keyword_fields(["status"])
+        .convert(&parse(&rendered).unwrap())
+        .unwrap();
+
+    assert_eq!(rendered, converted.to_query_string().unwrap());
+    assert_eq!(serde_json::to_value(&query).unwrap(), serde_json::to_value(&converted).unwrap());
+}
+
+#[test]
+fn render_errors_test() {
+    let error = QueryField::from(MultiMatch::new().fields(vec!["title"]).value("wind")).to_query_string().unwrap_err();
+    assert_eq!(error, RenderError::Unsupported("multi_match"));
+
+    let error = QueryField::from(Bool::new().must(vec![Boosting::new(Term::new("brand", "FIAT"), Term::new("year", 2010))])).to_query_string().unwrap_err();
+    assert_eq!(error, RenderError::Unsupported("boosting"));
+
+    let error = QueryField::from(Bool::new()
+        .should(vec![Term::new("brand", "FIAT"), Term::new("brand", "LADA")])
+        .minimum_should_match(MinimumShouldMatch::count(2)))
+        .to_query_string()
+        .unwrap_err();
+    assert_eq!(error, RenderError::UnsupportedParameter { query: "bool", parameter: "minimum_should_match".into() });
+
+    let error = QueryField::from(Term::new("status", "draft").case_insensitive(true)).to_query_string().unwrap_err();
+    assert_eq!(error.to_string(), "`case_insensitive` of `term` query can not be rendered as query_string");
+}
+
+#[test]
+fn render_empty_queries_test() {
+    let error = QueryField::from(Terms::new_with_terms_query("brand", Vec::<String>::new())).to_query_string().unwrap_err();
+    assert_eq!(error, RenderError::Empty("terms"));
+    assert_eq!(error.to_string(), "`terms` query without values can not be rendered as query_string");
+
+    let error = QueryField::from(Match::new().field("title").value(" ")).to_query_string().unwrap_err();
+    assert_eq!(error, RenderError::Empty("match"));
+}
+
+/// `term` renders as `field:value`, which query_string analyzes unless the field is a keyword
+#[test]
+fn render_term_is_analyzed_by_query_string_test() {
+    let rendered = render(Term::new("title", "Quick"));
+    assert_eq!(rendered, "title:Quick");
+
+    let parsed = parse(&rendered).unwrap();
+    let analyzed = Converter::new().convert(&parsed).unwrap();
+    assert_eq!(serde_json::to_value(&analyzed).unwrap(), serde_json::json!({ "match": { "title": { "query": "Quick" } } }));
+
+    let keyword = Converter::new().keyword_fields(["title"]).convert(&parsed).unwrap();
+    assert_eq!(serde_json::to_value(&keyword).unwrap(), serde_json::to_value(QueryField::from(Term::new("title", "Quick"))).unwrap());
+}
@@ -0,0 +1,237 @@
+use serde_json::json;
+use os_query_builder_rs::mapping::{
+    dynamic::{Dynamic, DynamicTemplate, MatchMappingType},
+    field::FieldMapping,
+    index_mapping::{IndexMapping, RoutingField, SourceField},
+    knn::{KnnEngine, KnnMethod, KnnVectorField, SpaceType},
+    numeric::{NumericField, ScaledFloatField},
+    object::{FlattenedField, JoinField, NestedField, ObjectField},
+    rank_feature::{RankFeatureField, RankFeaturesField},
+    scalar::{BooleanField, DateField, IpField},
+    geo::{GeoPointField, GeoShapeField},
+    text::{CompletionField, IndexOptions, KeywordField, SearchAsYouTypeField, TextField}
+};
+
+fn to_json<T: Into<FieldMapping>>(field: T) -> serde_json::Value {
+    serde_json::to_value(field.into()).unwrap()
+}
+
+#[test]
+fn text_fields_test() {
+    let title = TextField::new()
+        .analyzer("english")
+        .search_analyzer("english_search")
+        .copy_to(vec!["all"])
+        .field("raw", KeywordField::new().ignore_above(256u32))
+        .field("suggest", SearchAsYouTypeField::new().max_shingle_size(3u8));
+
+    assert_eq!(to_json(title), json!({
+        "type": "text",
+        "analyzer": "english",
+        "search_analyzer": "english_search",
+        "copy_to": ["all"],
+        "fields": {
+            "raw": { "type": "keyword", "ignore_above": 256 },
+            "suggest": { "type": "search_as_you_type", "max_shingle_size": 3 }
+        }
+    }));
+
+    assert_eq!(to_json(KeywordField::new().normalizer("lowercase").doc_values(false).null_value("NULL")),
+               json!({ "type": "keyword", "normalizer": "lowercase", "doc_values": false, "null_value": "NULL" }));
+    assert_eq!(to_json(CompletionField::new().analyzer("simple").preserve_separators(false).max_input_length(50u32)),
+               json!({ "type": "completion", "analyzer": "simple", "preserve_separators": false, "max_input_length": 50 }));
+}
+
+#[test]
+fn numeric_and_scalar_fields_test() {
+    assert_eq!(to_json(FieldMapping::Long(NumericField::new())), json!({ "type": "long" }));
+    assert_eq!(to_json(FieldMapping::Integer(NumericField::new().coerce(false).null_value(0))),
+               json!({ "type": "integer", "coerce": false, "null_value": 0 }));
+    assert_eq!(to_json(FieldMapping::HalfFloat(NumericField::new().index(false))), json!({ "type": "half_float", "index": false }));
+    assert_eq!(to_json(FieldMapping::UnsignedLong(NumericField::new().ignore_malformed(true))),
+               json!({ "type": "unsigned_long", "ignore_malformed": true }));
+    assert_eq!(to_json(ScaledFloatField::new(100)), json!({ "type": "scaled_float", "scaling_factor": 100.0 }));
+
+    assert_eq!(to_json(FieldMapping::Date(DateField::new().format("yyyy-MM-dd||epoch_millis"))),
+               json!({ "type": "date", "format": "yyyy-MM-dd||epoch_millis" }));
+    assert_eq!(to_json(FieldMapping::DateNanos(DateField::new())), json!({ "type": "date_nanos" }));
+    assert_eq!(to_json(BooleanField::new().null_value(false)), json!({ "type": "boolean", "null_value": false }));
+    assert_eq!(to_json(IpField::new().store(true)), json!({ "type": "ip", "store": true }));
+}
+
+#[test]
+fn geo_and_structured_fields_test() {
+    assert_eq!(to_json(GeoPointField::new().ignore_z_value(true)), json!({ "type": "geo_point", "ignore_z_value": true }));
+    assert_eq!(to_json(GeoShapeField::new().orientation("left").coerce(true)),
+               json!({ "type": "geo_shape", "orientation": "left", "coerce": true }));
+
+    let variants = NestedField::new()
+        .include_in_parent(true)
+        .property("sku", KeywordField::new())
+        .property("price", ScaledFloatField::new(100));
+    assert_eq!(to_json(variants), json!({
+        "type": "nested",
+        "include_in_parent": true,
+        "properties": {
+            "price": { "type": "scaled_float", "scaling_factor": 100.0 },
+            "sku": { "type": "keyword" }
+        }
+    }));
+
+    assert_eq!(to_json(ObjectField::new().enabled(false)), json!({ "type": "object", "enabled": false }));
+    assert_eq!(to_json(ObjectField::new().dynamic(false).property("name", TextField::new())),
+               json!({ "type": "object", "dynamic": false, "properties": { "name": { "type": "text" } } }));
+    assert_eq!(to_json(FlattenedField::new()), json!({ "type": "flat_object" }));
+
+    let join = JoinField::new()
+        .relation("question", ["answer", "comment"])
+        .relation("answer", ["vote"]);
+    assert_eq!(to_json(join), json!({
+        "type": "join",
+        "relations": { "answer": ["vote"], "question": ["answer", "comment"] }
+    }));
+
+    assert_eq!(to_json(RankFeatureField::new().positive_score_impact(false)),
+               json!({ "type": "rank_feature", "positive_score_impact": false }));
+    assert_eq!(to_json(RankFeaturesField::new()), json!({ "type": "rank_features" }));
+}
+
+#[test]
+fn knn_vector_field_test() {
+    let embedding = KnnVectorField::new(768u32)
+        .method(KnnMethod::new("hnsw")
+            .engine(KnnEngine::Faiss)
+            .space_type(SpaceType::Innerproduct)
+            .parameter("ef_construction", 128)
+            .parameter("m", 24));
+
+    assert_eq!(to_json(embedding), json!({
+        "type": "knn_vector",
+        "dimension": 768,
+        "method": {
+            "name": "hnsw",
+            "engine": "faiss",
+            "space_type": "innerproduct",
+            "parameters": { "ef_construction": 128, "m": 24 }
+        }
+    }));
+
+    assert_eq!(to_json(KnnVectorField::new(3u32).model_id("my-model")),
+               json!({ "type": "knn_vector", "dimension": 3, "model_id": "my-model" }));
+}
+
+#[test]
+fn index_mapping_test() {
+    let mapping = IndexMapping::new()
+        .dynamic(Dynamic::StrictAllowTemplates)
+        .dynamic_template("strings_as_keywords", DynamicTemplate::new(KeywordField::new())
+            .match_mapping_type(MatchMappingType::String)
+            .match_name("*_id"))
+        .dynamic_template("labels", DynamicTemplate::new(KeywordField::new())
+            .path_match("labels.*")
+            .path_unmatch("labels.internal.*")
+            .unmatch("*_text"))
+        .source(SourceField::new().excludes(vec!["embedding"]))
+        .routing(RoutingField::new().required(true))
+        .property("brand", KeywordField::new())
+        .property("year", FieldMapping::Short(NumericField::new()));
+
+    let expected = json!({
+        "dynamic": "strict_allow_templates",
+        "dynamic_templates": [
+            { "strings_as_keywords": { "match_mapping_type": "string", "match": "*_id", "mapping": { "type": "keyword" } } },
+            { "labels": { "unmatch": "*_text", "path_match": "labels.*", "path_unmatch": "labels.internal.*", "mapping": { "type": "keyword" } } }
+        ],
+        "_source": { "excludes": ["embedding"] },
+        "_routing": { "required": true },
+        "properties": {
+            "brand": { "type": "keyword" },
+            "year": { "type": "short" }
+        }
+    });
+
+    assert_eq!(serde_json::to_value(&mapping).unwrap(), expected);
+    assert_eq!(mapping.get_property("year").unwrap().type_name(), "short");
+    assert!(mapping.get_property("price").is_none());
+
+    assert_eq!(serde_json::to_value(IndexMapping::new().dynamic(true)).unwrap(), json!({ "dynamic": true }));
+    assert_eq!(serde_json::to_value(IndexMapping::new()).unwrap(), json!({}));
+}
+
+#[test]
+fn index_mapping_deserialize_test() {
+    let value = json!({
+        "dynamic": "false",
+        "properties": {
+            "title": { "type": "text", "fields": { "raw": { "type": "keyword" } } },
+            "attributes": { "type": "flattened" },
+            "location": { "type": "geo_point" },
+            "variants": { "type": "nested", "properties": { "price": { "type": "double" } } }
+        }
+    });
+
+    let mapping: IndexMapping = serde_json::from_value(value).unwrap();
+    assert_eq!(mapping.get_property("attributes").unwrap().type_name(), "flat_object");
+    assert_eq!(serde_json::to_value(&mapping).unwrap()["dynamic"], json!(false));
+    assert_eq!(serde_json::to_value(&mapping).unwrap()["properties"]["variants"],
+               json!({ "type": "nested", "properties": { "price": { "type": "double" } } }));
+
+    assert!(serde_json::from_value::<IndexMapping>(json!({ "dynamic": "sometimes" })).is_err());
+    assert!(serde_json::from_value::<IndexMapping>(json!({ "properties": { "a": { "type": "unknown" } } })).is_err());
+}
+
+#[test]
+fn raw_dynamic_template_test() {
+    let mapping = IndexMapping::new()
+        .dynamic_template("no_doc_values", DynamicTemplate::raw(json!({ "doc_values": false }))
+            .match_name("*_blob"))
+        .dynamic_template("unindexed", DynamicTemplate::raw(json!({ "type": "{dynamic_type}", "index": false }))
+            .path_match("raw.*"));
+
+    let expected = json!({
+        "dynamic_templates": [
+            { "no_doc_values": { "match": "*_blob", "mapping": { "doc_values": false } } },
+            { "unindexed": { "path_match": "raw.*", "mapping": { "type": "{dynamic_type}", "index": false } } }
+        ]
+    });
+    assert_eq!(serde_json::to_value(&mapping).unwrap(), expected);
+
+    let mapping: IndexMapping = serde_json::from_value(expected.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&mapping).unwrap(), expected);
+
+    let template: DynamicTemplate = serde_json::from_value(json!({ "mapping": { "type": "keyword" } })).unwrap();
+    assert_eq!(template.get_mapping().unwrap().type_name(), "keyword");
+    let template: DynamicTemplate = serde_json::from_value(json!({ "mapping": { "type": "{dynamic_type}" } })).unwrap();
+    assert!(template.get_mapping().is_none());
+}
+
+#[test]
+fn get_mapping_output_test() {
+    let mapping: IndexMapping = serde_json::from_value(json!({
+        "properties": {
+            "owner": {
+                "properties": {
+                    "name": { "type": "text", "norms": false, "index_options": "offsets" },
+                    "login": { "type": "keyword", "norms": true }
+                }
+            }
+        }
+    })).unwrap();
+
+    let owner = mapping.get_property("owner").unwrap();
+    assert_eq!(owner.type_name(), "object");
+    assert_eq!(serde_json::to_value(owner).unwrap(), json!({
+        "type": "object",
+        "properties": {
+            "name": { "type": "text", "norms": false, "index_options": "offsets" },
+            "login": { "type": "keyword", "norms": true }
+        }
+    }));
+
+    let error = serde_json::from_value::<FieldMapping>(json!({ "type": "text", "similarity": "BM25" })).unwrap_err();
+    assert!(error.to_string().contains("similarity"), "{error}");
+    assert!(serde_json::from_value::<FieldMapping>(json!({ "analyzer": "standard" })).is_err());
+
+    let text = TextField::new().norms(false).index_options(IndexOptions::Positions);
+    assert_eq!(to_json(text), json!({ "type": "text", "norms": false, "index_options": "positions" }));
+}
@@ -1,4 +1,5 @@
 use serde_json::json;
+use os_query_builder_rs::misc::minimum_should_match::MinimumShouldMatch;
 use os_query_builder_rs::full_text::match_boolean_prefix::MatchBoolPrefix;
 use os_query_builder_rs::misc::{
     fuzziness::Fuzziness,
@@ -63,7 +64,7 @@ fn match_bool_prefix_with_all_fields() {
         .query("Автозапчасть")
         .operator(Operator::And)
         .fuzziness(Fuzziness::Auto)
-        .minimum_should_match("90%")
+        .minimum_should_match(MinimumShouldMatch::percentage(90).unwrap())
         .prefix_length(200u64)
         .max_expansions(50u64)
         .fuzzy_rewrite(Rewrite::ConstantScore)
@@ -0,0 +1,127 @@
+use serde_json::json;
+use os_query_builder_rs::compound_query::bool::Bool;
+use os_query_builder_rs::full_text::r#match::Match;
+use os_query_builder_rs::misc::minimum_should_match::{CombinationValue, MinimumShouldMatch};
+use os_query_builder_rs::misc::validate::Validate;
+use os_query_builder_rs::term::term::Term;
+
+#[test]
+fn minimum_should_match_render_test() {
+    assert_eq!(MinimumShouldMatch::count(3).to_string(), "3");
+    assert_eq!(MinimumShouldMatch::count(-2).to_string(), "-2");
+    assert_eq!(MinimumShouldMatch::percentage(75).unwrap().to_string(), "75%");
+    assert_eq!(MinimumShouldMatch::percentage(-25).unwrap().to_string(), "-25%");
+    assert_eq!(MinimumShouldMatch::combination(3, CombinationValue::percentage(90).unwrap()).to_string(), "3<90%");
+
+    let combination = MinimumShouldMatch::combination(2, -25)
+        .and_combination(9, CombinationValue::percentage(-3).unwrap());
+    assert_eq!(combination.to_string(), "2<-25 9<-3%");
+}
+
+#[test]
+fn minimum_should_match_and_combination_keeps_single_value_test() {
+    let combination = MinimumShouldMatch::count(2).and_combination(3, CombinationValue::percentage(75).unwrap());
+    assert_eq!(combination.to_string(), "0<2 3<75%");
+
+    let combination = MinimumShouldMatch::percentage(50).unwrap().and_combination(4, -1);
+    assert_eq!(combination.to_string(), "0<50% 4<-1");
+    assert_eq!(combination, "0<50% 4<-1".parse().unwrap());
+}
+
+#[test]
+fn minimum_should_match_invalid_percentage_test() {
+    let err = MinimumShouldMatch::percentage(150).unwrap_err();
+    assert_eq!(err.to_string(), "invalid minimum_should_match `150%`");
+    assert!(CombinationValue::percentage(-101).is_err());
+    assert!(CombinationValue::percentage(-100).is_ok());
+}
+
+#[test]
+fn minimum_should_match_from_string_test() {
+    let query = Match::new()
+        .field("title")
+        .value("quick brown fox")
+        .minimum_should_match("90%");
+
+    assert_eq!(json!(query), json!({
+        "title": {
+            "query": "quick brown fox",
+            "minimum_should_match": "90%"
+        }
+    }));
+    assert!(query.validate().is_ok());
+
+    let query = Bool::new()
+        .should(vec![Term::new("brand", "FIAT")])
+        .minimum_should_match(String::from("90"));
+    assert_eq!(json!(query)["minimum_should_match"], json!(90));
+
+    let query = Match::new()
+        .field("title")
+        .value("quick brown fox")
+        .minimum_should_match("90 %");
+    assert_eq!(json!(query)["title"]["minimum_should_match"], json!("90 %"));
+
+    let errors = query.validate().unwrap_err();
+    assert_eq!(errors.to_string(), "/title/minimum_should_match: invalid minimum_should_match `90 %`");
+}
+
+#[test]
+fn minimum_should_match_parse_test() {
+    assert_eq!("3".parse(), Ok(MinimumShouldMatch::Count(3)));
+    assert_eq!(" -2 ".parse(), Ok(MinimumShouldMatch::Count(-2)));
+    assert_eq!("90%".parse(), Ok(MinimumShouldMatch::Percentage(90)));
+    assert_eq!("-25%".parse(), Ok(MinimumShouldMatch::Percentage(-25)));
+
+    let combination: MinimumShouldMatch = "2<-25 9<-3%".parse().unwrap();
+    assert_eq!(combination, MinimumShouldMatch::combination(2, -25).and_combination(9, CombinationValue::percentage(-3).unwrap()));
+
+    for invalid in ["", "90 %", "%", "150%", "ninety", "3<", "<90%", "3<90%<2", "-3<90%"] {
+        assert!(invalid.parse::<MinimumShouldMatch>().is_err(), "{invalid}");
+    }
+}
+
+#[test]
+fn minimum_should_match_serialize_test() {
+    let query = Bool::new()
+        .should(vec![Term::new("brand", "FIAT"), Term::new("brand", "LADA")])
+        .minimum_should_match(MinimumShouldMatch::combination(1, CombinationValue::percentage(50).unwrap()));
+
+    let json_expected = json!({
+        "bool": {
+            "should": [
+                { "term": { "brand": { "value": "FIAT" } } },
+                { "term": { "brand": { "value": "LADA" } } }
+            ],
+            "minimum_should_match": "1<50%"
+        }
+    });
+
+    assert_eq!(json_expected, json!({ "bool": query }));
+
+    let query = Match::new()
+        .field("title")
+        .value("quick brown fox")
+        .minimum_should_match(-1);
+
+    let json_expected = json!({
+        "title": {
+            "query": "quick brown fox",
+            "minimum_should_match": -1
+        }
+    });
+
+    assert_eq!(json_expected, json!(query));
+}
+
+#[test]
+fn minimum_should_match_deserialize_test() {
+    let value: MinimumShouldMatch = serde_json::from_value(json!(2)).unwrap();
+    assert_eq!(value, MinimumShouldMatch::Count(2));
+
+    let value: MinimumShouldMatch = serde_json::from_value(json!("3<90%")).unwrap();
+    assert_eq!(value, MinimumShouldMatch::combination(3, CombinationValue::percentage(90).unwrap()));
+
+    assert!(serde_json::from_value::<MinimumShouldMatch>(json!("90 percent")).is_err());
+    assert!(serde_json::from_value::<MinimumShouldMatch>(json!(1.5)).is_err());
+}
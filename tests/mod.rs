@@ -37,4 +37,52 @@ mod range_test;
 #[cfg(test)]
 mod intervals_test;
 #[cfg(test)]
-mod intervals_rule_test;
\ No newline at end of file
+mod intervals_rule_test;
+#[cfg(test)]
+mod query_test;
+#[cfg(test)]
+mod runtime_field_test;
+#[cfg(test)]
+mod search_response_test;
+#[cfg(test)]
+mod aggregations_response_test;
+#[cfg(test)]
+mod deserialize_test;
+#[cfg(test)]
+mod validate_test;
+#[cfg(test)]
+mod visit_test;
+#[cfg(test)]
+mod normalize_test;
+#[cfg(test)]
+mod query_ops_test;
+#[cfg(test)]
+mod compound_query_bool_append_test;
+#[cfg(test)]
+mod minimum_should_match_test;
+#[cfg(test)]
+mod lucene_parser_test;
+#[cfg(test)]
+mod simple_query_string_parser_test;
+#[cfg(test)]
+mod lucene_render_test;
+#[cfg(test)]
+mod bulk_test;
+#[cfg(test)]
+mod msearch_test;
+#[cfg(test)]
+mod by_query_test;
+#[cfg(test)]
+mod reindex_test;
+#[cfg(test)]
+mod mapping_test;
+#[cfg(test)]
+mod settings_test;
+#[cfg(test)]
+mod template_test;
+#[cfg(test)]
+mod ingest_test;
+#[cfg(test)]
+mod search_template_test;
+#[cfg(test)]
+mod scroll_test;
\ No newline at end of file
@@ -0,0 +1,149 @@
+use serde::Deserialize;
+use serde_json::json;
+use os_query_builder_rs::model::Query;
+use os_query_builder_rs::msearch::{
+    header::{MultiSearchHeader, SearchType},
+    request::MultiSearchRequest
+};
+use os_query_builder_rs::response::msearch::{MultiSearchError, MultiSearchResponse};
+use os_query_builder_rs::term::term::Term;
+
+#[derive(Debug, Deserialize)]
+struct Product {
+    brand: String,
+}
+
+#[test]
+fn msearch_request_test() {
+    let results = Query::new().query(Term::new("brand", "FIAT")).size(20usize);
+    let facet = Query::new().size(0usize).aggs(json!({ "years": { "terms": { "field": "year" } } }));
+
+    let msearch = MultiSearchRequest::new()
+        .search(MultiSearchHeader::new()
+                    .index("products")
+                    .preference("session-1")
+                    .routing("eu")
+                    .search_type(SearchType::DfsQueryThenFetch)
+                    .request_cache(false), results)
+        .search(MultiSearchHeader::new(), facet);
+
+    assert_eq!(msearch.len(), 2);
+    assert_eq!(msearch.to_ndjson(), concat!(
+        r#"{"index":"products","preference":"session-1","routing":"eu","search_type":"dfs_query_then_fetch","request_cache":false}"#, "\n",
+        r#"{"size":20,"query":{"term":{"brand":{"value":"FIAT"}}}}"#, "\n",
+        "{}\n",
+        r#"{"size":0,"aggs":{"years":{"terms":{"field":"year"}}}}"#, "\n",
+    ));
+}
+
+#[test]
+fn msearch_from_iterator_test() {
+    let msearch: MultiSearchRequest = ["products", "cars"]
+        .into_iter()
+        .map(|index| (index, Query::new()))
+        .collect();
+
+    assert!(!msearch.is_empty());
+    assert_eq!(msearch.to_ndjson(), "{\"index\":\"products\"}\n{}\n{\"index\":\"cars\"}\n{}\n");
+    assert_eq!(MultiSearchRequest::new().to_ndjson(), "");
+}
+
+#[test]
+fn msearch_response_test() {
+    let response: MultiSearchResponse<Product> = serde_json::from_value(json!({
+        "took": 14,
+        "responses": [
+            {
+                "took": 4, "timed_out": false, "status": 200,
+                "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+                "hits": {
+                    "total": { "value": 1, "relation": "eq" },
+                    "max_score": 1.0,
+                    "hits": [{ "_index": "products", "_id": "1", "_score": 1.0, "_source": { "brand": "FIAT" } }]
+                }
+            },
+            {
+                "error": {
+                    "root_cause": [{ "type": "index_not_found_exception", "reason": "no such index [cars]" }],
+                    "type": "index_not_found_exception",
+                    "reason": "no such index [cars]"
+                },
+                "status": 404
+            },
+            {
+                "took": 2, "timed_out": false, "status": 200,
+                "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+                "hits": { "total": { "value": 7, "relation": "eq" }, "max_score": null, "hits": [] },
+                "aggregations": { "years": { "doc_count_error_upper_bound": 0, "sum_other_doc_count": 0, "buckets": [] } }
+            }
+        ]
+    })).unwrap();
+
+    assert_eq!(response.took, Some(14));
+    assert_eq!(response.responses.len(), 3);
+
+    let first = response.responses[0].as_ref().unwrap();
+    assert_eq!(first.sources().map(|x| x.brand.as_str()).collect::<Vec<_>>(), vec!["FIAT"]);
+    assert!(response.responses[2].as_ref().unwrap().aggregations.is_some());
+    assert_eq!(response.successes().count(), 2);
+
+    let failure = response.failures().next().unwrap();
+    assert_eq!(failure.status(), Some(404));
+    assert_eq!(failure.error().unwrap().error_type, "index_not_found_exception");
+    assert_eq!(failure.error().unwrap().reason.as_deref(), Some("no such index [cars]"));
+}
+
+#[test]
+fn msearch_response_with_invalid_item_test() {
+    let response: MultiSearchResponse<Product> = serde_json::from_value(json!({
+        "responses": [
+            {
+                "took": 4, "timed_out": false, "status": 200,
+                "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+                "hits": { "hits": [{ "_index": "products", "_id": "1", "_source": { "brand": "FIAT" } }] }
+            },
+            {
+                "took": 3, "timed_out": false, "status": 200,
+                "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+                "hits": { "hits": [{ "_index": "cars", "_id": "1", "_source": { "model": "Niva" } }] }
+            },
+            { "took": 1 }
+        ]
+    })).unwrap();
+
+    assert_eq!(response.successes().count(), 1);
+    assert!(matches!(&response.responses[1], Err(MultiSearchError::Parse(error)) if error.contains("missing field `brand`")));
+    assert!(matches!(&response.responses[2], Err(MultiSearchError::Parse(_))));
+    assert_eq!(response.failures().filter_map(|x| x.status()).count(), 0);
+
+    let invalid = serde_json::from_value::<MultiSearchResponse>(json!({ "responses": {} }));
+    assert!(invalid.is_err());
+}
+
+#[test]
+fn msearch_response_keeps_keyed_buckets_order_test() {
+    let response: MultiSearchResponse = serde_json::from_str(r#"{
+        "responses": [
+            {
+                "took": 2, "timed_out": false, "status": 200,
+                "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+                "hits": { "hits": [] },
+                "aggregations": {
+                    "levels": {
+                        "buckets": {
+                            "warnings": { "doc_count": 2 },
+                            "errors": { "doc_count": 1 },
+                            "info": { "doc_count": 5 }
+                        }
+                    }
+                }
+            }
+        ]
+    }"#).unwrap();
+
+    let levels = response.responses[0].as_ref().unwrap()
+        .aggregations.as_ref().unwrap()
+        .buckets("levels").unwrap();
+    let keys: Vec<_> = levels.buckets.iter().map(|x| x.key.as_str().unwrap()).collect();
+    assert_eq!(keys, ["warnings", "errors", "info"]);
+}
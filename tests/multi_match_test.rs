@@ -2,6 +2,7 @@ use serde_json::json;
 use os_query_builder_rs::{
     full_text::multi_match::MultiMatch,
     misc::{
+        minimum_should_match::MinimumShouldMatch,
         operator::Operator,
         r#type::Type,
         zero_terms_query::ZeroTermsQuery
@@ -17,7 +18,7 @@ fn multi_match_test() {
         .operator(Operator::And)
         .query_type(Type::BestFields)
         .boost(2)
-        .minimum_should_match("90%");
+        .minimum_should_match(MinimumShouldMatch::percentage(90).unwrap());
     let json_str = json!({"boost":2.0,"fields":["brands","articles"],"minimum_should_match":"90%","operator":"and","query":"oc47","type":"best_fields"});
     let json = json!(multi_match);
     assert_eq!(json_str, json);
@@ -33,7 +34,7 @@ fn multi_match_all_fields_test() {
         .operator(Operator::And)
         .query_type(Type::BestFields)
         .boost(2)
-        .minimum_should_match("90%")
+        .minimum_should_match(MinimumShouldMatch::percentage(90).unwrap())
         .lenient(true)
         .zero_terms_query(ZeroTermsQuery::All)
         .fuzzy_transpositions(false)
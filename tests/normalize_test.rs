@@ -0,0 +1,248 @@
+use serde_json::json;
+use os_query_builder_rs::compound_query::{
+    bool::Bool,
+    constant_score::ConstantScore
+};
+use os_query_builder_rs::full_text::r#match::Match;
+use os_query_builder_rs::misc::normalize::Normalizer;
+use os_query_builder_rs::misc::query_field::QueryField;
+use os_query_builder_rs::term::{
+    range::Range,
+    term::Term
+};
+
+#[test]
+fn flatten_nested_bool_test() {
+    let query = Bool::new()
+        .must(vec![
+            QueryField::from(Bool::new().must(vec![Match::new().field("title").value("wind")])),
+            Bool::new()
+                .must(vec![Match::new().field("body").value("rain")])
+                .filter(vec![Term::new("lang", "en")])
+                .into()
+        ])
+        .filter(vec![Bool::new().filter(vec![Bool::new().filter(vec![Range::new().field("price").gte(10)])])]);
+
+    let json_actual = json!(Normalizer::new().normalize(query));
+    let json_expected = json!({
+        "bool": {
+            "must": [
+                { "match": { "title": { "query": "wind" } } },
+                { "match": { "body": { "query": "rain" } } }
+            ],
+            "filter": [
+                { "term": { "lang": { "value": "en" } } },
+                { "range": { "price": { "gte": 10 } } }
+            ]
+        }
+    });
+
+    assert_eq!(json_expected, json_actual);
+}
+
+#[test]
+fn single_filter_is_kept_in_scoring_context_test() {
+    let query = Bool::new().filter(vec![Term::new("brand", "FIAT")]);
+
+    let json_actual = json!(Normalizer::new().normalize(query));
+    let json_expected = json!({
+        "bool": {
+            "filter": [{ "term": { "brand": { "value": "FIAT" } } }]
+        }
+    });
+
+    assert_eq!(json_expected, json_actual);
+}
+
+#[test]
+fn drop_empty_bool_and_duplicates_test() {
+    let query = Bool::new()
+        .must(vec![QueryField::from(Match::new().field("title").value("wind")), Bool::new().into()])
+        .filter(vec![
+            QueryField::from(Term::new("brand", "FIAT")),
+            Bool::new().into(),
+            Term::new("brand", "FIAT").into()
+        ]);
+
+    let json_actual = json!(Normalizer::new().normalize(query));
+    let json_expected = json!({
+        "bool": {
+            "must": [{ "match": { "title": { "query": "wind" } } }],
+            "filter": [{ "term": { "brand": { "value": "FIAT" } } }]
+        }
+    });
+
+    assert_eq!(json_expected, json_actual);
+}
+
+#[test]
+fn empty_bool_in_should_matches_all_test() {
+    let query = Bool::new().should(vec![QueryField::from(Term::new("brand", "FIAT")), Bool::new().into()]);
+
+    let json_actual = json!(Normalizer::new().normalize(query));
+    let json_expected = json!({
+        "bool": {
+            "should": [
+                { "term": { "brand": { "value": "FIAT" } } },
+                { "bool": {} }
+            ]
+        }
+    });
+
+    assert_eq!(json_expected, json_actual);
+}
+
+#[test]
+fn merge_terms_test() {
+    let query = Bool::new()
+        .must(vec![Match::new().field("title").value("wind")])
+        .filter(vec![
+            QueryField::from(Term::new("color", "red")),
+            Term::new("color", "blue").into(),
+            Bool::new().should(vec![
+                QueryField::from(Term::new("brand", "FIAT")),
+                Term::new("size", 42).into(),
+                Term::new("brand", "LADA").into()
+            ]).into()
+        ])
+        .must_not(vec![
+            QueryField::from(Term::new("status", "deleted")),
+            Term::new("status", "hidden").into(),
+            Term::new("status", "draft").boost(2).into()
+        ]);
+
+    let json_actual = json!(Normalizer::new().normalize(query));
+    let json_expected = json!({
+        "bool": {
+            "must": [{ "match": { "title": { "query": "wind" } } }],
+            "filter": [
+                { "term": { "color": { "value": "red" } } },
+                { "term": { "color": { "value": "blue" } } },
+                {
+                    "bool": {
+                        "should": [
+                            { "terms": { "brand": ["FIAT", "LADA"] } },
+                            { "term": { "size": { "value": 42 } } }
+                        ]
+                    }
+                }
+            ],
+            "must_not": [
+                { "terms": { "status": ["deleted", "hidden"] } },
+                { "term": { "status": { "value": "draft", "boost": 2.0 } } }
+            ]
+        }
+    });
+
+    assert_eq!(json_expected, json_actual);
+}
+
+#[test]
+fn must_to_filter_test() {
+    let query = Bool::new().must(vec![
+        QueryField::from(Match::new().field("title").value("wind")),
+        Term::new("brand", "FIAT").into(),
+        Range::new().field("price").lte(100).into(),
+        ConstantScore::new(Bool::new().filter(vec![Term::new("lang", "en")])).into()
+    ]);
+
+    let json_actual = json!(Normalizer::new().must_to_filter(true).normalize(query));
+    let json_expected = json!({
+        "bool": {
+            "must": [{ "match": { "title": { "query": "wind" } } }],
+            "filter": [
+                { "term": { "brand": { "value": "FIAT" } } },
+                { "range": { "price": { "lte": 100 } } },
+                { "constant_score": { "filter": { "term": { "lang": { "value": "en" } } } } }
+            ]
+        }
+    });
+
+    assert_eq!(json_expected, json_actual);
+}
+
+#[test]
+fn boosted_bool_is_kept_test() {
+    let query = Bool::new().must(vec![
+        Bool::new()
+            .must(vec![Term::new("brand", "FIAT"), Term::new("lang", "en")])
+            .boost(2),
+        Bool::new()
+            .should(vec![Term::new("brand", "LADA")])
+            .boost(3)
+    ]);
+
+    let json_actual = json!(Normalizer::new().normalize(query));
+    let json_expected = json!({
+        "bool": {
+            "must": [
+                {
+                    "bool": {
+                        "must": [
+                            { "term": { "brand": { "value": "FIAT" } } },
+                            { "term": { "lang": { "value": "en" } } }
+                        ],
+                        "boost": 2.0
+                    }
+                },
+                {
+                    "bool": {
+                        "should": [{ "term": { "brand": { "value": "LADA" } } }],
+                        "boost": 3.0
+                    }
+                }
+            ]
+        }
+    });
+
+    assert_eq!(json_expected, json_actual);
+}
+
+#[test]
+fn should_stays_optional_after_dropping_empty_bool_test() {
+    let query = Bool::new()
+        .must(vec![Bool::new()])
+        .should(vec![Term::new("brand", "FIAT")]);
+
+    let json_actual = json!(Normalizer::new().normalize(query));
+    let json_expected = json!({
+        "bool": {
+            "should": [{ "term": { "brand": { "value": "FIAT" } } }],
+            "minimum_should_match": 0
+        }
+    });
+
+    assert_eq!(json_expected, json_actual);
+}
+
+#[test]
+fn should_stays_optional_after_lifting_must_not_test() {
+    let query = Bool::new()
+        .must(vec![Bool::new().must_not(vec![Term::new("status", "draft")])])
+        .should(vec![Term::new("brand", "FIAT")]);
+
+    let json_actual = json!(Normalizer::new().normalize(query));
+    let json_expected = json!({
+        "bool": {
+            "must_not": [{ "term": { "status": { "value": "draft" } } }],
+            "should": [{ "term": { "brand": { "value": "FIAT" } } }],
+            "minimum_should_match": 0
+        }
+    });
+
+    assert_eq!(json_expected, json_actual);
+
+    let query = Bool::new()
+        .filter(vec![Bool::new().must(vec![Term::new("lang", "en")])])
+        .should(vec![Term::new("brand", "FIAT")]);
+
+    let json_actual = json!(Normalizer::new().normalize(query));
+    let json_expected = json!({
+        "bool": {
+            "filter": [{ "term": { "lang": { "value": "en" } } }],
+            "should": [{ "term": { "brand": { "value": "FIAT" } } }]
+        }
+    });
+
+    assert_eq!(json_expected, json_actual);
+}
@@ -0,0 +1,139 @@
+use serde_json::json;
+use os_query_builder_rs::compound_query::bool::Bool;
+use os_query_builder_rs::full_text::r#match::Match;
+use os_query_builder_rs::misc::query_field::QueryField;
+use os_query_builder_rs::model::Query;
+use os_query_builder_rs::term::{
+    exists::Exists,
+    range::Range,
+    term::Term
+};
+
+#[test]
+fn and_or_not_test() {
+    let a = Term::new("brand", "FIAT");
+    let b = Term::new("brand", "LADA");
+    let c = Term::new("status", "deleted");
+
+    let query = a & b | !c;
+
+    let json_expected = json!({
+        "bool": {
+            "should": [
+                {
+                    "bool": {
+                        "must": [
+                            { "term": { "brand": { "value": "FIAT" } } },
+                            { "term": { "brand": { "value": "LADA" } } }
+                        ]
+                    }
+                },
+                {
+                    "bool": {
+                        "must_not": [{ "term": { "status": { "value": "deleted" } } }]
+                    }
+                }
+            ]
+        }
+    });
+
+    assert_eq!(json_expected, json!(query));
+}
+
+#[test]
+fn flatten_and_test() {
+    let query = Match::new().field("title").value("wind")
+        & Term::new("lang", "en").filtered()
+        & (Range::new().field("price").gte(10).filtered() & !Exists::new().field("deleted_at"))
+        & Match::new().field("body").value("rain");
+
+    let json_expected = json!({
+        "bool": {
+            "must": [
+                { "match": { "title": { "query": "wind" } } },
+                { "match": { "body": { "query": "rain" } } }
+            ],
+            "filter": [
+                { "term": { "lang": { "value": "en" } } },
+                { "range": { "price": { "gte": 10 } } }
+            ],
+            "must_not": [{ "exists": { "field": "deleted_at" } }]
+        }
+    });
+
+    assert_eq!(json_expected, json!(query));
+}
+
+#[test]
+fn flatten_or_test() {
+    let query = Term::new("color", "red") | Term::new("color", "blue") | (Term::new("color", "green") | Term::new("color", "black"));
+
+    let json_expected = json!({
+        "bool": {
+            "should": [
+                { "term": { "color": { "value": "red" } } },
+                { "term": { "color": { "value": "blue" } } },
+                { "term": { "color": { "value": "green" } } },
+                { "term": { "color": { "value": "black" } } }
+            ]
+        }
+    });
+
+    assert_eq!(json_expected, json!(query));
+}
+
+#[test]
+fn or_inside_and_is_kept_test() {
+    let query = (Term::new("color", "red") | Term::new("color", "blue")) & Term::new("size", 42);
+
+    let json_expected = json!({
+        "bool": {
+            "must": [
+                {
+                    "bool": {
+                        "should": [
+                            { "term": { "color": { "value": "red" } } },
+                            { "term": { "color": { "value": "blue" } } }
+                        ]
+                    }
+                },
+                { "term": { "size": { "value": 42 } } }
+            ]
+        }
+    });
+
+    assert_eq!(json_expected, json!(query));
+}
+
+#[test]
+fn double_not_test() {
+    let query = !!Term::new("brand", "FIAT");
+
+    assert_eq!(json!(query), json!({ "term": { "brand": { "value": "FIAT" } } }));
+}
+
+#[test]
+fn step_by_step_composition_test() {
+    let brands = ["FIAT", "LADA"];
+    let mut query = QueryField::from(Match::new().field("title").value("wind"));
+
+    for brand in brands {
+        query = query & Term::new("brand", brand).filtered();
+    }
+
+    let query = Query::new().query(query & Bool::new());
+
+    let json_expected = json!({
+        "query": {
+            "bool": {
+                "must": [{ "match": { "title": { "query": "wind" } } }],
+                "filter": [
+                    { "term": { "brand": { "value": "FIAT" } } },
+                    { "term": { "brand": { "value": "LADA" } } }
+                ]
+            }
+        }
+    });
+
+    assert_eq!(json_expected, json!(query));
+}
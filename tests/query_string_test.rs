@@ -72,7 +72,7 @@ fn simple_test() {
 
     let query_sting = QueryString::new()
         .query("historical epic heroic")
-        .minimum_should_match("2");
+        .minimum_should_match(2);
     let query = Query::new()
         .query(query_sting);
 
@@ -81,7 +81,7 @@ fn simple_test() {
         "query": {
             "query_string": {
               "query": "historical epic heroic",
-              "minimum_should_match": "2"
+              "minimum_should_match": 2
             }
         }
     });
@@ -94,7 +94,7 @@ fn simple_test() {
 fn query_string_all_fields_test() {
     let query_sting = QueryString::new()
         .query("historical epic heroic")
-        .minimum_should_match("2")
+        .minimum_should_match(2)
         .fuzzy_transpositions(true)
         .lenient(false)
         .fuzziness(Fuzziness::Uint(10))
@@ -114,7 +114,7 @@ fn query_string_all_fields_test() {
         "query": {
             "query_string": {
               "query": "historical epic heroic",
-              "minimum_should_match": "2",
+              "minimum_should_match": 2,
               "fuzzy_transpositions": true,
               "lenient": false,
               "fuzziness": 10,
@@ -0,0 +1,310 @@
+use serde_json::json;
+use os_query_builder_rs::misc::field_and_format::FieldAndFormat;
+use os_query_builder_rs::misc::script::Script;
+use os_query_builder_rs::misc::source_filter::SourceRules;
+use os_query_builder_rs::misc::top_hits::{InnerHits, TopHits};
+use os_query_builder_rs::misc::track_total_hits::TrackTotalHits;
+use os_query_builder_rs::model::Query;
+use os_query_builder_rs::term::term::Term;
+use os_query_builder_rs::full_text::r#match::Match;
+
+#[test]
+fn query_with_search_options_test() {
+    let query = Query::new()
+        .query(Match::new().field("title").value("wind"))
+        .track_total_hits(true)
+        .min_score(0.5)
+        .timeout("10s")
+        .terminate_after(1000u64)
+        .explain(true)
+        .version(true)
+        .seq_no_primary_term(true)
+        .track_scores(true)
+        .stats(vec!["group1", "group2"])
+        .profile(false);
+
+    let json_actual = json!(query);
+    let json_expected = json!({
+        "query": {
+            "match": {
+                "title": {
+                    "query": "wind"
+                }
+            }
+        },
+        "track_total_hits": true,
+        "min_score": 0.5,
+        "timeout": "10s",
+        "terminate_after": 1000,
+        "explain": true,
+        "version": true,
+        "seq_no_primary_term": true,
+        "track_scores": true,
+        "stats": ["group1", "group2"],
+        "profile": false
+    });
+
+    assert_eq!(json_expected, json_actual);
+}
+
+#[test]
+fn query_with_track_total_hits_count_test() {
+    let query = Query::new().track_total_hits(100000u32);
+
+    let json_actual = json!(query);
+    let json_expected = json!({
+        "track_total_hits": 100000
+    });
+
+    assert_eq!(json_expected, json_actual);
+    assert_eq!(json!(Query::new().track_total_hits(10_usize)), json!({ "track_total_hits": 10 }));
+    assert_eq!(json!(Query::new().track_total_hits(TrackTotalHits::try_from(10_i64).unwrap())), json!({ "track_total_hits": 10 }));
+}
+
+#[test]
+fn query_with_negative_track_total_hits_test() {
+    let error = TrackTotalHits::try_from(-1_i32).unwrap_err();
+    assert_eq!(error.to_string(), "track_total_hits must not be negative, got -1");
+    assert!(TrackTotalHits::try_from(-1_i64).is_err());
+}
+
+#[test]
+fn query_with_post_filter_test() {
+    let query = Query::new()
+        .query(Match::new().field("title").value("shirt"))
+        .post_filter(Term::new("color", "red"));
+
+    let json_actual = json!(query);
+    let json_expected = json!({
+        "query": {
+            "match": {
+                "title": {
+                    "query": "shirt"
+                }
+            }
+        },
+        "post_filter": {
+            "term": {
+                "color": {
+                    "value": "red"
+                }
+            }
+        }
+    });
+
+    assert_eq!(json_expected, json_actual);
+}
+
+#[test]
+fn query_with_indices_boost_test() {
+    let query = Query::new().indices_boost(vec![("my-alias", 1.4), ("my-index*", 1.3)]);
+
+    let json_actual = json!(query);
+    let json_expected = json!({
+        "indices_boost": [
+            { "my-alias": 1.4 },
+            { "my-index*": 1.3 }
+        ]
+    });
+
+    assert_eq!(json_expected, json_actual);
+}
+
+#[test]
+fn query_with_fields_test() {
+    let query = Query::new()
+        .stored_fields(vec!["title"])
+        .docvalue_fields(vec![
+            FieldAndFormat::new("created").format("epoch_millis"),
+            FieldAndFormat::new("rating")
+        ])
+        .fields(vec![FieldAndFormat::from("title"), FieldAndFormat::new("created").format("yyyy-MM-dd")]);
+
+    let json_actual = json!(query);
+    let json_expected = json!({
+        "stored_fields": ["title"],
+        "docvalue_fields": [
+            { "field": "created", "format": "epoch_millis" },
+            { "field": "rating" }
+        ],
+        "fields": [
+            { "field": "title" },
+            { "field": "created", "format": "yyyy-MM-dd" }
+        ]
+    });
+
+    assert_eq!(json_expected, json_actual);
+}
+
+#[test]
+fn query_with_script_fields_test() {
+    let script = Script::new()
+        .source("doc['price'].value * 2")
+        .lang("painless");
+    let query = Query::new().script_fields(vec![("double_price", script)]);
+
+    let json_actual = json!(query);
+    let json_expected = json!({
+        "script_fields": {
+            "double_price": {
+                "script": {
+                    "source": "doc['price'].value * 2",
+                    "lang": "painless"
+                }
+            }
+        }
+    });
+
+    assert_eq!(json_expected, json_actual);
+}
+
+#[test]
+fn query_with_ext_test() {
+    let query = Query::new().ext(json!({
+        "rerank": {
+            "query_context": {
+                "query_text": "wind"
+            }
+        }
+    }));
+
+    let json_actual = json!(query);
+    let json_expected = json!({
+        "ext": {
+            "rerank": {
+                "query_context": {
+                    "query_text": "wind"
+                }
+            }
+        }
+    });
+
+    assert_eq!(json_expected, json_actual);
+}
+
+#[test]
+fn query_with_source_disabled_test() {
+    let query = Query::new()
+        .source(false)
+        .query(Term::new("brand", "FIAT"));
+
+    let json_actual = json!(query);
+    let json_expected = json!({
+        "_source": false,
+        "query": {
+            "term": {
+                "brand": {
+                    "value": "FIAT"
+                }
+            }
+        }
+    });
+
+    assert_eq!(json_expected, json_actual);
+}
+
+#[test]
+fn query_with_source_includes_and_excludes_test() {
+    let source = SourceRules::new()
+        .includes(["title", "attributes.*"])
+        .excludes(vec!["*.embedding"]);
+    let query = Query::new().source(source);
+
+    let json_actual = json!(query);
+    let json_expected = json!({
+        "_source": {
+            "includes": ["title", "attributes.*"],
+            "excludes": ["*.embedding"]
+        }
+    });
+
+    assert_eq!(json_expected, json_actual);
+}
+
+#[test]
+fn query_with_source_excludes_only_test() {
+    let query = Query::new().source(SourceRules::new().excludes(["embedding"]));
+
+    let json_actual = json!(query);
+    let json_expected = json!({
+        "_source": {
+            "excludes": ["embedding"]
+        }
+    });
+
+    assert_eq!(json_expected, json_actual);
+}
+
+#[test]
+fn query_with_source_fields_test() {
+    let fields = ["title", "brand"].iter().map(|x| x.to_uppercase());
+    let query = Query::new().source_fields(fields);
+
+    let json_actual = json!(query);
+    let json_expected = json!({
+        "_source": ["TITLE", "BRAND"]
+    });
+
+    assert_eq!(json_expected, json_actual);
+}
+
+#[test]
+fn top_hits_with_source_test() {
+    let top_hits = TopHits::new()
+        .from(1usize)
+        .size(3usize)
+        .sort(json!([{ "price": "desc" }]))
+        .source(SourceRules::new().includes(["title"]).excludes(["*.embedding"]))
+        .docvalue_fields(["price"])
+        .version(true);
+
+    let json_actual = json!(top_hits);
+    let json_expected = json!({
+        "top_hits": {
+            "from": 1,
+            "size": 3,
+            "sort": [{ "price": "desc" }],
+            "_source": {
+                "includes": ["title"],
+                "excludes": ["*.embedding"]
+            },
+            "docvalue_fields": [{ "field": "price" }],
+            "version": true
+        }
+    });
+
+    assert_eq!(json_expected, json_actual);
+}
+
+#[test]
+fn inner_hits_with_source_test() {
+    let inner_hits = InnerHits::new()
+        .name("reviews")
+        .size(2usize)
+        .source(false)
+        .explain(true)
+        .seq_no_primary_term(true);
+
+    let json_actual = json!({
+        "nested": {
+            "path": "reviews",
+            "query": { "match_all": {} },
+            "inner_hits": inner_hits
+        }
+    });
+    let json_expected = json!({
+        "nested": {
+            "path": "reviews",
+            "query": { "match_all": {} },
+            "inner_hits": {
+                "name": "reviews",
+                "size": 2,
+                "_source": false,
+                "explain": true,
+                "seq_no_primary_term": true
+            }
+        }
+    });
+
+    assert_eq!(json_expected, json_actual);
+}
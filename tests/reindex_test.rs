@@ -0,0 +1,114 @@
+use serde_json::json;
+use os_query_builder_rs::bulk::metadata::VersionType;
+use os_query_builder_rs::by_query::{
+    conflicts::Conflicts,
+    params::ByQueryParams,
+    reindex::{OpType, Reindex, ReindexDest, ReindexSource, RemoteSource},
+    slice::Slice
+};
+use os_query_builder_rs::compound_query::bool::Bool;
+use os_query_builder_rs::misc::script::Script;
+use os_query_builder_rs::misc::source_filter::SourceRules;
+use os_query_builder_rs::response::by_query::{BulkByScrollResponse, TaskResponse, TaskStatusResponse};
+use os_query_builder_rs::term::{range::Range, term::Term};
+
+#[test]
+fn reindex_test() {
+    let filter = Bool::new()
+        .filter(vec![Term::new("brand", "FIAT")])
+        .must_not(vec![Range::new().field("year").lt(2000)]);
+
+    let reindex = Reindex::new(
+            ReindexSource::new(vec!["products-2023", "products-2024"])
+                .query(filter)
+                .source(SourceRules::new().excludes(vec!["internal.*"]))
+                .size(500u64)
+                .slice(Slice::new(1, 2)),
+            ReindexDest::new("products")
+                .op_type(OpType::Index)
+                .pipeline("enrich")
+                .version_type(VersionType::External)
+        )
+        .script(Script::new().source("ctx._source.migrated = true").lang("painless"))
+        .max_docs(10000u64)
+        .conflicts(Conflicts::Proceed);
+
+    assert_eq!(serde_json::to_value(reindex).unwrap(), json!({
+        "source": {
+            "index": ["products-2023", "products-2024"],
+            "query": {
+                "bool": {
+                    "filter": [{ "term": { "brand": { "value": "FIAT" } } }],
+                    "must_not": [{ "range": { "year": { "lt": 2000 } } }]
+                }
+            },
+            "_source": { "excludes": ["internal.*"] },
+            "size": 500,
+            "slice": { "id": 1, "max": 2 }
+        },
+        "dest": { "index": "products", "op_type": "index", "pipeline": "enrich", "version_type": "external" },
+        "script": { "source": "ctx._source.migrated = true", "lang": "painless" },
+        "max_docs": 10000,
+        "conflicts": "proceed"
+    }));
+}
+
+#[test]
+fn reindex_from_remote_test() {
+    let reindex = Reindex::new(
+        ReindexSource::from("products").remote(RemoteSource::new("https://old-cluster:9200")
+            .username("admin")
+            .password("secret")
+            .socket_timeout("1m")
+            .connect_timeout("10s")),
+        "products"
+    );
+
+    assert_eq!(serde_json::to_value(reindex).unwrap(), json!({
+        "source": {
+            "index": ["products"],
+            "remote": {
+                "host": "https://old-cluster:9200",
+                "username": "admin",
+                "password": "secret",
+                "socket_timeout": "1m",
+                "connect_timeout": "10s"
+            }
+        },
+        "dest": { "index": "products" }
+    }));
+}
+
+#[test]
+fn reindex_task_test() {
+    let params = ByQueryParams::new().wait_for_completion(false).requests_per_second(1000);
+    assert_eq!(format!("/_reindex?{params}"), "/_reindex?wait_for_completion=false&requests_per_second=1000");
+
+    let task: TaskResponse = serde_json::from_value(json!({ "task": "r1A2WoRbTwKZ516z6NEs5A:36619" })).unwrap();
+    assert_eq!(task.task, "r1A2WoRbTwKZ516z6NEs5A:36619");
+
+    let status: TaskStatusResponse = serde_json::from_value(json!({
+        "completed": true,
+        "task": {
+            "node": "r1A2WoRbTwKZ516z6NEs5A", "id": 36619, "type": "transport",
+            "action": "indices:data/write/reindex", "description": "reindex from [products-2023] to [products]",
+            "running_time_in_nanos": 5000000, "cancellable": true
+        },
+        "response": {
+            "took": 120, "timed_out": false, "total": 10, "updated": 0, "created": 10, "deleted": 0,
+            "batches": 1, "version_conflicts": 0, "noops": 0, "retries": { "bulk": 0, "search": 0 },
+            "throttled_millis": 0, "requests_per_second": -1.0, "throttled_until_millis": 0, "failures": []
+        }
+    })).unwrap();
+
+    assert!(status.completed);
+    let response = status.response.unwrap();
+    assert_eq!(response.created, 10);
+    assert_eq!(response.requests_per_second, -1.0);
+
+    let response: BulkByScrollResponse = serde_json::from_value(json!({
+        "took": 5, "timed_out": false, "total": 2, "created": 1, "batches": 1, "version_conflicts": 1,
+        "failures": [{ "index": "products", "id": "1", "status": 409, "cause": { "type": "version_conflict_engine_exception" } }]
+    })).unwrap();
+    assert_eq!(response.failures.len(), 1);
+}
@@ -0,0 +1,174 @@
+use serde_json::json;
+use os_query_builder_rs::compound_query::bool::Bool;
+use os_query_builder_rs::misc::runtime_field::{RuntimeField, RuntimeFieldType};
+use os_query_builder_rs::misc::script::Script;
+use os_query_builder_rs::misc::sort::{SortField, SortOrder};
+use os_query_builder_rs::model::Query;
+use os_query_builder_rs::term::range::Range;
+use os_query_builder_rs::term::term::Term;
+
+#[test]
+fn runtime_field_keyword_test() {
+    let script = Script::new().source("emit(doc['request'].value.splitOnToken(' ')[0])");
+    let method = RuntimeField::new("request_method", RuntimeFieldType::Keyword, script);
+
+    let query = Query::new()
+        .runtime_mappings(vec![method])
+        .query(Term::new("request_method", "GET"));
+
+    let json_actual = json!(query);
+    let json_expected = json!({
+        "derived": {
+            "request_method": {
+                "type": "keyword",
+                "script": {
+                    "source": "emit(doc['request'].value.splitOnToken(' ')[0])"
+                }
+            }
+        },
+        "query": {
+            "term": {
+                "request_method": {
+                    "value": "GET"
+                }
+            }
+        }
+    });
+
+    assert_eq!(json_expected, json_actual);
+}
+
+#[test]
+fn runtime_fields_with_range_test() {
+    let size = RuntimeField::new(
+        "size_kb",
+        RuntimeFieldType::Double,
+        Script::new().source("emit(doc['size'].value / 1024.0)").lang("painless")
+    );
+    let day = RuntimeField::new(
+        "day",
+        RuntimeFieldType::Date,
+        Script::new().source("emit(doc['timestamp'].value.toInstant().toEpochMilli())")
+    ).format("yyyy-MM-dd");
+    let location = RuntimeField::new(
+        "location",
+        RuntimeFieldType::GeoPoint,
+        Script::new().source("emit(doc['lat'].value, doc['lon'].value)")
+    );
+
+    let query = Query::new()
+        .runtime_mappings([size, day, location])
+        .query(Bool::new().filter(vec![Range::new().field("size_kb").gte(10)]));
+
+    let json_actual = json!(query);
+    let json_expected = json!({
+        "derived": {
+            "size_kb": {
+                "type": "double",
+                "script": {
+                    "source": "emit(doc['size'].value / 1024.0)",
+                    "lang": "painless"
+                }
+            },
+            "day": {
+                "type": "date",
+                "script": {
+                    "source": "emit(doc['timestamp'].value.toInstant().toEpochMilli())"
+                },
+                "format": "yyyy-MM-dd"
+            },
+            "location": {
+                "type": "geo_point",
+                "script": {
+                    "source": "emit(doc['lat'].value, doc['lon'].value)"
+                }
+            }
+        },
+        "query": {
+            "bool": {
+                "filter": [
+                    {
+                        "range": {
+                            "size_kb": {
+                                "gte": 10
+                            }
+                        }
+                    }
+                ]
+            }
+        }
+    });
+
+    assert_eq!(json_expected, json_actual);
+}
+
+#[test]
+fn runtime_field_sort_and_aggs_test() {
+    let method = RuntimeField::new(
+        "request_method",
+        RuntimeFieldType::Keyword,
+        Script::new().source("emit(doc['request'].value.splitOnToken(' ')[0])")
+    );
+    let size = RuntimeField::new(
+        "size_kb",
+        RuntimeFieldType::Double,
+        Script::new().source("emit(doc['size'].value / 1024.0)")
+    );
+
+    let query = Query::new()
+        .runtime_mappings([method, size])
+        .sort([SortField::new("size_kb").order(SortOrder::Desc), SortField::new("request_method")])
+        .aggs(json!({
+            "methods": {
+                "terms": { "field": "request_method" }
+            }
+        }));
+
+    let json_actual = json!(query);
+    let json_expected = json!({
+        "derived": {
+            "request_method": {
+                "type": "keyword",
+                "script": {
+                    "source": "emit(doc['request'].value.splitOnToken(' ')[0])"
+                }
+            },
+            "size_kb": {
+                "type": "double",
+                "script": {
+                    "source": "emit(doc['size'].value / 1024.0)"
+                }
+            }
+        },
+        "sort": [
+            { "size_kb": { "order": "desc" } },
+            "request_method"
+        ],
+        "aggs": {
+            "methods": {
+                "terms": { "field": "request_method" }
+            }
+        }
+    });
+
+    assert_eq!(json_expected, json_actual);
+}
+
+#[test]
+fn sort_deserialize_test() {
+    let query: Query = serde_json::from_value(json!({
+        "sort": [
+            { "size_kb": { "order": "asc", "missing": "_last", "unmapped_type": "double" } },
+            { "day": "desc" },
+            "_score"
+        ]
+    })).unwrap();
+
+    assert_eq!(json!(query), json!({
+        "sort": [
+            { "size_kb": { "order": "asc", "missing": "_last", "unmapped_type": "double" } },
+            { "day": { "order": "desc" } },
+            "_score"
+        ]
+    }));
+}
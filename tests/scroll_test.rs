@@ -0,0 +1,144 @@
+use std::cell::RefCell;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use os_query_builder_rs::model::Query;
+use os_query_builder_rs::response::scroll::ClearScrollResponse;
+use os_query_builder_rs::scroll::{
+    iterator::{ScrollCall, ScrollError, ScrollIterator},
+    request::{ClearScroll, ScrollRequest}
+};
+use os_query_builder_rs::term::term::Term;
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Product {
+    brand: String,
+}
+
+fn page(scroll_id: &str, brands: &[&str]) -> Value {
+    let hits: Vec<Value> = brands.iter()
+        .map(|brand| json!({ "_index": "products", "_id": brand, "_source": { "brand": brand } }))
+        .collect();
+
+    json!({
+        "_scroll_id": scroll_id, "took": 1, "timed_out": false,
+        "_shards": { "total": 1, "successful": 1, "failed": 0 },
+        "hits": { "total": { "value": 3, "relation": "eq" }, "hits": hits }
+    })
+}
+
+/// Records the calls as `METHOD path body` and answers with the next response
+fn transport<'a>(calls: &'a RefCell<Vec<String>>, mut responses: Vec<Result<Value, String>>) -> impl FnMut(ScrollCall<'_>) -> Result<Value, String> + 'a {
+    responses.reverse();
+    move |call| {
+        calls.borrow_mut().push(format!("{} {} {}", call.method(), call.path(), call.body()));
+        match call {
+            ScrollCall::Clear(_) => Ok(json!({ "succeeded": true, "num_freed": 1 })),
+            _ => responses.pop().expect("unexpected call"),
+        }
+    }
+}
+
+#[test]
+fn scroll_request_test() {
+    let request = ScrollRequest::new("5m", "id-1");
+    assert_eq!(serde_json::to_value(&request).unwrap(), json!({ "scroll": "5m", "scroll_id": "id-1" }));
+    assert_eq!((request.get_scroll(), request.get_scroll_id()), ("5m", "id-1"));
+
+    assert_eq!(serde_json::to_value(ClearScroll::from("id-1")).unwrap(), json!({ "scroll_id": ["id-1"] }));
+    assert_eq!(ClearScroll::new(vec![String::from("a"), String::from("b")]).get_scroll_ids(), ["a", "b"]);
+
+    let response: ClearScrollResponse = serde_json::from_value(json!({ "succeeded": true, "num_freed": 2 })).unwrap();
+    assert!(response.succeeded);
+    assert_eq!(response.num_freed, 2);
+}
+
+#[test]
+fn scroll_iterator_test() {
+    let calls = RefCell::new(Vec::new());
+    let responses = vec![
+        Ok(page("id-1", &["FIAT", "LADA"])),
+        Ok(page("id-2", &["KIA"])),
+        Ok(page("id-2", &[])),
+    ];
+    let query = Query::new().query(Term::new("status", "active")).size(2usize);
+
+    let brands: Vec<Vec<String>> = ScrollIterator::<Product, _, _>::new("products", query, "1m", transport(&calls, responses))
+        .map(|page| page.unwrap().into_iter().map(|hit| hit.source.unwrap().brand).collect())
+        .collect();
+
+    assert_eq!(brands, vec![vec!["FIAT", "LADA"], vec!["KIA"]]);
+    assert_eq!(*calls.borrow(), vec![
+        r#"POST products/_search?scroll=1m {"query":{"term":{"status":{"value":"active"}}},"size":2}"#,
+        r#"POST _search/scroll {"scroll":"1m","scroll_id":"id-1"}"#,
+        r#"POST _search/scroll {"scroll":"1m","scroll_id":"id-2"}"#,
+        r#"DELETE _search/scroll {"scroll_id":["id-2"]}"#,
+    ]);
+}
+
+#[test]
+fn scroll_clear_on_drop_test() {
+    let calls = RefCell::new(Vec::new());
+    let mut scroll = ScrollIterator::<Product, _, _>::new("products", Query::new().size(2usize), "1m", transport(&calls, vec![Ok(page("id-1", &["FIAT", "LADA"]))]));
+
+    assert_eq!(scroll.scroll_id(), None);
+    assert_eq!(scroll.next().unwrap().unwrap().len(), 2);
+    assert_eq!(scroll.scroll_id(), Some("id-1"));
+    drop(scroll);
+
+    assert_eq!(calls.borrow().last().unwrap(), r#"DELETE _search/scroll {"scroll_id":["id-1"]}"#);
+
+    let calls = RefCell::new(Vec::new());
+    let mut scroll = ScrollIterator::<Product, _, _>::new("products", Query::new(), "1m", transport(&calls, vec![Ok(page("id-1", &["FIAT"]))]));
+    scroll.next();
+    scroll.clear().unwrap();
+
+    assert!(scroll.next().is_none());
+    drop(scroll);
+    assert_eq!(calls.borrow().len(), 2);
+}
+
+#[test]
+fn scroll_errors_test() {
+    let calls = RefCell::new(Vec::new());
+    let responses = vec![Ok(page("id-1", &["FIAT"])), Err(String::from("connection reset"))];
+    let mut scroll = ScrollIterator::<Product, _, _>::new("products", Query::new(), "1m", transport(&calls, responses));
+
+    assert!(scroll.next().unwrap().is_ok());
+    let error = scroll.next().unwrap().unwrap_err();
+    assert!(matches!(error, ScrollError::Transport(_)));
+    assert_eq!(error.to_string(), "scroll request failed: connection reset");
+    assert!(scroll.next().is_none());
+    assert_eq!(calls.borrow().last().unwrap(), r#"DELETE _search/scroll {"scroll_id":["id-1"]}"#);
+
+    let calls = RefCell::new(Vec::new());
+    let responses = vec![Ok(json!({ "error": { "type": "search_context_missing_exception", "reason": "No search context found" }, "status": 404 }))];
+    let error = ScrollIterator::<Product, _, _>::new("products", Query::new(), "1m", transport(&calls, responses)).next().unwrap().unwrap_err();
+    assert_eq!(error.to_string(), "scroll failed with search_context_missing_exception: No search context found");
+
+    let calls = RefCell::new(Vec::new());
+    let mut response = page("id-1", &["FIAT"]);
+    response.as_object_mut().unwrap().remove("_scroll_id");
+    let error = ScrollIterator::<Product, _, _>::new("products", Query::new(), "1m", transport(&calls, vec![Ok(response)])).next().unwrap().unwrap_err();
+    assert!(matches!(error, ScrollError::MissingScrollId));
+
+    let calls = RefCell::new(Vec::new());
+    let error = ScrollIterator::<Product, _, _>::new("products", Query::new(), "1m", transport(&calls, vec![Ok(json!({ "hits": 1 }))])).next().unwrap().unwrap_err();
+    assert!(matches!(error, ScrollError::Response(_)));
+    assert_eq!(calls.borrow().len(), 1);
+}
+
+#[test]
+fn scroll_clear_after_invalid_page_test() {
+    let calls = RefCell::new(Vec::new());
+    let mut response = page("id-1", &["FIAT"]);
+    response["hits"]["hits"][0]["_source"] = json!({ "model": "Niva" });
+    let mut scroll = ScrollIterator::<Product, _, _>::new("products", Query::new(), "1m", transport(&calls, vec![Ok(response)]));
+
+    let error = scroll.next().unwrap().unwrap_err();
+    assert!(matches!(error, ScrollError::Response(_)));
+    assert!(scroll.next().is_none());
+    drop(scroll);
+
+    assert_eq!(calls.borrow().len(), 2);
+    assert_eq!(calls.borrow().last().unwrap(), r#"DELETE _search/scroll {"scroll_id":["id-1"]}"#);
+}
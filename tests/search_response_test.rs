@@ -0,0 +1,178 @@
+use serde::Deserialize;
+use serde_json::{json, Value};
+use os_query_builder_rs::response::search::{SearchResponse, TotalHitsRelation};
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Product {
+    brand: String,
+    article: String,
+}
+
+#[test]
+fn search_response_with_typed_source_test() {
+    let response: SearchResponse<Product> = serde_json::from_value(json!({
+        "took": 12,
+        "timed_out": false,
+        "_shards": {
+            "total": 2,
+            "successful": 2,
+            "skipped": 0,
+            "failed": 0
+        },
+        "hits": {
+            "total": {
+                "value": 10000,
+                "relation": "gte"
+            },
+            "max_score": 2.5,
+            "hits": [
+                {
+                    "_index": "products",
+                    "_id": "1",
+                    "_score": 2.5,
+                    "_source": {
+                        "brand": "FIAT",
+                        "article": "oc47"
+                    },
+                    "highlight": {
+                        "brand": ["<em>FIAT</em>"]
+                    },
+                    "matched_queries": ["by_brand"]
+                },
+                {
+                    "_index": "products",
+                    "_id": "2",
+                    "_score": 1.5,
+                    "_source": {
+                        "brand": "LADA",
+                        "article": "2101"
+                    }
+                }
+            ]
+        }
+    })).unwrap();
+
+    assert_eq!(response.took, 12);
+    assert!(!response.timed_out);
+    assert_eq!(response.shards.successful, 2);
+    assert_eq!(response.total(), Some(10000));
+    assert_eq!(response.hits.total.as_ref().unwrap().relation, TotalHitsRelation::Gte);
+    assert_eq!(response.hits.max_score, Some(2.5));
+
+    let first = &response.hits.hits[0];
+    assert_eq!(first.index, "products");
+    assert_eq!(first.id, "1");
+    assert_eq!(first.highlight.as_ref().unwrap()["brand"], vec!["<em>FIAT</em>"]);
+    assert_eq!(first.matched_queries.as_deref(), Some(&["by_brand".to_string()][..]));
+
+    let brands: Vec<&str> = response.sources().map(|x| x.brand.as_str()).collect();
+    assert_eq!(brands, vec!["FIAT", "LADA"]);
+}
+
+#[test]
+fn search_response_with_sort_fields_and_no_source_test() {
+    let response: SearchResponse<Product> = serde_json::from_value(json!({
+        "took": 1,
+        "timed_out": false,
+        "_shards": {
+            "total": 1,
+            "successful": 1,
+            "failed": 0
+        },
+        "hits": {
+            "max_score": null,
+            "hits": [
+                {
+                    "_index": "products",
+                    "_id": "1",
+                    "_score": null,
+                    "_version": 3,
+                    "_seq_no": 7,
+                    "_primary_term": 1,
+                    "fields": {
+                        "created": ["2024-01-01"]
+                    },
+                    "sort": [1704067200000_i64, "1"]
+                }
+            ]
+        }
+    })).unwrap();
+
+    let hit = &response.hits.hits[0];
+    assert_eq!(response.total(), None);
+    assert_eq!(hit.score, None);
+    assert!(hit.source.is_none());
+    assert_eq!(hit.version, Some(3));
+    assert_eq!(hit.seq_no, Some(7));
+    assert_eq!(hit.fields.as_ref().unwrap()["created"], vec![json!("2024-01-01")]);
+    assert_eq!(hit.sort.as_ref().unwrap(), &vec![json!(1704067200000_i64), json!("1")]);
+}
+
+#[test]
+fn search_response_with_inner_hits_and_explanation_test() {
+    let response: SearchResponse<Value> = serde_json::from_value(json!({
+        "took": 5,
+        "timed_out": false,
+        "_shards": {
+            "total": 1,
+            "successful": 1,
+            "skipped": 0,
+            "failed": 0
+        },
+        "hits": {
+            "total": {
+                "value": 1,
+                "relation": "eq"
+            },
+            "max_score": 1.2,
+            "hits": [
+                {
+                    "_index": "orders",
+                    "_id": "7",
+                    "_score": 1.2,
+                    "_source": {
+                        "items": [{ "sku": "a" }]
+                    },
+                    "_explanation": {
+                        "value": 1.2,
+                        "description": "sum of:",
+                        "details": [
+                            {
+                                "value": 1.2,
+                                "description": "weight(items.sku:a)",
+                                "details": []
+                            }
+                        ]
+                    },
+                    "inner_hits": {
+                        "items": {
+                            "hits": {
+                                "total": {
+                                    "value": 1,
+                                    "relation": "eq"
+                                },
+                                "max_score": 1.2,
+                                "hits": [
+                                    {
+                                        "_index": "orders",
+                                        "_id": "7",
+                                        "_score": 1.2,
+                                        "_source": { "sku": "a" }
+                                    }
+                                ]
+                            }
+                        }
+                    }
+                }
+            ]
+        }
+    })).unwrap();
+
+    let hit = &response.hits.hits[0];
+    let explanation = hit.explanation.as_ref().unwrap();
+    assert_eq!(explanation.description, "sum of:");
+    assert_eq!(explanation.details[0].description, "weight(items.sku:a)");
+
+    let inner = &hit.inner_hits.as_ref().unwrap()["items"];
+    assert_eq!(inner.hits.hits[0].source, Some(json!({ "sku": "a" })));
+}
@@ -0,0 +1,148 @@
+use std::collections::BTreeMap;
+use serde_json::{json, Value};
+use os_query_builder_rs::compound_query::bool::Bool;
+use os_query_builder_rs::full_text::r#match::Match;
+use os_query_builder_rs::model::Query;
+use os_query_builder_rs::response::search_template::RenderTemplateResponse;
+use os_query_builder_rs::search_template::{
+    mustache::render,
+    request::SearchTemplateRequest,
+    source::{json_placeholder, placeholder, StoredTemplate, TemplateError, TemplateSource}
+};
+use os_query_builder_rs::term::{range::Range, term::Term};
+
+fn params(value: Value) -> BTreeMap<String, Value> {
+    serde_json::from_value(value).unwrap()
+}
+
+#[test]
+fn mustache_variables_test() {
+    let context = json!({ "name": "a \"b\"\n", "price": { "gte": 10 }, "tags": ["x", "y"], "flag": true, "none": null });
+
+    assert_eq!(render("{{name}}", &context).unwrap(), r#"a \"b\"\n"#);
+    assert_eq!(render("{{{name}}}|{{& name }}", &context).unwrap(), "a \"b\"\n|a \"b\"\n");
+    assert_eq!(render("{{price.gte}} {{tags.1}} {{flag}} [{{none}}] [{{missing}}]", &context).unwrap(), "10 y true [] []");
+    assert_eq!(render("a{{! comment }}b", &context).unwrap(), "ab");
+}
+
+#[test]
+fn mustache_sections_test() {
+    let context = json!({ "tags": ["x", "y"], "empty": [], "range": { "gte": 1 }, "text": "", "size": 0 });
+
+    assert_eq!(render("{{#tags}}<{{.}}>{{/tags}}", &context).unwrap(), "<x><y>");
+    assert_eq!(render("{{#range}}{{gte}}{{/range}}", &context).unwrap(), "1");
+    assert_eq!(render("{{#empty}}a{{/empty}}{{^empty}}b{{/empty}}", &context).unwrap(), "b");
+    assert_eq!(render("{{#text}}a{{/text}}{{^missing}}b{{/missing}}", &context).unwrap(), "b");
+    assert_eq!(render("{{#size}}{{size}}{{/size}}", &context).unwrap(), "0");
+    assert_eq!(render("{{#range}}{{#tags}}{{gte}}{{.}}{{/tags}}{{/range}}", &context).unwrap(), "1x1y");
+}
+
+#[test]
+fn mustache_functions_test() {
+    let context = json!({ "tags": ["x", "y"], "range": { "gte": 1 }, "query": "a b&c" });
+
+    assert_eq!(render("{{#toJson}}tags{{/toJson}} {{#toJson}}range{{/toJson}}", &context).unwrap(), r#"["x","y"] {"gte":1}"#);
+    assert_eq!(render("{{#join}}tags{{/join}}", &context).unwrap(), "x,y");
+    assert_eq!(render("{{#join delimiter='|'}}tags{{/join delimiter='|'}}", &context).unwrap(), "x|y");
+    assert_eq!(render("{{#url}}q={{query}}{{/url}}", &context).unwrap(), "q%3Da+b%26c");
+}
+
+#[test]
+fn mustache_errors_test() {
+    let error = render("{{#tags}}x", &json!({})).unwrap_err();
+    assert_eq!((error.position(), error.message()), (0, "section `tags` is not closed"));
+
+    let error = render("{{#a}}{{/b}}", &json!({})).unwrap_err();
+    assert_eq!(error.to_string(), "expected closing tag of `a`, found `b` at 6");
+
+    assert_eq!(render("x{{/a}}", &json!({})).unwrap_err().message(), "unexpected closing tag `a`");
+    assert_eq!(render("{{name", &json!({})).unwrap_err().message(), "tag is not closed");
+    assert_eq!(render("{{ }}", &json!({})).unwrap_err().message(), "tag is empty");
+    assert_eq!(render("{{=<% %>=}}", &json!({})).unwrap_err().message(), "changing delimiters is not supported");
+}
+
+#[test]
+fn stored_template_test() {
+    let query = Query::new()
+        .query(Bool::new()
+            .must(vec![Match::new().field("title").value(placeholder("text"))])
+            .filter(vec![Range::new().field("price").gte(placeholder("min_price"))]));
+    let template = StoredTemplate::new(TemplateSource::from_query(&query).unquote("min_price"));
+
+    assert_eq!(serde_json::to_value(&template).unwrap(), json!({
+        "script": {
+            "lang": "mustache",
+            "source": r#"{"query":{"bool":{"must":[{"match":{"title":{"query":"{{text}}"}}}],"filter":[{"range":{"price":{"gte":{{min_price}}}}}]}}}"#
+        }
+    }));
+
+    let template = StoredTemplate::new(json!({ "query": { "term": { "brand": "{{brand}}" } } }));
+    assert_eq!(serde_json::to_value(&template).unwrap(), json!({
+        "script": { "lang": "mustache", "source": { "query": { "term": { "brand": "{{brand}}" } } } }
+    }));
+}
+
+#[test]
+fn render_from_query_test() {
+    let template = Query::new()
+        .query(Bool::new()
+            .must(vec![Term::new("brand", placeholder("brand"))])
+            .filter(vec![Range::new().field("price").gte(placeholder("min_price")).lte(json_placeholder("max_price"))]));
+    let source = TemplateSource::from_query(&template).unquote("min_price");
+
+    let rendered = source.render(&params(json!({ "brand": "say \"hi\"", "min_price": 10, "max_price": 20.5 }))).unwrap();
+    let expected = Query::new()
+        .query(Bool::new()
+            .must(vec![Term::new("brand", "say \"hi\"")])
+            .filter(vec![Range::new().field("price").gte(10).lte(20.5)]));
+
+    assert_eq!(rendered, serde_json::to_value(expected).unwrap());
+}
+
+#[test]
+fn render_errors_test() {
+    let source = TemplateSource::from(r#"{"size": {{size}}}"#);
+    assert!(matches!(source.render(&params(json!({}))), Err(TemplateError::Json(_))));
+
+    let source = TemplateSource::from("{{#size}}");
+    let error = source.render(&params(json!({}))).unwrap_err();
+    assert!(matches!(error, TemplateError::Mustache(_)));
+    assert_eq!(error.to_string(), "invalid template: section `size` is not closed at 0");
+}
+
+#[test]
+fn search_template_request_test() {
+    let request = SearchTemplateRequest::stored("product-search")
+        .param("brand", "FIAT")
+        .param("tags", json!(["a", "b"]))
+        .explain(true)
+        .profile(false);
+
+    assert_eq!(serde_json::to_value(&request).unwrap(), json!({
+        "id": "product-search",
+        "params": { "brand": "FIAT", "tags": ["a", "b"] },
+        "explain": true,
+        "profile": false
+    }));
+    assert_eq!(request.params().len(), 2);
+    assert!(request.render().is_none());
+
+    let request = SearchTemplateRequest::inline(json!({ "query": { "term": { "brand": "{{brand}}" } }, "size": "{{size}}" }))
+        .param("brand", "LADA")
+        .param("size", 5);
+
+    assert_eq!(serde_json::to_value(&request).unwrap(), json!({
+        "source": { "query": { "term": { "brand": "{{brand}}" } }, "size": "{{size}}" },
+        "params": { "brand": "LADA", "size": 5 }
+    }));
+    assert_eq!(request.render().unwrap().unwrap(), json!({ "query": { "term": { "brand": "LADA" } }, "size": "5" }));
+}
+
+#[test]
+fn render_template_response_test() {
+    let response: RenderTemplateResponse = serde_json::from_value(json!({
+        "template_output": { "query": { "term": { "brand": "FIAT" } } }
+    })).unwrap();
+
+    assert_eq!(response.template_output, json!({ "query": { "term": { "brand": "FIAT" } } }));
+}
@@ -0,0 +1,161 @@
+use serde_json::json;
+use os_query_builder_rs::compound_query::bool::Bool;
+use os_query_builder_rs::full_text::{
+    match_phrase::MatchPhrase,
+    query_string::QueryString,
+    r#match::Match
+};
+use os_query_builder_rs::mapping::{
+    index_mapping::IndexMapping,
+    text::{KeywordField, TextField}
+};
+use os_query_builder_rs::settings::{
+    analysis::{Analysis, UnknownAnalyzer},
+    analyzer::{CustomAnalyzer, CustomNormalizer, StandardAnalyzer},
+    char_filter::{HtmlStripCharFilter, MappingCharFilter, PatternReplaceCharFilter},
+    index_settings::IndexSettings,
+    token_filter::{
+        AsciiFoldingFilter, LowercaseFilter, ShingleFilter, StemmerFilter, StopFilter,
+        SynonymFilter, TokenFilter, WordDelimiterGraphFilter
+    },
+    tokenizer::{KeywordTokenizer, NgramTokenizer, PatternTokenizer, StandardTokenizer, TokenChars, Tokenizer}
+};
+
+fn analysis() -> Analysis {
+    Analysis::new()
+        .char_filter("strip_html", HtmlStripCharFilter::new().escaped_tags(vec!["b"]))
+        .char_filter("ampersand", MappingCharFilter::new().mappings(vec!["& => and"]))
+        .char_filter("digits_only", PatternReplaceCharFilter::new("[^0-9]").replacement(""))
+        .tokenizer("autocomplete", Tokenizer::EdgeNgram(NgramTokenizer::new()
+            .min_gram(2u32)
+            .max_gram(10u32)
+            .token_chars([TokenChars::Letter, TokenChars::Digit])))
+        .tokenizer("trigrams", Tokenizer::Ngram(NgramTokenizer::new().min_gram(3u32).max_gram(3u32)))
+        .tokenizer("comma", PatternTokenizer::new().pattern(",").group(-1))
+        .tokenizer("whole", KeywordTokenizer::new().buffer_size(256u32))
+        .tokenizer("short", StandardTokenizer::new().max_token_length(5u32))
+        .filter("synonyms", TokenFilter::SynonymGraph(SynonymFilter::inline(["tv, television", "laptop => notebook"]).lenient(true)))
+        .filter("file_synonyms", TokenFilter::Synonym(SynonymFilter::path("analysis/synonyms.txt").expand(false)))
+        .filter("english_stop", StopFilter::new().stopwords("_english_").ignore_case(true))
+        .filter("custom_stop", StopFilter::new().stopwords(vec!["a", "the"]))
+        .filter("english_stemmer", StemmerFilter::new("light_english"))
+        .filter("bigrams", ShingleFilter::new().min_shingle_size(2u32).max_shingle_size(2u32).output_unigrams(false))
+        .filter("parts", WordDelimiterGraphFilter::new().preserve_original(true).split_on_numerics(false).protected_words(vec!["Wi-Fi"]))
+        .filter("folding", AsciiFoldingFilter::new().preserve_original(true))
+        .filter("turkish_lowercase", LowercaseFilter::new().language("turkish"))
+        .analyzer("product_text", CustomAnalyzer::new("standard")
+            .char_filter(vec!["strip_html", "ampersand"])
+            .filter(vec!["lowercase", "folding", "english_stop", "english_stemmer"]))
+        .analyzer("product_search", CustomAnalyzer::new("standard").filter(vec!["lowercase", "synonyms"]))
+        .analyzer("autocomplete", CustomAnalyzer::new("autocomplete").filter(vec!["lowercase"]))
+        .analyzer("short_standard", StandardAnalyzer::new().max_token_length(100u32).stopwords("_english_"))
+        .normalizer("folded", CustomNormalizer::new().filter(vec!["lowercase", "asciifolding"]))
+}
+
+#[test]
+fn analysis_settings_test() {
+    let settings = IndexSettings::new()
+        .number_of_shards(1u32)
+        .number_of_replicas(2u32)
+        .refresh_interval("1s")
+        .knn(true)
+        .analysis(analysis());
+
+    assert_eq!(serde_json::to_value(settings).unwrap(), json!({
+        "number_of_shards": 1,
+        "number_of_replicas": 2,
+        "refresh_interval": "1s",
+        "knn": true,
+        "analysis": {
+            "analyzer": {
+                "autocomplete": { "type": "custom", "tokenizer": "autocomplete", "filter": ["lowercase"] },
+                "product_search": { "type": "custom", "tokenizer": "standard", "filter": ["lowercase", "synonyms"] },
+                "product_text": {
+                    "type": "custom",
+                    "tokenizer": "standard",
+                    "char_filter": ["strip_html", "ampersand"],
+                    "filter": ["lowercase", "folding", "english_stop", "english_stemmer"]
+                },
+                "short_standard": { "type": "standard", "max_token_length": 100, "stopwords": "_english_" }
+            },
+            "tokenizer": {
+                "autocomplete": { "type": "edge_ngram", "min_gram": 2, "max_gram": 10, "token_chars": ["letter", "digit"] },
+                "comma": { "type": "pattern", "pattern": ",", "group": -1 },
+                "short": { "type": "standard", "max_token_length": 5 },
+                "trigrams": { "type": "ngram", "min_gram": 3, "max_gram": 3 },
+                "whole": { "type": "keyword", "buffer_size": 256 }
+            },
+            "filter": {
+                "bigrams": { "type": "shingle", "min_shingle_size": 2, "max_shingle_size": 2, "output_unigrams": false },
+                "custom_stop": { "type": "stop", "stopwords": ["a", "the"] },
+                "english_stemmer": { "type": "stemmer", "language": "light_english" },
+                "english_stop": { "type": "stop", "stopwords": "_english_", "ignore_case": true },
+                "file_synonyms": { "type": "synonym", "synonyms_path": "analysis/synonyms.txt", "expand": false },
+                "folding": { "type": "asciifolding", "preserve_original": true },
+                "parts": { "type": "word_delimiter_graph", "preserve_original": true, "split_on_numerics": false, "protected_words": ["Wi-Fi"] },
+                "synonyms": { "type": "synonym_graph", "synonyms": ["tv, television", "laptop => notebook"], "lenient": true },
+                "turkish_lowercase": { "type": "lowercase", "language": "turkish" }
+            },
+            "char_filter": {
+                "ampersand": { "type": "mapping", "mappings": ["& => and"] },
+                "digits_only": { "type": "pattern_replace", "pattern": "[^0-9]", "replacement": "" },
+                "strip_html": { "type": "html_strip", "escaped_tags": ["b"] }
+            },
+            "normalizer": {
+                "folded": { "type": "custom", "filter": ["lowercase", "asciifolding"] }
+            }
+        }
+    }));
+}
+
+#[test]
+fn analysis_settings_round_trip_test() {
+    let value = serde_json::to_value(IndexSettings::new().analysis(analysis()).setting("max_ngram_diff", 8)).unwrap();
+    let settings: IndexSettings = serde_json::from_value(value.clone()).unwrap();
+
+    assert_eq!(serde_json::to_value(&settings).unwrap(), value);
+    assert!(settings.get_analysis().unwrap().has_analyzer("product_text"));
+    assert!(settings.get_analysis().unwrap().has_normalizer("folded"));
+}
+
+#[test]
+fn analyzer_references_test() {
+    let analysis = analysis();
+
+    let name = analysis.analyzer_name("product_search").unwrap();
+    assert_eq!(name.as_str(), "product_search");
+    assert_eq!(analysis.analyzer_name("english").unwrap().as_str(), "english");
+    assert_eq!(analysis.analyzer_name("product_serach").unwrap_err(), UnknownAnalyzer("product_serach".into()));
+
+    let query = Bool::new()
+        .must(vec![Match::new().field("title").value("tv").analyzer(name.clone())])
+        .should(vec![QueryString::new().query("laptop").analyzer(name).quote_analyzer("product_txt")])
+        .filter(vec![MatchPhrase::new().field("title").value("smart tv").analyzer("autocompletion")]);
+
+    let errors = analysis.validate_query(&query.into()).unwrap_err();
+    assert_eq!(errors.0.iter().map(|x| x.path.as_str()).collect::<Vec<_>>(), vec![
+        "/bool/filter/0/match_phrase/title/analyzer",
+        "/bool/should/0/query_string/quote_analyzer",
+    ]);
+    assert_eq!(errors.0[0].message, "unknown analyzer `autocompletion`");
+}
+
+#[test]
+fn mapping_references_test() {
+    let analysis = analysis();
+
+    let mapping = IndexMapping::new()
+        .property("title", TextField::new().analyzer("product_text").search_analyzer("product_search")
+            .field("raw", KeywordField::new().normalizer("folded")))
+        .property("sku", KeywordField::new().normalizer("lowercase"));
+    assert!(analysis.validate_mapping(&mapping).is_ok());
+
+    let mapping = IndexMapping::new()
+        .property("title", TextField::new().analyzer("product_txt"))
+        .property("sku", KeywordField::new().normalizer("folding"));
+    let errors = analysis.validate_mapping(&mapping).unwrap_err();
+    assert_eq!(errors.to_string(), concat!(
+        "/properties/sku/normalizer: unknown normalizer `folding`\n",
+        "/properties/title/analyzer: unknown analyzer `product_txt`"
+    ));
+}
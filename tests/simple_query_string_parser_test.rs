@@ -0,0 +1,160 @@
+use serde_json::json;
+use os_query_builder_rs::full_text::simple_query_string::SimpleQueryString;
+use os_query_builder_rs::lucene::{
+    ast::{Clause, Node, Occur},
+    simple::SimpleParser
+};
+use os_query_builder_rs::misc::operator::Operator;
+use os_query_builder_rs::misc::simple_query_string_flag::SimpleQueryStringFlags;
+
+fn word(value: &str) -> Node {
+    Node::Term { field: None, value: value.into() }
+}
+
+#[test]
+fn flags_render_test() {
+    let flags = SimpleQueryStringFlags::OR | SimpleQueryStringFlags::NOT | SimpleQueryStringFlags::NEAR;
+    assert_eq!(flags.to_string(), "OR|NOT|SLOP");
+    assert_eq!(SimpleQueryStringFlags::ALL.to_string(), "ALL");
+    assert_eq!(SimpleQueryStringFlags::NONE.to_string(), "NONE");
+    assert_eq!((!SimpleQueryStringFlags::FUZZY & !SimpleQueryStringFlags::PREFIX).to_string(), "AND|OR|NOT|PHRASE|PRECEDENCE|ESCAPE|WHITESPACE|SLOP");
+
+    let query = SimpleQueryString::new()
+        .fields(vec!["title"])
+        .query("wind | rain")
+        .flags(SimpleQueryStringFlags::OR | SimpleQueryStringFlags::PHRASE);
+
+    let json_expected = json!({
+        "query": "wind | rain",
+        "fields": ["title"],
+        "flags": "OR|PHRASE"
+    });
+
+    assert_eq!(json_expected, json!(query));
+}
+
+#[test]
+fn flags_parse_test() {
+    assert_eq!("ALL".parse(), Ok(SimpleQueryStringFlags::ALL));
+    assert_eq!("none".parse(), Ok(SimpleQueryStringFlags::NONE));
+    assert_eq!("near".parse(), Ok(SimpleQueryStringFlags::SLOP));
+    assert_eq!(" AND | Prefix ".parse(), Ok(SimpleQueryStringFlags::AND | SimpleQueryStringFlags::PREFIX));
+    assert!("AND|XOR".parse::<SimpleQueryStringFlags>().is_err());
+
+    let mut flags = SimpleQueryStringFlags::ALL;
+    flags.remove(SimpleQueryStringFlags::FUZZY);
+    assert!(!flags.contains(SimpleQueryStringFlags::FUZZY));
+    flags.insert(SimpleQueryStringFlags::FUZZY);
+    assert_eq!(flags, SimpleQueryStringFlags::ALL);
+
+    let flags: SimpleQueryStringFlags = serde_json::from_value(json!("OR|NOT")).unwrap();
+    assert_eq!(flags, SimpleQueryStringFlags::OR | SimpleQueryStringFlags::NOT);
+}
+
+#[test]
+fn parse_left_to_right_test() {
+    let node = SimpleParser::new().parse("wind + rain | snow").unwrap();
+
+    assert_eq!(node, Node::Bool(vec![
+        Clause::new(Occur::Should, Node::Bool(vec![
+            Clause::new(Occur::Must, word("wind")),
+            Clause::new(Occur::Must, word("rain")),
+        ])),
+        Clause::new(Occur::Should, word("snow")),
+    ]));
+
+    let node = SimpleParser::new().parse("wind + (rain | snow)").unwrap();
+    assert_eq!(node, Node::Bool(vec![
+        Clause::new(Occur::Must, word("wind")),
+        Clause::new(Occur::Must, Node::Bool(vec![
+            Clause::new(Occur::Should, word("rain")),
+            Clause::new(Occur::Should, word("snow")),
+        ])),
+    ]));
+}
+
+#[test]
+fn parse_not_test() {
+    let node = SimpleParser::new().parse("wind -rain").unwrap();
+    assert_eq!(node, Node::Bool(vec![
+        Clause::new(Occur::Should, word("wind")),
+        Clause::new(Occur::Should, Node::Bool(vec![Clause::new(Occur::MustNot, word("rain"))])),
+    ]));
+
+    let node = SimpleParser::new()
+        .default_operator(Operator::And)
+        .parse("wind -rain wi-fi")
+        .unwrap();
+    assert_eq!(node, Node::Bool(vec![
+        Clause::new(Occur::Must, word("wind")),
+        Clause::new(Occur::MustNot, word("rain")),
+        Clause::new(Occur::Must, word("wi-fi")),
+    ]));
+}
+
+#[test]
+fn parse_values_test() {
+    let parser = SimpleParser::new();
+
+    assert_eq!(parser.parse("\"quick fox\"~2").unwrap(), Node::Phrase { field: None, text: "quick fox".into(), slop: Some(2) });
+    assert_eq!(parser.parse("jonh~1").unwrap(), Node::Fuzzy { field: None, value: "jonh".into(), distance: Some(1) });
+    assert_eq!(parser.parse("jonh~").unwrap(), Node::Fuzzy { field: None, value: "jonh".into(), distance: None });
+    assert_eq!(parser.parse("jo?n*").unwrap(), Node::Wildcard { field: None, pattern: "jo\\?n*".into() });
+    assert_eq!(parser.parse("c\\+\\+").unwrap(), word("c++"));
+    assert_eq!(parser.parse("Сальники+").unwrap(), word("Сальники"));
+}
+
+#[test]
+fn parse_disabled_operators_as_text_test() {
+    let parser = SimpleParser::new()
+        .flags(SimpleQueryStringFlags::WHITESPACE | SimpleQueryStringFlags::PHRASE);
+
+    assert_eq!(parser.parse("wind|rain +snow").unwrap(), Node::Bool(vec![
+        Clause::new(Occur::Should, word("wind|rain")),
+        Clause::new(Occur::Should, word("+snow")),
+    ]));
+    assert_eq!(parser.parse("jonh~1 fox*").unwrap(), Node::Bool(vec![
+        Clause::new(Occur::Should, word("jonh~1")),
+        Clause::new(Occur::Should, word("fox*")),
+    ]));
+
+    let parser = SimpleParser::new().flags(SimpleQueryStringFlags::NONE);
+    assert_eq!(parser.parse("quick + brown fox").unwrap(), word("quick + brown fox"));
+}
+
+#[test]
+fn parse_strict_test() {
+    let parser = SimpleParser::new()
+        .flags(SimpleQueryStringFlags::AND | SimpleQueryStringFlags::WHITESPACE | SimpleQueryStringFlags::PRECEDENCE)
+        .strict(true);
+
+    assert!(parser.parse("wind + (rain snow)").is_ok());
+
+    for (query, position) in [
+        ("wind | rain", 5),
+        ("\"quick fox\"", 0),
+        ("wind -rain", 5),
+        ("jonh~1", 4),
+        ("fox*", 3),
+        ("(wind rain", 0),
+        ("wind)", 4),
+        ("", 0),
+    ] {
+        let error = parser.parse(query).unwrap_err();
+        assert_eq!(error.position(), position, "{query}: {error}");
+    }
+}
+
+#[test]
+fn parse_depth_limit_test() {
+    let parser = SimpleParser::new();
+
+    let query = format!("{}wind{}", "(".repeat(256), ")".repeat(256));
+    assert_eq!(parser.parse(&query).unwrap(), word("wind"));
+
+    let error = parser.parse(&format!("{}wind", "(".repeat(257))).unwrap_err();
+    assert_eq!(error.position(), 256);
+    assert_eq!(error.message(), "query is nested deeper than 256 levels");
+
+    assert!(parser.parse(&"(".repeat(100_000)).is_err());
+}
@@ -12,7 +12,7 @@ fn simple_query_string_test() {
         .fuzzy_max_expansions(60u64)
         .fuzzy_transpositions(true)
         .lenient(true)
-        .minimum_should_match("2")
+        .minimum_should_match(2)
         .auto_generate_synonyms_phrase_query(false);
 
     let actual_json = json!(simple_query_string);
@@ -26,7 +26,7 @@ fn simple_query_string_test() {
         "fuzzy_max_expansions": 60,
         "fuzzy_transpositions": true,
         "lenient": true,
-        "minimum_should_match": "2",
+        "minimum_should_match": 2,
         "query": "Сальники+"
     });
 
@@ -43,7 +43,7 @@ fn query_simple_query_string_test() {
         .fuzzy_max_expansions(60u64)
         .fuzzy_transpositions(true)
         .lenient(true)
-        .minimum_should_match("2")
+        .minimum_should_match(2)
         .auto_generate_synonyms_phrase_query(false);
 
     let query = Query::new().query(simple_query_string).size(20usize);
@@ -61,7 +61,7 @@ fn query_simple_query_string_test() {
                 "fuzzy_max_expansions": 60,
                 "fuzzy_transpositions": true,
                 "lenient": true,
-                "minimum_should_match": "2",
+                "minimum_should_match": 2,
                 "query": "Сальники+"
             }
         },
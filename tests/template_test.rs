@@ -0,0 +1,125 @@
+use serde_json::json;
+use os_query_builder_rs::alias::{
+    actions::{AddAlias, AliasAction, AliasActions, RemoveAlias, RemoveIndex},
+    definition::Alias
+};
+use os_query_builder_rs::compound_query::bool::Bool;
+use os_query_builder_rs::mapping::{
+    field::FieldMapping,
+    index_mapping::IndexMapping,
+    scalar::DateField,
+    text::KeywordField
+};
+use os_query_builder_rs::settings::index_settings::IndexSettings;
+use os_query_builder_rs::template::{
+    component_template::ComponentTemplate,
+    index_template::{DataStream, IndexTemplate, Template}
+};
+use os_query_builder_rs::term::{exists::Exists, term::Term};
+
+#[test]
+fn index_template_test() {
+    let template = IndexTemplate::new(["products-*", "archive-products-*"])
+        .priority(200u64)
+        .version(3u64)
+        .meta(json!({ "owner": "search" }))
+        .composed_of(["shared-settings", "shared-mappings"])
+        .template(Template::new()
+            .settings(IndexSettings::new().number_of_shards(2u32))
+            .mappings(IndexMapping::new().property("tenant", KeywordField::new()))
+            .alias("products", Alias::new())
+            .alias("products-acme", Alias::new()
+                .filter(Term::new("tenant", "acme"))
+                .routing("acme")
+                .is_hidden(true)));
+
+    assert_eq!(serde_json::to_value(template).unwrap(), json!({
+        "index_patterns": ["products-*", "archive-products-*"],
+        "template": {
+            "settings": { "number_of_shards": 2 },
+            "mappings": { "properties": { "tenant": { "type": "keyword" } } },
+            "aliases": {
+                "products": {},
+                "products-acme": {
+                    "filter": { "term": { "tenant": { "value": "acme" } } },
+                    "routing": "acme",
+                    "is_hidden": true
+                }
+            }
+        },
+        "composed_of": ["shared-settings", "shared-mappings"],
+        "priority": 200,
+        "version": 3,
+        "_meta": { "owner": "search" }
+    }));
+}
+
+#[test]
+fn data_stream_template_test() {
+    let template = IndexTemplate::new(["logs-nginx"])
+        .data_stream(DataStream::new().timestamp_field("request_time"))
+        .template(Template::new().mappings(IndexMapping::new().property("request_time", FieldMapping::Date(DateField::new()))));
+
+    assert_eq!(serde_json::to_value(template).unwrap(), json!({
+        "index_patterns": ["logs-nginx"],
+        "template": { "mappings": { "properties": { "request_time": { "type": "date" } } } },
+        "data_stream": { "timestamp_field": { "name": "request_time" } }
+    }));
+
+    assert_eq!(serde_json::to_value(IndexTemplate::new(["logs-*"]).data_stream(DataStream::new())).unwrap(),
+               json!({ "index_patterns": ["logs-*"], "data_stream": {} }));
+}
+
+#[test]
+fn component_template_test() {
+    let component = ComponentTemplate::new(Template::new().settings(IndexSettings::new().number_of_replicas(1u32)))
+        .version(1u64)
+        .meta(json!({ "description": "replicas" }));
+
+    assert_eq!(serde_json::to_value(component).unwrap(), json!({
+        "template": { "settings": { "number_of_replicas": 1 } },
+        "version": 1,
+        "_meta": { "description": "replicas" }
+    }));
+}
+
+#[test]
+fn alias_actions_test() {
+    let tenant_filter = Bool::new()
+        .filter(vec![Term::new("tenant", "acme")])
+        .must_not(vec![Exists::new().field("deleted_at")]);
+
+    let actions = AliasActions::new()
+        .action(AddAlias::new("products-v2", "products").is_write_index(true))
+        .action(AddAlias::new("products-v2", "products-acme")
+            .filter(tenant_filter)
+            .index_routing("acme")
+            .search_routing("acme,shared"))
+        .action(RemoveAlias::new("products-v1", "products").must_exist(true))
+        .action(RemoveIndex::new("products-v0"));
+
+    assert_eq!(serde_json::to_value(&actions).unwrap(), json!({
+        "actions": [
+            { "add": { "index": "products-v2", "alias": "products", "is_write_index": true } },
+            { "add": {
+                "index": "products-v2",
+                "alias": "products-acme",
+                "filter": {
+                    "bool": {
+                        "filter": [{ "term": { "tenant": { "value": "acme" } } }],
+                        "must_not": [{ "exists": { "field": "deleted_at" } }]
+                    }
+                },
+                "index_routing": "acme",
+                "search_routing": "acme,shared"
+            } },
+            { "remove": { "index": "products-v1", "alias": "products", "must_exist": true } },
+            { "remove_index": { "index": "products-v0" } }
+        ]
+    }));
+
+    assert!(matches!(actions.iter().last(), Some(AliasAction::RemoveIndex(_))));
+
+    let parsed: AliasActions = serde_json::from_value(serde_json::to_value(&actions).unwrap()).unwrap();
+    assert_eq!(serde_json::to_value(parsed).unwrap(), serde_json::to_value(actions).unwrap());
+}
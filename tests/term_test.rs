@@ -17,7 +17,7 @@ fn term_test() {
 
 #[test]
 fn term_test_with_value_is_int() {
-    let term = Term::new("brand_id", 1 as i64);
+    let term = Term::new("brand_id", 1_i64);
     let json_actual = json!(term);
 
     let json_expected = json!({
@@ -45,7 +45,8 @@ fn term_test_with_value_is_float64() {
 #[test]
 fn term_test_with_value_is_float32() {
 
-    let value = 24334.1232131 as f32;
+    #[allow(clippy::excessive_precision)]
+    let value = 24334.1232131_f32;
     let term = Term::new("brand_id", value);
     let json_actual = json!(term);
 
@@ -0,0 +1,96 @@
+use os_query_builder_rs::compound_query::{
+    bool::Bool,
+    boosting::Boosting,
+    disjunction_max::DisMax
+};
+use os_query_builder_rs::full_text::intervals::{
+    interval::Intervals,
+    rules::{all_of::AllOfRule, r#match::MatchRule}
+};
+use os_query_builder_rs::full_text::{
+    multi_match::MultiMatch,
+    r#match::Match
+};
+use os_query_builder_rs::misc::query_field::QueryField;
+use os_query_builder_rs::misc::validate::{Validate, ValidationError};
+use os_query_builder_rs::model::Query;
+use os_query_builder_rs::term::{
+    range::Range,
+    term::Term,
+    terms_set::TermsSet
+};
+
+#[test]
+fn valid_query_test() {
+    let query = Query::new()
+        .query(Bool::new()
+            .must(vec![Match::new().field("title").value("wind")])
+            .filter(vec![QueryField::from(Term::new("brand", "FIAT")), Range::new().field("price").gte(10).into()]))
+        .build();
+
+    assert!(query.is_ok());
+}
+
+#[test]
+fn range_and_term_without_field_test() {
+    let errors = Query::new()
+        .query(Bool::new()
+            .filter(vec![QueryField::from(Range::new().gte(10)), Term::new("", "FIAT").into()]))
+        .build()
+        .unwrap_err();
+
+    assert_eq!(errors.0, vec![
+        ValidationError::new("/query/bool/filter/0/range", "field is missing"),
+        ValidationError::new("/query/bool/filter/1/term", "field is empty"),
+    ]);
+}
+
+#[test]
+fn match_without_query_test() {
+    let errors = QueryField::from(Match::new().field("title")).validate().unwrap_err();
+    assert_eq!(errors.0, vec![ValidationError::new("/match/title/query", "query is missing")]);
+
+    let errors = QueryField::from(MultiMatch::new().fields(vec!["title"])).validate().unwrap_err();
+    assert_eq!(errors.0, vec![ValidationError::new("/multi_match/query", "query is missing")]);
+}
+
+#[test]
+fn empty_match_rule_test() {
+    let intervals = Intervals::new()
+        .field("my_text")
+        .intervals(AllOfRule::new().intervals(vec![MatchRule::new().query("hot"), MatchRule::new()]));
+
+    let errors = Query::new().query(intervals).build().unwrap_err();
+    assert_eq!(errors.0, vec![
+        ValidationError::new("/query/intervals/my_text/all_of/intervals/1/match/query", "query is empty")
+    ]);
+}
+
+#[test]
+fn nested_compound_queries_test() {
+    let boosting = Boosting::new(Term::new("brand", "FIAT"), Match::new().field("title"))
+        .negative_boost(2);
+    let dis_max = DisMax::new(Vec::<QueryField>::new());
+    let terms_set = TermsSet::new().field("tags").terms(vec!["a"]);
+
+    let errors = Query::new()
+        .query(Bool::new().should(vec![QueryField::from(boosting), dis_max.into()]))
+        .post_filter(terms_set)
+        .build()
+        .unwrap_err();
+
+    assert_eq!(errors.0, vec![
+        ValidationError::new("/query/bool/should/0/boosting/negative/match/title/query", "query is missing"),
+        ValidationError::new("/query/bool/should/0/boosting/negative_boost", "negative_boost must be between 0 and 1"),
+        ValidationError::new("/query/bool/should/1/dis_max/queries", "queries are empty"),
+        ValidationError::new("/post_filter/terms_set/tags", "minimum_should_match_field or minimum_should_match_script is missing"),
+    ]);
+}
+
+#[test]
+fn path_is_escaped_test() {
+    let errors = QueryField::from(Match::new().field("a/b~c")).validate().unwrap_err();
+
+    assert_eq!(errors.0[0].path, "/match/a~1b~0c/query");
+    assert_eq!(errors.to_string(), "/match/a~1b~0c/query: query is missing");
+}
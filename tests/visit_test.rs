@@ -0,0 +1,158 @@
+use serde_json::json;
+use os_query_builder_rs::compound_query::{
+    bool::Bool,
+    boosting::Boosting,
+    constant_score::ConstantScore,
+    disjunction_max::DisMax
+};
+use os_query_builder_rs::full_text::intervals::{
+    interval::Intervals,
+    rules::{any_of::AnyOfRule, r#match::MatchRule, prefix::PrefixRule}
+};
+use os_query_builder_rs::full_text::{
+    multi_match::MultiMatch,
+    r#match::Match
+};
+use os_query_builder_rs::misc::query_field::QueryField;
+use os_query_builder_rs::misc::visit::{Visitor, VisitorMut, Walk};
+use os_query_builder_rs::model::Query;
+use os_query_builder_rs::term::{
+    exists::Exists,
+    range::Range,
+    term::Term,
+    terms::Terms
+};
+
+#[derive(Default)]
+struct CollectFields(Vec<String>);
+
+impl Visitor for CollectFields {
+    fn visit_field(&mut self, field: &str) {
+        self.0.push(field.to_string());
+    }
+}
+
+struct RenameField<'a>(&'a str, &'a str);
+
+impl VisitorMut for RenameField<'_> {
+    fn visit_field_mut(&mut self, field: &mut String) {
+        if field == self.0 {
+            *field = self.1.to_string();
+        }
+    }
+}
+
+struct TenantFilter(&'static str);
+
+impl VisitorMut for TenantFilter {
+    fn visit_query_mut(&mut self, query: &mut QueryField) {
+        let original = query.clone();
+        *query = Bool::new()
+            .must(vec![original])
+            .filter(vec![Term::new("tenant_id", self.0)])
+            .into();
+    }
+}
+
+#[test]
+fn collect_fields_test() {
+    let query = QueryField::from(Bool::new()
+        .must(vec![
+            QueryField::from(MultiMatch::new().fields(vec!["title^2", "body"]).value("wind")),
+            DisMax::new(vec![Term::new("brand", "FIAT"), Term::new("model", "Panda")]).into()
+        ])
+        .should(vec![Boosting::new(Exists::new().field("photo"), Range::new().field("price").gte(100))])
+        .filter(vec![
+            QueryField::from(ConstantScore::new(Terms::new_with_terms_query("color", vec!["red"]))),
+            Intervals::new()
+                .field("text")
+                .intervals(AnyOfRule::new().intervals(vec![
+                    MatchRule::new().query("hot").use_field("text.stem")
+                ]))
+                .into()
+        ]));
+
+    let mut fields = CollectFields::default();
+    fields.visit_query(&query);
+
+    assert_eq!(fields.0, vec!["title", "body", "brand", "model", "color", "text", "text.stem", "photo", "price"]);
+}
+
+#[test]
+fn rename_fields_test() {
+    let mut query = Query::new()
+        .query(Bool::new()
+            .must(vec![MultiMatch::new().fields(vec!["name^3", "description"]).value("wind")])
+            .filter(vec![
+                QueryField::from(Terms::new_with_terms_query("name", vec!["a", "b"])),
+                Intervals::new().field("name").intervals(PrefixRule::new().prefix("win")).into()
+            ]))
+        .post_filter(Match::new().field("name").value("x"));
+
+    query.walk_mut(&mut RenameField("name", "title"));
+
+    let json_expected = json!({
+        "query": {
+            "bool": {
+                "must": [{ "multi_match": { "query": "wind", "fields": ["title^3", "description"] } }],
+                "filter": [
+                    { "terms": { "title": ["a", "b"] } },
+                    { "intervals": { "title": { "prefix": { "prefix": "win" } } } }
+                ]
+            }
+        },
+        "post_filter": { "match": { "title": { "query": "x" } } }
+    });
+
+    assert_eq!(json_expected, json!(query));
+}
+
+#[test]
+fn inject_tenant_filter_test() {
+    let mut query = Query::new().query(Match::new().field("title").value("wind"));
+    query.walk_mut(&mut TenantFilter("acme"));
+
+    let json_expected = json!({
+        "query": {
+            "bool": {
+                "must": [{ "match": { "title": { "query": "wind" } } }],
+                "filter": [{ "term": { "tenant_id": { "value": "acme" } } }]
+            }
+        }
+    });
+
+    assert_eq!(json_expected, json!(query));
+}
+
+#[test]
+fn strip_boosts_test() {
+    struct StripBoost(usize);
+
+    impl VisitorMut for StripBoost {
+        fn visit_boost_mut(&mut self, boost: &mut Option<f64>) {
+            if boost.take().is_some() {
+                self.0 += 1;
+            }
+        }
+    }
+
+    let mut query = QueryField::from(Bool::new().should(vec![
+        QueryField::from(Term::new("brand", "FIAT").boost(2)),
+        ConstantScore::new(Match::new().field("title").value("wind").boost(3)).boost(1.5).into(),
+        Range::new().field("price").gte(1).into()
+    ]));
+
+    let mut strip = StripBoost(0);
+    strip.visit_query_mut(&mut query);
+
+    assert_eq!(strip.0, 3);
+    assert_eq!(json!(query), json!({
+        "bool": {
+            "should": [
+                { "term": { "brand": { "value": "FIAT" } } },
+                { "constant_score": { "filter": { "match": { "title": { "query": "wind" } } } } },
+                { "range": { "price": { "gte": 1 } } }
+            ]
+        }
+    }));
+}